@@ -3,7 +3,8 @@ use oxc::ast::{
   AstKind, MemberExpressionKind,
   ast::{self, AssignmentExpression, Expression, PropertyKey},
 };
-use oxc::span::CompactStr;
+use oxc::span::{CompactStr, Span};
+use oxc::syntax::operator::UnaryOperator;
 use rolldown_common::{AstScopes, EcmaModuleAstUsage};
 use rolldown_ecmascript_utils::ExpressionExt;
 
@@ -41,7 +42,11 @@ pub enum CommonJsAstType {
   /// `console.log(exports)`
   ExportsRead,
   EsModuleFlag,
-  Reexport,
+  /// `Object.freeze(exports)` or `Object.seal(module.exports)` as a statement, no export is added
+  /// after it.
+  ExportsFrozen,
+  /// `module.exports = require('mod')`, the span is the span of the `require('mod')` call
+  Reexport(Span),
 }
 
 impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
@@ -102,8 +107,12 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       Some(CommonJsAstType::ExportsPropWrite(prop)) if prop == "*" => {
         self.result.ast_usage.remove(EcmaModuleAstUsage::AllStaticExportPropertyAccess);
       }
-      Some(CommonJsAstType::Reexport) => {
+      Some(CommonJsAstType::ExportsFrozen) => {
+        self.result.ast_usage.insert(EcmaModuleAstUsage::ExportsFrozen);
+      }
+      Some(CommonJsAstType::Reexport(span)) => {
         self.result.ast_usage.insert(EcmaModuleAstUsage::IsCjsReexport);
+        self.cjs_reexport_require_span = Some(*span);
       }
       _ => {}
     }
@@ -122,6 +131,12 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     if !is_same_member_expr {
       return None;
     }
+    // The returned object is the `exports` object itself, so only a standalone call is a marker.
+    if matches!(self.visit_path.get(base_cursor - 2), Some(AstKind::ExpressionStatement(_)))
+      && is_object_freeze(&self.result.symbol_ref_db.ast_scopes, call_expr)
+    {
+      return Some(CommonJsAstType::ExportsFrozen);
+    }
     is_object_define_property_es_module(&self.result.symbol_ref_db.ast_scopes, call_expr)
   }
 
@@ -145,10 +160,11 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
 
     let assignment_expr = parent.as_assignment_expression()?;
 
-    let Expression::BooleanLiteral(bool_lit) = &assignment_expr.right else {
-      return Some(CommonJsAstType::ExportsPropWrite("__esModule".into()));
-    };
-    bool_lit.value.then_some(CommonJsAstType::EsModuleFlag)
+    match to_boolean(&assignment_expr.right) {
+      Some(true) => Some(CommonJsAstType::EsModuleFlag),
+      Some(false) => None,
+      None => Some(CommonJsAstType::ExportsPropWrite("__esModule".into())),
+    }
   }
 
   /// check if the `module` is used as : module.exports = require('mod');
@@ -171,7 +187,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       .as_expression()?
       .as_string_literal()
       .is_some()
-      .then_some(CommonJsAstType::Reexport)
+      .then_some(CommonJsAstType::Reexport(call_expr.span))
   }
 }
 
@@ -180,6 +196,19 @@ pub enum CjsGlobalAssignmentType {
   ExportsAssignment,
 }
 
+/// check if the `CallExpression` is `Object.freeze(...)` or `Object.seal(...)` with a global
+/// `Object`.
+fn is_object_freeze(scope: &AstScopes, call_expr: &ast::CallExpression<'_>) -> bool {
+  let Some(callee) = call_expr.callee.as_member_expression() else {
+    return false;
+  };
+  callee
+    .object()
+    .as_identifier()
+    .is_some_and(|object| object.name == "Object" && scope.is_unresolved(object.reference_id()))
+    && matches!(callee.static_property_name(), Some("freeze" | "seal"))
+}
+
 /// check if the `CallExpression` is Object.defineProperty(exports, "__esModule", { value: true });
 pub fn is_object_define_property_es_module(
   scope: &AstScopes,
@@ -217,9 +246,9 @@ pub fn is_object_define_property_es_module(
       _ => None,
     })
     .is_some_and(|obj_expr| match obj_expr.properties.as_slice() {
-      [ast::ObjectPropertyKind::ObjectProperty(kind)] => match (&kind.key, &kind.value) {
-        (PropertyKey::StaticIdentifier(id), Expression::BooleanLiteral(bool_lit)) => {
-          id.name == "value" && bool_lit.value
+      [ast::ObjectPropertyKind::ObjectProperty(kind)] => match &kind.key {
+        PropertyKey::StaticIdentifier(id) => {
+          id.name == "value" && to_boolean(&kind.value) == Some(true)
         }
         _ => false,
       },
@@ -232,6 +261,25 @@ pub fn is_object_define_property_es_module(
   }
 }
 
+/// The truthiness of a literal-like `__esModule` value, e.g. `true`, `!0` or `1` emitted by
+/// transpilers and minifiers. `None` if it depends on something else.
+fn to_boolean(expr: &Expression<'_>) -> Option<bool> {
+  match expr.without_parentheses() {
+    Expression::BooleanLiteral(lit) => Some(lit.value),
+    Expression::NumericLiteral(lit) => Some(lit.value != 0.0 && !lit.value.is_nan()),
+    Expression::StringLiteral(lit) => Some(!lit.value.is_empty()),
+    Expression::NullLiteral(_) => Some(false),
+    Expression::ObjectExpression(_)
+    | Expression::ArrayExpression(_)
+    | Expression::FunctionExpression(_)
+    | Expression::ArrowFunctionExpression(_) => Some(true),
+    Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+      to_boolean(&unary.argument).map(|value| !value)
+    }
+    _ => None,
+  }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -298,4 +346,15 @@ mod tests {
       assert_eq!(result, Some(CommonJsAstType::ExportsPropWrite("__esModule".into())));
     }
   }
+
+  #[test]
+  fn test_is_object_define_property_with_truthy_value() {
+    let source = r#"Object.defineProperty(exports, "__esModule", { value: !0 });"#;
+    let allocator = Allocator::default();
+    let (ast_scopes, program) = create_ast_scopes_and_program_from_source(source, &allocator);
+
+    let call_expr = extract_call_expr(&program).unwrap();
+    let result = is_object_define_property_es_module(&ast_scopes, call_expr);
+    assert_eq!(result, Some(CommonJsAstType::EsModuleFlag));
+  }
 }
@@ -6,6 +6,7 @@ use oxc::{
   ast_visit::{Visit, walk},
   semantic::{ScopeFlags, SymbolId},
   span::{GetSpan, Span},
+  syntax::operator::BinaryOperator,
 };
 use rolldown_common::{
   ConstExportMeta, EcmaModuleAstUsage, EcmaViewMeta, ImportKind, ImportRecordMeta, LocalExport,
@@ -29,14 +30,16 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
   fn enter_scope(
     &mut self,
     flags: oxc::semantic::ScopeFlags,
-    _scope_id: &std::cell::Cell<Option<oxc::semantic::ScopeId>>,
+    scope_id: &std::cell::Cell<Option<oxc::semantic::ScopeId>>,
   ) {
     self.scope_stack.push(flags);
+    self.scope_id_stack.push(scope_id.get().expect("scope id should be set by semantic analysis"));
     self.traverse_state.set(TraverseState::TopLevel, is_top_level(&self.scope_stack));
   }
 
   fn leave_scope(&mut self) {
     self.scope_stack.pop();
+    self.scope_id_stack.pop();
     self.traverse_state.set(TraverseState::TopLevel, is_top_level(&self.scope_stack));
   }
 
@@ -85,6 +88,8 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
         &self.result.symbol_ref_db.ast_scopes,
         self.immutable_ctx.flat_options,
         self.immutable_ctx.options,
+        self.immutable_ctx.comments,
+        self.immutable_ctx.source,
         None,
       )
       .detect_side_effect_of_stmt(stmt);
@@ -93,6 +98,7 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
       {
         self.current_stmt_info.debug_label = Some(stmt.to_source_string());
       }
+      self.result.stmt_spans.push(stmt.span());
 
       self.visit_statement(stmt);
       if self.current_stmt_info.side_effect.intersects(
@@ -138,12 +144,18 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
     let symbol_id = ident.symbol_id.get().unpack();
     if self.is_root_symbol(symbol_id) {
       self.declare_normal_symbol_ref(symbol_id);
+      let scoping = self.result.symbol_ref_db.scoping();
+      if !scoping.symbol_flags(symbol_id).is_import()
+        && scoping.symbol_span(symbol_id) == ident.span
+      {
+        self.result.top_level_symbols.push((ident.name.as_str().into(), ident.span));
+      }
     }
   }
 
   fn visit_for_of_statement(&mut self, it: &ast::ForOfStatement<'ast>) {
     let is_top_level_await = it.r#await && self.is_valid_tla_scope();
-    if is_top_level_await && !self.immutable_ctx.flat_options.keep_esm_import_export_syntax() {
+    if is_top_level_await && !self.immutable_ctx.flat_options.supports_top_level_await() {
       self.result.errors.push(BuildDiagnostic::unsupported_feature(
         self.immutable_ctx.id.resource_id().clone(),
         self.immutable_ctx.source.clone(),
@@ -163,7 +175,7 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
 
   fn visit_await_expression(&mut self, it: &ast::AwaitExpression<'ast>) {
     let is_top_level_await = self.is_valid_tla_scope();
-    if !self.immutable_ctx.flat_options.keep_esm_import_export_syntax() && is_top_level_await {
+    if !self.immutable_ctx.flat_options.supports_top_level_await() && is_top_level_await {
       self.result.errors.push(BuildDiagnostic::unsupported_feature(
         self.immutable_ctx.id.resource_id().clone(),
         self.immutable_ctx.source.clone(),
@@ -188,7 +200,19 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
 
   fn visit_statement(&mut self, stmt: &ast::Statement<'ast>) {
     if let Some(decl) = stmt.as_module_declaration() {
-      self.scan_module_decl(decl);
+      // Top-level statements are visited directly by `visit_program`, so the path is empty for them.
+      // The parser rejects nested exports, but an AST coming from a plugin may still contain them,
+      // and scanning them would register exports of bindings that aren't in the module scope.
+      if is_export_decl(decl) && !self.visit_path.is_empty() {
+        let span = decl.span();
+        self.result.errors.push(BuildDiagnostic::nested_export(
+          self.immutable_ctx.id.resource_id().clone(),
+          self.immutable_ctx.source.clone(),
+          Span::new(span.start, span.start + 6),
+        ));
+      } else {
+        self.scan_module_decl(decl);
+      }
     }
     walk::walk_statement(self, stmt);
   }
@@ -204,17 +228,28 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
   fn visit_import_expression(&mut self, expr: &ast::ImportExpression<'ast>) {
     // If a `ImportExpression` is ignored by `/* @vite-ignore */` comment, we should not treat it as a dynamic import
     let should_ignore = self.is_imoprt_expr_ignored_by_comment(expr);
-    if !should_ignore && let Some(request) = expr.source.as_static_module_request() {
+    if !should_ignore && let Some(request) = expr.source.as_constant_module_request() {
       let import_rec_idx =
-        self.add_import_record(request.as_str(), ImportKind::DynamicImport, expr.source.span(), {
+        self.add_import_record(request.as_ref(), ImportKind::DynamicImport, expr.source.span(), {
           let mut meta = ImportRecordMeta::empty();
           meta.set(ImportRecordMeta::IsTopLevel, self.is_root_scope());
           meta.set(ImportRecordMeta::IsUnspannedImport, expr.source.span().is_empty());
           meta.set(ImportRecordMeta::InTryCatchBlock, self.in_side_try_catch_block());
+          meta.set(ImportRecordMeta::IsHoistableDynamicImport, self.is_hoistable_dynamic_import());
           meta
         });
       self.init_dynamic_import_binding_usage_info(import_rec_idx);
       self.result.imports.insert(expr.span, import_rec_idx);
+    } else if !should_ignore {
+      self.result.dynamic_import_exprs.push(expr.span);
+      self.result.warnings.push(
+        BuildDiagnostic::dynamic_import(
+          self.immutable_ctx.id.to_string(),
+          self.immutable_ctx.source.clone(),
+          expr.source.span(),
+        )
+        .with_severity_warning(),
+      );
     }
     walk::walk_import_expression(self, expr);
   }
@@ -229,9 +264,18 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
               && member_expr.static_property_name() == Some("exports")
             {
               self.cjs_module_ident.get_or_insert(Span::new(id.span.start, id.span.start + 6));
+              if self.is_in_conditional_control_flow() {
+                self.conditional_cjs_module_exports.get_or_insert(member_expr.span());
+              }
+              if let Some(export_span) = self.first_cjs_property_export {
+                self
+                  .cjs_module_exports_reassignment
+                  .get_or_insert((export_span, member_expr.span()));
+              }
             }
             if id.name == "exports" && self.is_global_identifier_reference(id) {
               self.cjs_exports_ident.get_or_insert(Span::new(id.span.start, id.span.start + 7));
+              self.first_cjs_property_export.get_or_insert(member_expr.span());
 
               if let Some((span, export_name)) = member_expr.static_property_info() {
                 // `exports.test = ...`
@@ -245,9 +289,21 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
                     .add_constant_symbol(exported_symbol.symbol, ConstExportMeta::new(value, true));
                 }
 
+                // The export is always present once any of its assignments is unconditional.
+                let is_conditional = self.is_in_conditional_control_flow()
+                  && self
+                    .result
+                    .commonjs_exports
+                    .get(export_name)
+                    .is_none_or(|export| export.is_conditional);
                 self.result.commonjs_exports.insert(
                   export_name.into(),
-                  LocalExport { referenced: exported_symbol, span, came_from_commonjs: true },
+                  LocalExport {
+                    referenced: exported_symbol,
+                    span,
+                    came_from_commonjs: true,
+                    is_conditional,
+                  },
                 );
               }
             }
@@ -260,6 +316,7 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
                 && member_expr.property.name == "exports"
               {
                 self.cjs_module_ident.get_or_insert(Span::new(id.span.start, id.span.start + 6));
+                self.first_cjs_property_export.get_or_insert(node.left.span());
               }
             }
           }
@@ -280,11 +337,27 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
   }
 
   fn visit_meta_property(&mut self, it: &ast::MetaProperty<'ast>) {
+    if self.record_import_meta_env_reference(it) {
+      return;
+    }
     if self.immutable_ctx.flat_options.keep_esm_import_export_syntax() {
       walk::walk_meta_property(self, it);
       return;
     }
+    if self.record_destructured_import_meta(it) {
+      walk::walk_meta_property(self, it);
+      return;
+    }
     if let Some(parent) = self.visit_path.last() {
+      if it.meta.name == "import"
+        && it.property.name == "meta"
+        && parent
+          .as_member_expression_kind()
+          .and_then(|member_expr| member_expr.static_property_name())
+          .is_some_and(|name| name == "url")
+      {
+        self.result.ast_usage.insert(EcmaModuleAstUsage::ImportMetaUrl);
+      }
       if !parent
         .as_member_expression_kind()
         .map(|member_expr| {
@@ -408,6 +481,9 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
 
   fn visit_call_expression(&mut self, it: &ast::CallExpression<'ast>) {
     self.try_extract_hmr_info_from_hot_accept_call(it);
+    if self.immutable_ctx.flat_options.resolve_require_resolve_to_asset_enabled() {
+      self.handle_require_resolve_with_string_literal(it);
+    }
     walk::walk_call_expression(self, it);
   }
 }
@@ -441,8 +517,8 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
               Some(CommonJsAstType::ExportsPropWrite(prop)) => {
                 self.cjs_named_exports_usage.entry(prop).or_default().write += 1;
               }
-              Some(CommonJsAstType::EsModuleFlag) => {}
-              Some(CommonJsAstType::Reexport) => {
+              Some(CommonJsAstType::EsModuleFlag | CommonJsAstType::ExportsFrozen) => {}
+              Some(CommonJsAstType::Reexport(_)) => {
                 // This is only usd for `module.exports = require('mod')`
                 // should only reached when `ident_ref` is `module`
                 unreachable!()
@@ -481,8 +557,19 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
               self.result.dummy_record_set.insert(ident_ref.span);
             }
           }
+          "arguments" => {
+            self.arguments_references += 1;
+            // Other sloppy mode only syntax, e.g. `with` or `0777`, is already rejected by the
+            // parser, since every module is parsed as strict mode code.
+            if let Some((span, props)) = self.try_extract_parent_static_member_expr_chain(1)
+              && props[0].0 == "callee"
+            {
+              self.arguments_callee_spans.push(span);
+            }
+          }
           _ => {}
         }
+        self.try_diagnostic_node_global_in_browser(ident_ref);
         self.process_global_identifier_ref_by_ancestor(ident_ref);
       }
       super::IdentifierReferenceKind::Root(root_symbol_id) => {
@@ -490,9 +577,13 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         // use this flag to avoid insert it as `Symbol` at the same time.
         let mut is_inserted_before = false;
         if self.result.named_imports.contains_key(&root_symbol_id) {
-          if let Some((span, props)) = self.try_extract_parent_static_member_expr_chain(usize::MAX)
+          // Longer chains only show up in generated code, the import is referenced as a whole for
+          // them. Collect one more property than the limit to tell whether the chain is too long.
+          let max_len = self.immutable_ctx.options.optimization.max_member_expr_chain_length();
+          if let Some((span, props)) =
+            self.try_extract_parent_static_member_expr_chain(max_len.saturating_add(1))
           {
-            if !span.is_unspanned() {
+            if !span.is_unspanned() && props.len() <= max_len {
               is_inserted_before = true;
               self.add_member_expr_reference(root_symbol_id, props, span);
             }
@@ -502,6 +593,21 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
           self.add_referenced_symbol(root_symbol_id);
         }
 
+        // `export { foo }` only forwards the binding, it doesn't read it
+        if self.traverse_state.contains(TraverseState::TopLevel)
+          && self.result.named_imports.contains_key(&root_symbol_id)
+          && !ident_ref
+            .reference_id
+            .get()
+            .is_some_and(|reference_id| self.export_clause_references.contains(&reference_id))
+        {
+          self
+            .result
+            .imports_referenced_at_init
+            .entry(root_symbol_id.symbol)
+            .or_insert(ident_ref.span);
+        }
+
         if self.traverse_state.contains(TraverseState::RootSymbolReferenceStmtInfoId) {
           // Since `0` is always namespace object stmt info
           self
@@ -536,20 +642,51 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     &mut self,
     ident_ref: &IdentifierReference,
   ) -> Option<()> {
-    let parent = self.visit_path.last()?;
-    if let AstKind::CallExpression(_) = parent {
-      if ident_ref.name == "eval" {
-        // TODO: esbuild track has_eval for each scope, this could reduce bailout range, and may
-        // improve treeshaking performance. https://github.com/evanw/esbuild/blob/360d47230813e67d0312ad754cad2b6ee09b151b/internal/js_ast/js_ast.go#L1288-L1291
-        self.result.ecma_view_meta.insert(EcmaViewMeta::Eval);
-        self.result.warnings.push(
-          BuildDiagnostic::eval(
-            self.immutable_ctx.id.to_string(),
-            self.immutable_ctx.source.clone(),
-            ident_ref.span,
-          )
-          .with_severity_warning(),
-        );
+    if ident_ref.name != "eval" {
+      return None;
+    }
+    let is_direct_eval = self.classify_eval_call(ident_ref)?;
+    if is_direct_eval {
+      // The tree shaking bailout is scoped to the top level statement containing the `eval`, so
+      // an `eval` in a function that is never included doesn't keep the rest of the module.
+      // `new Function(...)` isn't tracked, since its body only sees the global scope.
+      self.result.ecma_view_meta.insert(EcmaViewMeta::Eval);
+      self.current_stmt_info.meta.insert(StmtInfoMeta::HasEval);
+      // Only the scope containing the `eval` and its enclosing scopes are visible to the evaluated
+      // code, so bindings of unrelated scopes can still be renamed.
+      self.result.scopes_with_direct_eval.extend(self.scope_id_stack.iter().copied());
+    }
+    // An indirect eval, e.g. `(0, eval)(...)` or `eval?.(...)`, runs in the global scope and can't
+    // see the bindings of the module, so it only counts as a call with side effects.
+    self.result.warnings.push(
+      BuildDiagnostic::eval(
+        self.immutable_ctx.id.to_string(),
+        self.immutable_ctx.source.clone(),
+        ident_ref.span,
+      )
+      .with_severity_warning(),
+    );
+    None
+  }
+
+  /// Returns `Some(true)` for a direct `eval(...)` call, `Some(false)` for an indirect one such as
+  /// `(0, eval)(...)` or `eval?.(...)`, and `None` if `eval` isn't called at all.
+  fn classify_eval_call(&self, ident_ref: &IdentifierReference) -> Option<bool> {
+    let mut callee_span = ident_ref.span;
+    let mut is_indirect = false;
+    for ancestor in self.visit_path.iter().rev() {
+      match ancestor {
+        AstKind::ParenthesizedExpression(paren) => callee_span = paren.span,
+        AstKind::SequenceExpression(seq)
+          if seq.expressions.last().is_some_and(|expr| expr.span() == callee_span) =>
+        {
+          is_indirect = true;
+          callee_span = seq.span;
+        }
+        AstKind::CallExpression(call) if call.callee.span() == callee_span => {
+          return Some(!is_indirect && !call.optional);
+        }
+        _ => return None,
       }
     }
     None
@@ -557,13 +694,27 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
 
   /// return `bool` represent if it is a global require call
   fn process_global_require_call(&mut self, expr: &ast::CallExpression<'ast>) -> bool {
-    let (value, span) = match expr.arguments.first() {
-      Some(ast::Argument::StringLiteral(request)) => (request.value, request.span),
-      Some(ast::Argument::TemplateLiteral(request)) => match request.single_quasi() {
-        Some(value) => (value, request.span),
-        None => return false,
-      },
-      _ => return false,
+    let Some(request) = expr.arguments.first() else {
+      return false;
+    };
+    let span = request.span();
+    let Some(value) = request.as_expression().and_then(ExpressionExt::as_constant_module_request)
+    else {
+      if matches!(&expr.callee, ast::Expression::Identifier(callee) if callee.name == "require") {
+        self.result.dynamic_requires.push(span);
+        let is_prefixed_concatenation =
+          request.as_expression().is_some_and(is_prefixed_string_concatenation);
+        self.result.warnings.push(
+          BuildDiagnostic::dynamic_require(
+            self.immutable_ctx.id.to_string(),
+            self.immutable_ctx.source.clone(),
+            span,
+            is_prefixed_concatenation,
+          )
+          .with_severity_warning(),
+        );
+      }
+      return false;
     };
     let mut init_meta = if span.is_empty() {
       ImportRecordMeta::IsUnspannedImport
@@ -605,8 +756,37 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     };
     let in_side_try_catch_block = self.in_side_try_catch_block();
     init_meta.set(ImportRecordMeta::InTryCatchBlock, in_side_try_catch_block);
+    init_meta.set(
+      ImportRecordMeta::IsCjsReexportTarget,
+      self.cjs_reexport_require_span == Some(expr.span),
+    );
     let id = self.add_import_record(value.as_ref(), ImportKind::Require, span, init_meta);
     self.result.imports.insert(expr.span, id);
+    let usage = self.extract_require_usage();
+    self.result.require_usage.insert(expr.span, usage);
     true
   }
 }
+
+fn is_export_decl(decl: &ast::ModuleDeclaration) -> bool {
+  matches!(
+    decl,
+    ast::ModuleDeclaration::ExportAllDeclaration(_)
+      | ast::ModuleDeclaration::ExportNamedDeclaration(_)
+      | ast::ModuleDeclaration::ExportDefaultDeclaration(_)
+  )
+}
+
+/// `'./locale/' + lang` or `` `./locale/${lang}` ``, which usually picks one module of a directory.
+fn is_prefixed_string_concatenation(expr: &Expression) -> bool {
+  match expr.without_parentheses() {
+    Expression::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => {
+      matches!(binary.left.without_parentheses(), Expression::StringLiteral(_))
+        || is_prefixed_string_concatenation(&binary.left)
+    }
+    Expression::TemplateLiteral(template) => {
+      !template.expressions.is_empty() && !template.quasis[0].value.raw.is_empty()
+    }
+    _ => false,
+  }
+}
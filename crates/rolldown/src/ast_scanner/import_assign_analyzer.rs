@@ -14,6 +14,7 @@ use super::AstScanner;
 impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
   pub fn check_import_assign(&mut self, ident: &IdentifierReference, symbol_id: SymbolId) {
     let symbol_flag = self.result.symbol_ref_db.scoping().symbol_flags(symbol_id);
+    // Default, named and namespace import bindings are all flagged with `SymbolFlags::Import`.
     if symbol_flag.contains(SymbolFlags::Import) {
       let symbol_ref: SymbolRef = (self.immutable_ctx.idx, symbol_id).into();
       let is_namespace = self
@@ -5,6 +5,7 @@ mod hmr;
 pub mod impl_visit;
 mod import_assign_analyzer;
 mod new_url;
+mod require_resolve;
 pub mod side_effect_detector;
 
 use arcstr::ArcStr;
@@ -12,7 +13,7 @@ use const_eval::{ConstEvalCtx, try_extract_const_literal};
 use oxc::ast::ast::{BindingPatternKind, Expression, ImportExpression};
 use oxc::ast::{AstKind, ast};
 use oxc::ast_visit::walk;
-use oxc::semantic::{Reference, ScopeFlags, Scoping};
+use oxc::semantic::{Reference, ReferenceId, ScopeFlags, ScopeId, Scoping};
 use oxc::span::SPAN;
 use oxc::{
   ast::{
@@ -30,17 +31,18 @@ use oxc_index::IndexVec;
 use rolldown_common::dynamic_import_usage::{DynamicImportExportsUsage, DynamicImportUsageInfo};
 use rolldown_common::{
   ConstExportMeta, ConstantValue, EcmaModuleAstUsage, EcmaViewMeta, ExportsKind, FlatOptions,
-  HmrInfo, ImportAttribute, ImportKind, ImportRecordIdx, ImportRecordMeta, LocalExport,
-  MemberExprRef, ModuleDefFormat, ModuleId, ModuleIdx, NamedImport, RawImportRecord,
-  SideEffectDetail, Specifier, StmtInfo, StmtInfoIdx, StmtInfoMeta, StmtInfos, SymbolRef,
-  SymbolRefDbForModule, SymbolRefFlags, TaggedSymbolRef, ThisExprReplaceKind,
-  generate_replace_this_expr_map,
+  HmrInfo, ImportAttribute, ImportKind, ImportRecordIdx, ImportRecordMeta, InjectImport,
+  LocalExport, MemberExprRef, ModuleDefFormat, ModuleId, ModuleIdx, NamedImport, Platform,
+  RUNTIME_MODULE_KEY, RawImportRecord, SideEffectDetail, Specifier, StmtInfo, StmtInfoIdx,
+  StmtInfoMeta, StmtInfos, SymbolRef, SymbolRefDbForModule, SymbolRefFlags, TaggedSymbolRef,
+  ThisExprReplaceKind, generate_replace_this_expr_map,
 };
 use rolldown_ecmascript_utils::{BindingIdentifierExt, BindingPatternExt, FunctionExt};
-use rolldown_error::{BuildDiagnostic, BuildResult, CjsExportSpan};
+use rolldown_error::{BuildDiagnostic, BuildResult, CjsExportSpan, EventKindSwitcher};
 use rolldown_std_utils::PathExt;
 use rolldown_utils::concat_string;
-use rolldown_utils::ecmascript::legitimize_identifier_name;
+use rolldown_utils::ecmascript::{is_bare_specifier, legitimize_identifier_name};
+use rolldown_utils::global_reference::is_node_specific_global_ident_ref;
 use rolldown_utils::indexmap::FxIndexMap;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::borrow::Cow;
@@ -55,6 +57,17 @@ use crate::ast_scanner::cjs_export_analyzer::CommonjsExportSymbolUsage;
 static ENABLED_CJS_NAMESPACE_MERGING_MODULE_REQUEST: [&str; 3] =
   ["this-is-only-used-for-testing", "react", "react/jsx-runtime"];
 
+/// How the value of a global `require(...)` call is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequireUsage {
+  /// Only a static property chain of the required value is read, e.g. `['b', 'c']` for
+  /// `require('./a').b.c`
+  PropertyChain(Vec<CompactStr>),
+  /// The required value is used as a whole, e.g. `require('./a')()`, `const a = require('./a')`
+  /// or `require('./a').b = 1`. It's not safe to prune the namespace of the required module.
+  Opaque,
+}
+
 #[derive(Debug)]
 pub struct ScanResult {
   /// Using `IndexMap` to make sure the order of the named imports always sorted by the span of the
@@ -104,12 +117,41 @@ pub struct ScanResult {
   pub dynamic_import_rec_exports_usage: FxHashMap<ImportRecordIdx, DynamicImportExportsUsage>,
   /// `new URL('...', import.meta.url)`
   pub new_url_references: FxHashMap<Span, ImportRecordIdx>,
+  /// `require.resolve('...')`
+  pub require_resolve_references: FxHashMap<Span, ImportRecordIdx>,
   pub this_expr_replace_map: FxHashMap<Span, ThisExprReplaceKind>,
   pub hmr_info: HmrInfo,
   pub hmr_hot_ref: Option<SymbolRef>,
   pub directive_range: Vec<Span>,
   pub constant_export_map: FxHashMap<SymbolId, ConstExportMeta>,
   pub import_attribute_map: FxHashMap<ImportRecordIdx, ImportAttribute>,
+  /// Keyed by the span of the `require(...)` call, the related import record could be found in
+  /// `imports`
+  pub require_usage: FxHashMap<Span, RequireUsage>,
+  pub dynamic_import_exprs: Vec<Span>,
+  pub import_meta_env_references: FxHashMap<Span, Option<CompactStr>>,
+  pub destructured_import_meta: FxHashMap<Span, Vec<CompactStr>>,
+  pub deprecated_exports: FxHashMap<SymbolId, ArcStr>,
+  pub imports_referenced_at_init: FxHashMap<SymbolId, Span>,
+  pub source_mapping_url: Option<ArcStr>,
+  pub scopes_with_direct_eval: FxHashSet<ScopeId>,
+  /// Names and definition spans of the functions, classes and variables declared in the module
+  /// scope, in source order. Imported bindings and redeclarations of a `var` aren't included.
+  pub top_level_symbols: Vec<(CompactStr, Span)>,
+  /// Spans of the arguments of `require(...)` calls that can't be resolved at build time, e.g.
+  /// `require(name)` or `require('./locale/' + lang)`.
+  pub dynamic_requires: Vec<Span>,
+  /// Spans of the top level statements, in source order. The statement of `stmt_spans[i]` is
+  /// `stmt_infos[i + 1]`, since the first `StmtInfo` is the namespace statement.
+  pub stmt_spans: Vec<Span>,
+}
+
+impl ScanResult {
+  /// See `StmtInfos::side_effect_free_stmts`. The indices match `program.body[idx - 1]` of the
+  /// parsed AST, and the `StmtInfo` of the module later.
+  pub fn side_effect_free_stmts(&self) -> Vec<(StmtInfoIdx, String)> {
+    self.stmt_infos.side_effect_free_stmts(&self.stmt_spans)
+  }
 }
 
 bitflags::bitflags! {
@@ -145,9 +187,37 @@ pub struct AstScanner<'me, 'ast> {
   /// cjs ident span used for emit `commonjs_variable_in_esm` warning
   cjs_exports_ident: Option<Span>,
   cjs_module_ident: Option<Span>,
+  /// Span of the `module.exports` in the first `module.exports = ...` nested in control flow, e.g.
+  /// `if (cond) { module.exports = ... }`
+  conditional_cjs_module_exports: Option<Span>,
+  /// Span of the first assignment target like `exports.a` or `module.exports.a`. Used for the
+  /// `ModuleExportsReassignment` check.
+  first_cjs_property_export: Option<Span>,
+  /// Spans of the first property export and of the `module.exports` reassigned after it.
+  cjs_module_exports_reassignment: Option<(Span, Span)>,
+  /// Span of the `require('mod')` call in `module.exports = require('mod')`. The `module` is
+  /// visited before the `require` call, so the import record is flagged once the call is reached.
+  cjs_reexport_require_span: Option<Span>,
+  /// Spans of `arguments.callee` reads. They only throw in strict mode, so they are reported once
+  /// the module is known to be ESM.
+  arguments_callee_spans: Vec<Span>,
+  /// Number of references to the `arguments` object visited so far
+  arguments_references: usize,
+  /// Top-level function declarations with their trailing parameters that are never
+  /// referenced. Used for the `UnusedFunctionParameter` check.
+  unused_trailing_params: Vec<(SymbolId, Vec<(ArcStr, Span)>)>,
+  /// The `@deprecated` JSDoc text of the export declaration being scanned
+  current_export_deprecation: Option<ArcStr>,
+  /// References of local bindings in `export { foo }` clauses, which don't count as using them
+  export_clause_references: FxHashSet<ReferenceId>,
+  /// Records of `import { a } from './m'`-like declarations, keyed by the module request. Later
+  /// declarations importing bindings from the same request reuse the record.
+  named_import_decl_records: FxHashMap<CompactStr, ImportRecordIdx>,
   cur_class_decl: Option<SymbolId>,
   visit_path: Vec<AstKind<'ast>>,
   scope_stack: Vec<ScopeFlags>,
+  /// Ids of the scopes in `scope_stack`
+  scope_id_stack: Vec<ScopeId>,
   dynamic_import_usage_info: DynamicImportUsageInfo,
   /// "top level" `this` AstNode range in source code
   top_level_this_expr_set: FxHashSet<Span>,
@@ -205,6 +275,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       hashbang_range: None,
       dynamic_import_rec_exports_usage: FxHashMap::default(),
       new_url_references: FxHashMap::default(),
+      require_resolve_references: FxHashMap::default(),
       this_expr_replace_map: FxHashMap::default(),
       hmr_info: HmrInfo::default(),
       hmr_hot_ref,
@@ -214,6 +285,17 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       constant_export_map: FxHashMap::default(),
       ecma_view_meta: EcmaViewMeta::default(),
       import_attribute_map: FxHashMap::default(),
+      require_usage: FxHashMap::default(),
+      dynamic_import_exprs: Vec::new(),
+      import_meta_env_references: FxHashMap::default(),
+      destructured_import_meta: FxHashMap::default(),
+      deprecated_exports: FxHashMap::default(),
+      imports_referenced_at_init: FxHashMap::default(),
+      source_mapping_url: None,
+      scopes_with_direct_eval: FxHashSet::default(),
+      top_level_symbols: vec![],
+      dynamic_requires: vec![],
+      stmt_spans: vec![],
     };
 
     Self {
@@ -234,9 +316,20 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       esm_import_keyword: None,
       cjs_module_ident: None,
       cjs_exports_ident: None,
+      conditional_cjs_module_exports: None,
+      first_cjs_property_export: None,
+      cjs_module_exports_reassignment: None,
+      cjs_reexport_require_span: None,
+      arguments_callee_spans: vec![],
+      arguments_references: 0,
+      unused_trailing_params: vec![],
+      current_export_deprecation: None,
+      export_clause_references: FxHashSet::default(),
+      named_import_decl_records: FxHashMap::default(),
       cur_class_decl: None,
       visit_path: vec![],
       scope_stack: vec![],
+      scope_id_stack: vec![],
       dynamic_import_usage_info: DynamicImportUsageInfo::default(),
       top_level_this_expr_set: FxHashSet::default(),
       is_nested_this_inside_class: false,
@@ -277,11 +370,34 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
   #[expect(clippy::too_many_lines)]
   pub fn scan(mut self, program: &Program<'ast>) -> BuildResult<ScanResult> {
     self.visit_program(program);
+    self.result.source_mapping_url = self.extract_source_mapping_url();
+    if self.immutable_ctx.options.checks.contains(EventKindSwitcher::UnusedExportedFunction) {
+      self.report_unused_exported_functions();
+    }
+    if self.immutable_ctx.options.checks.contains(EventKindSwitcher::UnusedFunctionParameter) {
+      self.report_unused_function_params();
+    }
+    if self.immutable_ctx.options.checks.contains(EventKindSwitcher::ModuleAlwaysThrows) {
+      self.report_module_always_throws(program);
+    }
+    if self.immutable_ctx.options.checks.contains(EventKindSwitcher::ModuleExportsReassignment) {
+      self.report_module_exports_reassignment();
+    }
     let mut exports_kind = ExportsKind::None;
 
     if self.esm_export_keyword.is_some() {
       exports_kind = ExportsKind::Esm;
-      if let Some(start) = self.cjs_module_ident {
+      if let Some(span) = self.conditional_cjs_module_exports {
+        self.result.warnings.push(
+          BuildDiagnostic::conditional_module_exports_in_esm(
+            self.immutable_ctx.id.to_string(),
+            self.immutable_ctx.source.clone(),
+            self.esm_export_keyword.expect("should have start offset"),
+            span,
+          )
+          .with_severity_warning(),
+        );
+      } else if let Some(start) = self.cjs_module_ident {
         self.result.warnings.push(
           BuildDiagnostic::commonjs_variable_in_esm(
             self.immutable_ctx.id.to_string(),
@@ -347,6 +463,17 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
 
     self.result.exports_kind = exports_kind;
 
+    if matches!(exports_kind, ExportsKind::Esm) {
+      for span in std::mem::take(&mut self.arguments_callee_spans) {
+        self.result.errors.push(BuildDiagnostic::sloppy_mode_syntax_in_esm(
+          self.immutable_ctx.id.resource_id().clone(),
+          self.immutable_ctx.source.clone(),
+          span,
+          "arguments.callee",
+        ));
+      }
+    }
+
     // If some commonjs module facade exports was used locally, we need to explicitly mark them as
     // has side effects, so that they should not be removed in linking stage.
     let mut bailout_inlined_cjs_exports_symbol_ids = FxHashSet::default();
@@ -413,6 +540,29 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     self.esm_export_keyword.get_or_insert(span);
   }
 
+  /// The extension of `.cjs` and `.cts` files is only an interop hint, ESM syntax in them is still
+  /// bundled as ESM. This warns about it when the `EsmSyntaxInCommonJs` check is enabled.
+  fn check_esm_syntax_in_forced_commonjs(&mut self, syntax: &'static str, span: Span) {
+    if !self.immutable_ctx.options.checks.contains(EventKindSwitcher::EsmSyntaxInCommonJs) {
+      return;
+    }
+    let extension = match self.immutable_ctx.module_type {
+      ModuleDefFormat::CJS => ".cjs",
+      ModuleDefFormat::Cts => ".cts",
+      _ => return,
+    };
+    self.result.warnings.push(
+      BuildDiagnostic::esm_syntax_in_commonjs(
+        self.immutable_ctx.id.resource_id().clone(),
+        self.immutable_ctx.source.clone(),
+        span,
+        syntax,
+        extension,
+      )
+      .with_severity_warning(),
+    );
+  }
+
   fn declare_normal_symbol_ref(&mut self, id: SymbolId) {
     self
       .current_stmt_info
@@ -427,10 +577,27 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       .push(TaggedSymbolRef::LinkOnly((self.immutable_ctx.idx, id).into()));
   }
 
+  fn is_typescript_module(&self) -> bool {
+    std::path::Path::new(self.immutable_ctx.id.resource_id().as_str())
+      .extension()
+      .and_then(|ext| ext.to_str())
+      .is_some_and(|ext| matches!(ext, "ts" | "tsx" | "mts" | "cts"))
+  }
+
   fn get_root_binding(&self, name: &str) -> Option<SymbolId> {
     self.result.symbol_ref_db.scoping().get_root_binding(name)
   }
 
+  /// Whether the module request starts with one of the configured `virtualPrefixes`.
+  fn is_virtual_module_request(&self, module_request: &str) -> bool {
+    self
+      .immutable_ctx
+      .options
+      .virtual_prefixes
+      .iter()
+      .any(|prefix| module_request.starts_with(prefix.as_str()))
+  }
+
   /// `is_dummy` means if it the import record is created during ast transformation.
   fn add_import_record(
     &mut self,
@@ -466,6 +633,12 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     {
       rec.meta.insert(ImportRecordMeta::SafelyMergeCjsNs);
     }
+    let is_virtual = self.is_virtual_module_request(module_request);
+    rec.meta.set(ImportRecordMeta::IsVirtualModuleRequest, is_virtual);
+    rec
+      .meta
+      .set(ImportRecordMeta::IsBareSpecifier, !is_virtual && is_bare_specifier(module_request));
+    rec.meta.set(ImportRecordMeta::IsNativeAddon, module_request.ends_with(".node"));
 
     let id = self.result.import_records.push(rec);
     self.current_stmt_info.import_records.push(id);
@@ -525,8 +698,10 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         referenced: (self.immutable_ctx.idx, local).into(),
         span,
         came_from_commonjs: false,
+        is_conditional: false,
       },
     );
+    self.record_deprecated_export(local);
   }
 
   fn add_local_default_export(&mut self, local: SymbolId, span: Span) {
@@ -536,8 +711,167 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
 
     self.result.named_exports.insert(
       "default".into(),
-      LocalExport { referenced: symbol_ref, span, came_from_commonjs: false },
+      LocalExport {
+        referenced: symbol_ref,
+        span,
+        came_from_commonjs: false,
+        is_conditional: false,
+      },
     );
+    self.record_deprecated_export(local);
+  }
+
+  fn record_deprecated_export(&mut self, local: SymbolId) {
+    if let Some(deprecation) = &self.current_export_deprecation {
+      self.result.deprecated_exports.insert(local, deprecation.clone());
+    }
+  }
+
+  /// Extract the text following `@deprecated` in the JSDoc comment attached to the node, e.g.
+  /// `/** @deprecated Use `bar` instead */` gives "Use `bar` instead".
+  fn extract_jsdoc_deprecation(&self, node_span: Span) -> Option<ArcStr> {
+    let comments = self.immutable_ctx.comments;
+    let start = comments.partition_point(|comment| comment.attached_to < node_span.start);
+    let jsdoc = comments[start..]
+      .iter()
+      .take_while(|comment| comment.attached_to == node_span.start)
+      .filter(|comment| comment.is_jsdoc())
+      .last()?;
+    let text = jsdoc.span.source_text(self.immutable_ctx.source);
+    let (_, after_tag) = text.split_once("@deprecated")?;
+    let deprecation = after_tag
+      .trim_end_matches("*/")
+      .lines()
+      .map(|line| line.trim().trim_start_matches('*').trim())
+      .take_while(|line| !line.starts_with('@'))
+      .filter(|line| !line.is_empty())
+      .collect::<Vec<_>>()
+      .join(" ");
+    Some(deprecation.into())
+  }
+
+  /// Report exported function declarations that are never referenced within the module, apart from
+  /// `export { foo }` clauses. They might be dead code unless consumers of the module use them.
+  fn report_unused_exported_functions(&mut self) {
+    let ast_scopes = &self.result.symbol_ref_db.ast_scopes;
+    let scoping = ast_scopes.scoping();
+    let mut unused = self
+      .result
+      .named_exports
+      .values()
+      .filter(|local| !local.came_from_commonjs)
+      .map(|local| local.referenced.symbol)
+      .filter(|&symbol_id| {
+        // Anonymous `export default function() {}` is bound to a facade symbol
+        !ast_scopes.is_facade_symbol(symbol_id)
+          && scoping.symbol_flags(symbol_id).is_function()
+          && scoping
+            .get_resolved_reference_ids(symbol_id)
+            .iter()
+            .all(|reference_id| self.export_clause_references.contains(reference_id))
+      })
+      .collect::<FxHashSet<_>>()
+      .into_iter()
+      .map(|symbol_id| {
+        (scoping.symbol_span(symbol_id), ArcStr::from(scoping.symbol_name(symbol_id)))
+      })
+      .collect::<Vec<_>>();
+    unused.sort_unstable_by_key(|(span, _)| span.start);
+    for (span, name) in unused {
+      self.result.warnings.push(
+        BuildDiagnostic::unused_exported_function(
+          self.immutable_ctx.id.to_string(),
+          self.immutable_ctx.source.clone(),
+          span,
+          name,
+        )
+        .with_severity_warning(),
+      );
+    }
+  }
+
+  fn report_unused_function_params(&mut self) {
+    let exported_symbols = self
+      .result
+      .named_exports
+      .values()
+      .filter(|local| !local.came_from_commonjs)
+      .map(|local| local.referenced.symbol)
+      .collect::<FxHashSet<_>>();
+    for (symbol_id, params) in std::mem::take(&mut self.unused_trailing_params) {
+      if !exported_symbols.contains(&symbol_id) {
+        continue;
+      }
+      let function_name = ArcStr::from(self.result.symbol_ref_db.scoping().symbol_name(symbol_id));
+      self.result.warnings.push(
+        BuildDiagnostic::unused_function_parameter(
+          self.immutable_ctx.id.to_string(),
+          self.immutable_ctx.source.clone(),
+          function_name,
+          params,
+        )
+        .with_severity_warning(),
+      );
+    }
+  }
+
+  /// Report a `throw` statement directly in the module scope. It's reached whenever the module is
+  /// evaluated, unless a top-level `return` of a CommonJS module comes first.
+  fn report_module_always_throws(&mut self, program: &Program<'ast>) {
+    if self.result.ast_usage.contains(EcmaModuleAstUsage::TopLevelReturn) {
+      return;
+    }
+    let Some(span) = program.body.iter().find_map(|stmt| match stmt {
+      ast::Statement::ThrowStatement(throw_stmt) => Some(throw_stmt.span),
+      _ => None,
+    }) else {
+      return;
+    };
+    self.result.warnings.push(
+      BuildDiagnostic::module_always_throws(
+        self.immutable_ctx.id.to_string(),
+        self.immutable_ctx.source.clone(),
+        span,
+      )
+      .with_severity_warning(),
+    );
+  }
+
+  /// Report `exports.a = 1; module.exports = {}`, where the new `module.exports` drops `a`. The
+  /// other order, `module.exports = {}; module.exports.a = 1`, is fine. `module` and `exports` are
+  /// plain globals in an ES module, which is reported by `CommonJsVariableInEsm` instead.
+  fn report_module_exports_reassignment(&mut self) {
+    if self.esm_export_keyword.is_some() {
+      return;
+    }
+    let Some((export_span, reassignment_span)) = self.cjs_module_exports_reassignment else {
+      return;
+    };
+    self.result.warnings.push(
+      BuildDiagnostic::module_exports_reassignment(
+        self.immutable_ctx.id.to_string(),
+        self.immutable_ctx.source.clone(),
+        export_span,
+        reassignment_span,
+      )
+      .with_severity_warning(),
+    );
+  }
+
+  /// Detect the `//# sourceMappingURL=...` comment at the end of the module. The url either points
+  /// to an existing source map or carries the map inline as a `data:` url.
+  fn extract_source_mapping_url(&self) -> Option<ArcStr> {
+    let comment = self.immutable_ctx.comments.last()?;
+    let source = self.immutable_ctx.source.as_str();
+    if !source[comment.span.end as usize..].trim().is_empty() {
+      return None;
+    }
+    let text = comment.content_span().source_text(source).trim();
+    let url = text
+      .strip_prefix("# sourceMappingURL=")
+      .or_else(|| text.strip_prefix("@ sourceMappingURL="))?
+      .trim();
+    (!url.is_empty() && !url.contains(char::is_whitespace)).then(|| url.into())
   }
 
   /// Record `export { [imported] as [export_name] } from ...` statement.
@@ -600,6 +934,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         referenced: generated_imported_as_ref,
         span: name_import.span_imported,
         came_from_commonjs: false,
+        is_conditional: false,
       },
     );
     self.result.named_imports.insert(generated_imported_as_ref, name_import);
@@ -632,6 +967,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         referenced: generated_imported_as_ref,
         span: name_import.span_imported,
         came_from_commonjs: false,
+        is_conditional: false,
       },
     );
     self.result.named_imports.insert(generated_imported_as_ref, name_import);
@@ -664,7 +1000,46 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
 
   fn visit_function_decl(&mut self, it: &ast::Function<'ast>, flags: oxc::semantic::ScopeFlags) {
     self.current_stmt_info.meta.insert(StmtInfoMeta::FnDecl);
+    let arguments_references = self.arguments_references;
     walk::walk_function(self, it, flags);
+    // Parameters may still be read through `arguments` or a direct `eval` in the body
+    if self.immutable_ctx.options.checks.contains(EventKindSwitcher::UnusedFunctionParameter)
+      && self.arguments_references == arguments_references
+      && !it
+        .scope_id
+        .get()
+        .is_some_and(|scope_id| self.result.scopes_with_direct_eval.contains(&scope_id))
+    {
+      self.collect_unused_trailing_params(it);
+    }
+  }
+
+  fn collect_unused_trailing_params(&mut self, it: &ast::Function<'ast>) {
+    let Some(id) = &it.id else {
+      return;
+    };
+    let symbol_id = id.symbol_id();
+    if !self.is_root_symbol(symbol_id) {
+      return;
+    }
+    let scoping = self.result.symbol_ref_db.scoping();
+    let rest = it.params.rest.as_ref().map(|rest| &rest.argument);
+    let mut params = vec![];
+    // Only trailing parameters can be removed without shifting the positions of the others.
+    // Destructuring patterns and default values are kept, since they may have side effects.
+    for pattern in it.params.items.iter().map(|item| &item.pattern).chain(rest).rev() {
+      let BindingPatternKind::BindingIdentifier(ident) = &pattern.kind else {
+        break;
+      };
+      if !scoping.get_resolved_reference_ids(ident.symbol_id()).is_empty() {
+        break;
+      }
+      params.push((ArcStr::from(ident.name.as_str()), ident.span));
+    }
+    if !params.is_empty() {
+      params.reverse();
+      self.unused_trailing_params.push((symbol_id, params));
+    }
   }
 
   fn visit_class_decl(&mut self, it: &ast::Class<'ast>) {
@@ -709,14 +1084,28 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       }
     } else {
       decl.specifiers.iter().for_each(|spec| {
+        if let ast::ModuleExportName::IdentifierReference(ident) = &spec.local
+          && let Some(reference_id) = ident.reference_id.get()
+        {
+          self.export_clause_references.insert(reference_id);
+        }
         if let Some(local_symbol_id) = self.get_root_binding(spec.local.name().as_str()) {
           self.add_local_export(spec.exported.name().as_str(), local_symbol_id, spec.span);
         } else {
+          // Type-only bindings are erased by the TypeScript transform before scanning, so
+          // `export { SomeType }` ends up here instead of becoming a broken value export.
+          let local_name = spec.local.name();
+          let note = self.is_typescript_module().then(|| {
+            format!(
+              "If `{local_name}` is a type, use `export type {{ {local_name} }}` to export it. Types are erased during the build and can't be exported as values."
+            )
+          });
           self.result.errors.push(BuildDiagnostic::export_undefined_variable(
             self.immutable_ctx.id.to_string(),
             self.immutable_ctx.source.clone(),
             spec.local.span(),
-            ArcStr::from(spec.local.name().as_str()),
+            ArcStr::from(local_name.as_str()),
+            note,
           ));
         }
       });
@@ -728,6 +1117,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
                 self.add_local_export(&id.name, id.expect_symbol_id(), id.span);
               });
               if let BindingPatternKind::BindingIdentifier(ref binding) = decl.id.kind {
+                self.try_diagnostic_require_in_esm_export(&binding.name, decl.init.as_ref());
                 let symbol_id = binding.symbol_id();
                 if let Some(value) = self.extract_constant_value_from_expr(decl.init.as_ref()) {
                   self.add_constant_symbol(symbol_id, ConstExportMeta::new(value, false));
@@ -837,6 +1227,14 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
   }
 
   fn scan_import_decl(&mut self, decl: &ImportDeclaration) {
+    if let Some(rec_id) = self.try_merge_into_existing_import_record(decl) {
+      self.result.import_records[rec_id].state.merged_spans.push(decl.source.span());
+      self.result.imports.insert(decl.span, rec_id);
+      self.current_stmt_info.import_records.push(rec_id);
+      self.scan_import_specifiers(decl, rec_id);
+      return;
+    }
+
     let rec_id = self.add_import_record(
       decl.source.value.as_str(),
       ImportKind::Import,
@@ -847,6 +1245,9 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         ImportRecordMeta::empty()
       },
     );
+    if Self::is_mergeable_import_decl(decl) {
+      self.named_import_decl_records.insert(decl.source.value.as_str().into(), rec_id);
+    }
 
     if let Some(ref with_clause) = decl.with_clause {
       self
@@ -860,7 +1261,40 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       self.result.import_records[rec_id].meta.insert(ImportRecordMeta::IsPlainImport);
     }
 
+    self.scan_import_specifiers(decl, rec_id);
+  }
+
+  /// Only declarations with default or named specifiers are merged. Plain imports, namespace
+  /// imports and imports with attributes keep their own records, since they affect how the
+  /// statement is rendered.
+  fn is_mergeable_import_decl(decl: &ImportDeclaration) -> bool {
+    decl.with_clause.is_none()
+      && !decl.source.span().is_empty()
+      && decl.specifiers.as_ref().is_some_and(|specifiers| {
+        !specifiers.is_empty()
+          && specifiers.iter().all(|spec| {
+            !matches!(spec, ast::ImportDeclarationSpecifier::ImportNamespaceSpecifier(_))
+          })
+      })
+  }
+
+  /// `import { a } from './m'; import { b } from './m'` share one import record, with the named
+  /// imports of both declarations bound to it. Each declaration still maps its own span to the
+  /// record in `imports`.
+  fn try_merge_into_existing_import_record(
+    &self,
+    decl: &ImportDeclaration,
+  ) -> Option<ImportRecordIdx> {
+    if !Self::is_mergeable_import_decl(decl) {
+      return None;
+    }
+    self.named_import_decl_records.get(decl.source.value.as_str()).copied()
+  }
+
+  fn scan_import_specifiers(&mut self, decl: &ImportDeclaration, rec_id: ImportRecordIdx) {
     let Some(specifiers) = &decl.specifiers else { return };
+    // Malformed specifiers like `import { x = 1 } from './m'` never reach here, they are reported as
+    // `PARSE_ERROR` by the parser. So every specifier is guaranteed to have a bound local symbol.
     specifiers.iter().for_each(|spec| match spec {
       ast::ImportDeclarationSpecifier::ImportSpecifier(spec) => {
         let sym = spec.local.expect_symbol_id();
@@ -880,20 +1314,32 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
   fn scan_module_decl(&mut self, decl: &ModuleDeclaration<'ast>) {
     match decl {
       ast::ModuleDeclaration::ImportDeclaration(decl) => {
-        self.esm_import_keyword.get_or_insert(Span::new(decl.span.start, decl.span.start + 6));
+        let keyword = Span::new(decl.span.start, decl.span.start + 6);
+        self.esm_import_keyword.get_or_insert(keyword);
+        self.check_esm_syntax_in_forced_commonjs("import", keyword);
         self.scan_import_decl(decl);
       }
       ast::ModuleDeclaration::ExportAllDeclaration(decl) => {
-        self.set_esm_export_keyword(Span::new(decl.span.start, decl.span.start + 6));
+        let keyword = Span::new(decl.span.start, decl.span.start + 6);
+        self.set_esm_export_keyword(keyword);
+        self.check_esm_syntax_in_forced_commonjs("export", keyword);
         self.scan_export_all_decl(decl);
       }
       ast::ModuleDeclaration::ExportNamedDeclaration(decl) => {
-        self.set_esm_export_keyword(Span::new(decl.span.start, decl.span.start + 6));
+        let keyword = Span::new(decl.span.start, decl.span.start + 6);
+        self.set_esm_export_keyword(keyword);
+        self.check_esm_syntax_in_forced_commonjs("export", keyword);
+        self.current_export_deprecation = self.extract_jsdoc_deprecation(decl.span);
         self.scan_export_named_decl(decl);
+        self.current_export_deprecation = None;
       }
       ast::ModuleDeclaration::ExportDefaultDeclaration(decl) => {
-        self.set_esm_export_keyword(Span::new(decl.span.start, decl.span.start + 6));
+        let keyword = Span::new(decl.span.start, decl.span.start + 6);
+        self.set_esm_export_keyword(keyword);
+        self.check_esm_syntax_in_forced_commonjs("export", keyword);
+        self.current_export_deprecation = self.extract_jsdoc_deprecation(decl.span);
         self.scan_export_default_decl(decl);
+        self.current_export_deprecation = None;
         match &decl.declaration {
           ast::ExportDefaultDeclarationKind::ClassDeclaration(class) => {
             self.visit_class_decl(class);
@@ -947,6 +1393,93 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     None
   }
 
+  /// `export const x = require('./y')` mixes an ESM export with a CommonJS require, the value of
+  /// `x` depends on how the required module is interop-ed.
+  fn try_diagnostic_require_in_esm_export(
+    &mut self,
+    name: &str,
+    init: Option<&Expression<'ast>>,
+  ) -> Option<()> {
+    let Expression::CallExpression(call_expr) = init?.without_parentheses() else {
+      return None;
+    };
+    let Expression::Identifier(callee) = &call_expr.callee else {
+      return None;
+    };
+    if callee.name != "require"
+      || !self.is_global_identifier_reference(callee)
+      || !matches!(call_expr.arguments.first(), Some(ast::Argument::StringLiteral(_)))
+    {
+      return None;
+    }
+    self.result.warnings.push(
+      BuildDiagnostic::require_in_esm_export(
+        self.immutable_ctx.id.to_string(),
+        self.immutable_ctx.source.clone(),
+        call_expr.span,
+        name.into(),
+      )
+      .with_severity_warning(),
+    );
+    None
+  }
+
+  /// Node.js globals referenced under `platform: 'browser'` will be `undefined` at runtime unless
+  /// they are provided by `define` or `inject`. Reads through the global object, e.g.
+  /// `globalThis.process`, are treated the same as the bare reference.
+  fn try_diagnostic_node_global_in_browser(&mut self, id_ref: &IdentifierReference) -> Option<()> {
+    let options = self.immutable_ctx.options;
+    if !matches!(options.platform, Platform::Browser)
+      || self.immutable_ctx.id.as_ref() == RUNTIME_MODULE_KEY
+    {
+      return None;
+    }
+    let parent = self.visit_path.last();
+    let (name, span, member_path, guard) = match parent {
+      Some(AstKind::StaticMemberExpression(member_expr))
+        if matches!(id_ref.name.as_str(), "globalThis" | "window" | "self" | "global")
+          && is_node_specific_global_ident_ref(&member_expr.property.name) =>
+      {
+        let name = member_expr.property.name.as_str();
+        let member_path = concat_string!(id_ref.name.as_str(), ".", name);
+        (name, member_expr.span, Some(member_path), self.visit_path.iter().rev().nth(1))
+      }
+      _ => {
+        if !is_node_specific_global_ident_ref(&id_ref.name) {
+          return None;
+        }
+        (id_ref.name.as_str(), id_ref.span, None, parent)
+      }
+    };
+    // `typeof process !== 'undefined'` is the common way to guard the access.
+    if let Some(AstKind::UnaryExpression(unary_expr)) = guard
+      && unary_expr.operator == ast::UnaryOperator::Typeof
+    {
+      return None;
+    }
+    let is_covered_by_define =
+      options.define.iter().any(|(key, _)| key == name || Some(key) == member_path.as_ref());
+    let is_covered_by_inject = options.inject.iter().any(|inject| match inject {
+      InjectImport::Named { imported, alias, .. } => {
+        alias.as_deref().unwrap_or(imported.as_str()) == name
+      }
+      InjectImport::Namespace { alias, .. } => alias == name,
+    });
+    if is_covered_by_define || is_covered_by_inject {
+      return None;
+    }
+    self.result.warnings.push(
+      BuildDiagnostic::node_global_in_browser(
+        self.immutable_ctx.id.to_string(),
+        self.immutable_ctx.source.clone(),
+        span,
+        name.into(),
+      )
+      .with_severity_warning(),
+    );
+    None
+  }
+
   /// return a `Some(SymbolRef)` if the identifier referenced a top level `IdentBinding`
   fn resolve_identifier_reference(&self, ident: &IdentifierReference) -> IdentifierReferenceKind {
     match self.resolve_symbol_from_reference(ident) {
@@ -988,6 +1521,30 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     (!props.is_empty()).then_some((span, props))
   }
 
+  /// Extract how the value of a `require(...)` call is used, the `visit_path` should end with the
+  /// `require(...)` call expression.
+  pub fn extract_require_usage(&self) -> RequireUsage {
+    let mut props = vec![];
+    let mut ancestors = self.visit_path.iter().rev().skip(1).peekable();
+    while let Some(ancestor_ast) = ancestors.next() {
+      let name = match ancestor_ast {
+        AstKind::ParenthesizedExpression(_) => continue,
+        AstKind::StaticMemberExpression(expr) => Some(expr.property.name),
+        AstKind::ComputedMemberExpression(expr) => expr.static_property_name(),
+        _ => break,
+      };
+      // `require('./a').b = 1` writes the property rather than reading it
+      let is_assigned = ancestor_ast.as_member_expression_kind().is_some_and(|member_expr| {
+        ancestors.peek().is_some_and(|parent| member_expr.is_assigned_to_in_parent(parent))
+      });
+      match name {
+        Some(name) if !is_assigned => props.push(name.as_str().into()),
+        _ => break,
+      }
+    }
+    if props.is_empty() { RequireUsage::Opaque } else { RequireUsage::PropertyChain(props) }
+  }
+
   // `console` in `console.log` is a global reference
   pub fn is_global_identifier_reference(&self, ident: &IdentifierReference) -> bool {
     let symbol_id = self.resolve_symbol_from_reference(ident);
@@ -1003,6 +1560,116 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         .any(|flags| flags.contains(ScopeFlags::Function) && !flags.contains(ScopeFlags::Arrow))
   }
 
+  /// If current position is nested in a control flow of the current function or the top level,
+  /// e.g. `if`, `switch`, loops, the conditional and logical expression.
+  pub fn is_in_conditional_control_flow(&self) -> bool {
+    for kind in self.visit_path.iter().rev() {
+      match kind {
+        AstKind::IfStatement(_)
+        | AstKind::SwitchStatement(_)
+        | AstKind::ForStatement(_)
+        | AstKind::ForInStatement(_)
+        | AstKind::ForOfStatement(_)
+        | AstKind::WhileStatement(_)
+        | AstKind::DoWhileStatement(_)
+        | AstKind::CatchClause(_)
+        | AstKind::ConditionalExpression(_)
+        | AstKind::LogicalExpression(_) => return true,
+        AstKind::ArrowFunctionExpression(_) | AstKind::FunctionBody(_) | AstKind::Function(_) => {
+          return false;
+        }
+        _ => {}
+      }
+    }
+    false
+  }
+
+  /// Record `import.meta.env` references if the `importMetaEnv` option is configured, the
+  /// `visit_path` should end with the parent of the `import.meta` meta property.
+  pub fn record_import_meta_env_reference(&mut self, meta: &ast::MetaProperty<'ast>) -> bool {
+    let import_meta_env = &self.immutable_ctx.options.import_meta_env;
+    if import_meta_env.is_empty() || meta.meta.name != "import" || meta.property.name != "meta" {
+      return false;
+    }
+    let mut ancestors = self.visit_path.iter().rev();
+    let Some(AstKind::StaticMemberExpression(env_expr)) = ancestors.next() else {
+      return false;
+    };
+    if env_expr.property.name != "env" {
+      return false;
+    }
+    let reference = match ancestors.next() {
+      Some(AstKind::StaticMemberExpression(member_expr))
+        if member_expr.object.span() == env_expr.span
+          && import_meta_env.contains_key(member_expr.property.name.as_str()) =>
+      {
+        (member_expr.span, Some(member_expr.property.name.as_str().into()))
+      }
+      _ => (env_expr.span, None),
+    };
+    self.result.import_meta_env_references.insert(reference.0, reference.1);
+    true
+  }
+
+  /// Record `import.meta` destructured by a variable declaration, e.g. `const { url } = import.meta`.
+  /// Returns `true` if all destructured properties could be polyfilled, so the `import.meta` isn't
+  /// left empty for non-esm output.
+  pub fn record_destructured_import_meta(&mut self, meta: &ast::MetaProperty<'ast>) -> bool {
+    if meta.meta.name != "import" || meta.property.name != "meta" {
+      return false;
+    }
+    let Some(AstKind::VariableDeclarator(decl)) = self.visit_path.last() else {
+      return false;
+    };
+    let BindingPatternKind::ObjectPattern(pattern) = &decl.id.kind else {
+      return false;
+    };
+    if decl.init.as_ref().is_none_or(|init| init.span() != meta.span) {
+      return false;
+    }
+    let keys = pattern
+      .properties
+      .iter()
+      .filter_map(|prop| prop.key.static_name())
+      .map(|name| CompactStr::new(&name))
+      .collect::<Vec<_>>();
+    if keys.iter().any(|key| key == "url") {
+      self.result.ast_usage.insert(EcmaModuleAstUsage::ImportMetaUrl);
+    }
+    let all_polyfillable = pattern.rest.is_none()
+      && keys.len() == pattern.properties.len()
+      && keys.iter().all(|key| matches!(key.as_str(), "url" | "dirname" | "filename"));
+    self.result.destructured_import_meta.insert(meta.span, keys);
+    all_polyfillable
+  }
+
+  /// If current `import(...)` is the awaited initializer of a top level variable declaration, e.g.
+  /// `const m = await import('./x')`. The `visit_path` should end with the parent of the
+  /// `import(...)` expression.
+  pub fn is_hoistable_dynamic_import(&self) -> bool {
+    let mut ancestors = self
+      .visit_path
+      .iter()
+      .rev()
+      .filter(|kind| !matches!(kind, AstKind::ParenthesizedExpression(_)));
+    if !matches!(ancestors.next(), Some(AstKind::AwaitExpression(_))) {
+      return false;
+    }
+    if !matches!(ancestors.next(), Some(AstKind::VariableDeclarator(_))) {
+      return false;
+    }
+    if !matches!(ancestors.next(), Some(AstKind::VariableDeclaration(_))) {
+      return false;
+    }
+    match ancestors.next() {
+      Some(AstKind::Program(_)) => true,
+      Some(AstKind::ExportNamedDeclaration(_)) => {
+        matches!(ancestors.next(), Some(AstKind::Program(_)))
+      }
+      _ => false,
+    }
+  }
+
   pub fn in_side_try_catch_block(&self) -> bool {
     for kind in self.visit_path.iter().rev() {
       match kind {
@@ -1070,3 +1737,1054 @@ pub enum IdentifierReferenceKind {
   /// rest
   Other,
 }
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use oxc::ast::ast::Statement;
+  use oxc::span::{CompactStr, SourceType, Span};
+  use rolldown_common::{
+    DEFAULT_MAX_MEMBER_EXPR_CHAIN_LENGTH, EcmaModuleAstUsage, EcmaViewMeta, ExperimentalOptions,
+    ExportsKind, FlatOptions, ImportKind, ImportRecordMeta, ModuleDefFormat, ModuleId, ModuleIdx,
+    ModuleType, NormalizedBundlerOptions, NormalizedOptimizationConfig, OutputFormat,
+    SideEffectDetail, StmtInfoMeta, SymbolOrMemberExprRef,
+  };
+  use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
+  use rolldown_error::{EventKind, EventKindSwitcher};
+  use rustc_hash::FxHashSet;
+
+  use super::{AstScanner, RequireUsage, ScanResult};
+  use crate::{
+    types::oxc_parse_type::OxcParseType, utils::pre_process_ecma_ast::PreProcessEcmaAst,
+  };
+
+  fn scan(code: &str) -> ScanResult {
+    scan_with_module_type(code, ModuleDefFormat::Unknown)
+  }
+
+  fn scan_with_module_type(code: &str, module_type: ModuleDefFormat) -> ScanResult {
+    let ast = EcmaCompiler::parse("<Noop>", code, SourceType::default()).unwrap();
+    scan_ast(&ast, module_type, NormalizedBundlerOptions::default())
+  }
+
+  fn scan_ast(
+    ast: &EcmaAst,
+    module_type: ModuleDefFormat,
+    options: NormalizedBundlerOptions,
+  ) -> ScanResult {
+    let scoping = ast.make_scoping();
+    let options = Arc::new(options);
+    let flat_options = FlatOptions::from_shared_options(&options);
+    let id = ModuleId::new("<Noop>");
+    let scanner = AstScanner::new(
+      ModuleIdx::new(0),
+      scoping,
+      "noop",
+      module_type,
+      ast.source(),
+      &id,
+      ast.comments(),
+      &options,
+      ast.allocator(),
+      flat_options,
+    );
+    scanner.scan(ast.program()).unwrap()
+  }
+
+  fn scan_with_options(code: &str, options: NormalizedBundlerOptions) -> ScanResult {
+    let ast = EcmaCompiler::parse("<Noop>", code, SourceType::default()).unwrap();
+    scan_ast(&ast, ModuleDefFormat::Unknown, options)
+  }
+
+  /// The rendered warnings of `kind`, with only the check of `kind` enabled.
+  fn check_warnings(code: &str, kind: EventKind) -> Vec<String> {
+    let checks = EventKindSwitcher::from_bits_truncate(1 << kind as u32);
+    scan_with_options(code, NormalizedBundlerOptions { checks, ..Default::default() })
+      .warnings
+      .iter()
+      .filter(|warning| warning.kind() as u32 == kind as u32)
+      .map(|warning| warning.to_diagnostic().to_string())
+      .collect()
+  }
+
+  fn require_usage_of(result: &ScanResult, module_request: &str) -> RequireUsage {
+    let (span, _) = result
+      .imports
+      .iter()
+      .find(|(_, rec_idx)| result.import_records[**rec_idx].module_request == module_request)
+      .expect("should have the import record");
+    result.require_usage[span].clone()
+  }
+
+  #[test]
+  fn require_property_chain() {
+    let result = scan("require('./a').b; console.log(require('./b').c['d']);");
+    assert_eq!(require_usage_of(&result, "./a"), RequireUsage::PropertyChain(vec!["b".into()]));
+    assert_eq!(
+      require_usage_of(&result, "./b"),
+      RequireUsage::PropertyChain(vec!["c".into(), "d".into()])
+    );
+  }
+
+  #[test]
+  fn require_opaque_usage() {
+    let result = scan("require('./a')(); const b = require('./b'); require('./c')[key];");
+    assert_eq!(require_usage_of(&result, "./a"), RequireUsage::Opaque);
+    assert_eq!(require_usage_of(&result, "./b"), RequireUsage::Opaque);
+    assert_eq!(require_usage_of(&result, "./c"), RequireUsage::Opaque);
+
+    // Assignment targets are written rather than read
+    let result = scan("require('./a').b = 1; require('./b').c.d = 1; require('./c').e++;");
+    assert_eq!(require_usage_of(&result, "./a"), RequireUsage::Opaque);
+    assert_eq!(require_usage_of(&result, "./b"), RequireUsage::PropertyChain(vec!["c".into()]));
+    assert_eq!(require_usage_of(&result, "./c"), RequireUsage::Opaque);
+  }
+
+  #[test]
+  fn esm_syntax_in_forced_commonjs() {
+    let code = "import a from './a'; export const x = a;";
+    let ast = EcmaCompiler::parse("<Noop>", code, SourceType::default()).unwrap();
+    let esm_syntax_warnings = |module_type: ModuleDefFormat, checks: EventKindSwitcher| {
+      let options = NormalizedBundlerOptions { checks, ..Default::default() };
+      let result = scan_ast(&ast, module_type, options);
+      assert!(result.errors.is_empty());
+      result
+        .warnings
+        .iter()
+        .filter(|warning| matches!(warning.kind(), EventKind::EsmSyntaxInCommonJs))
+        .count()
+    };
+    for module_type in [ModuleDefFormat::CJS, ModuleDefFormat::Cts] {
+      assert_eq!(esm_syntax_warnings(module_type, EventKindSwitcher::EsmSyntaxInCommonJs), 2);
+      // The check is opt-in
+      assert_eq!(esm_syntax_warnings(module_type, EventKindSwitcher::empty()), 0);
+    }
+    for module_type in
+      [ModuleDefFormat::EsmMjs, ModuleDefFormat::CjsPackageJson, ModuleDefFormat::Unknown]
+    {
+      assert_eq!(esm_syntax_warnings(module_type, EventKindSwitcher::EsmSyntaxInCommonJs), 0);
+    }
+  }
+
+  #[test]
+  fn non_static_dynamic_import_exprs() {
+    let code = "import('./x'); import(`./y`); import(variable); import(/* @vite-ignore */ other);";
+    let result = scan(code);
+    assert_eq!(result.dynamic_import_exprs, vec![Span::new(30, 46)]);
+  }
+
+  fn dynamic_import_meta_of(result: &ScanResult, module_request: &str) -> ImportRecordMeta {
+    result
+      .import_records
+      .iter()
+      .find(|rec| rec.module_request == module_request)
+      .expect("should have the import record")
+      .meta
+  }
+
+  #[test]
+  fn hoistable_dynamic_import() {
+    let result = scan(
+      "const a = await import('./a'); export const { b } = (await import('./b'));
+      const c = await import('./c').then((m) => m); { const d = await import('./d') }
+      async function f() { const e = await import('./e') }",
+    );
+    let is_hoistable = |module_request| {
+      dynamic_import_meta_of(&result, module_request)
+        .contains(ImportRecordMeta::IsHoistableDynamicImport)
+    };
+    assert!(is_hoistable("./a"));
+    assert!(is_hoistable("./b"));
+    assert!(!is_hoistable("./c"));
+    assert!(!is_hoistable("./d"));
+    assert!(!is_hoistable("./e"));
+  }
+
+  /// Returns whether `ns` is referenced as a whole, and the property chains accessed on it.
+  fn namespace_usage(result: &ScanResult) -> (bool, Vec<Vec<String>>) {
+    let (ns_ref, _) = result.named_imports.first().expect("should have the namespace import");
+    let mut is_referenced_as_whole = false;
+    let mut member_chains = vec![];
+    for (_, stmt_info) in result.stmt_infos.iter_enumerated_without_namespace_stmt() {
+      for reference in &stmt_info.referenced_symbols {
+        match reference {
+          SymbolOrMemberExprRef::Symbol(symbol_ref) if symbol_ref == ns_ref => {
+            is_referenced_as_whole = true;
+          }
+          SymbolOrMemberExprRef::MemberExpr(member_expr) if member_expr.object_ref == *ns_ref => {
+            member_chains.push(
+              member_expr.prop_and_span_list.iter().map(|(prop, _)| prop.to_string()).collect(),
+            );
+          }
+          _ => {}
+        }
+      }
+    }
+    (is_referenced_as_whole, member_chains)
+  }
+
+  #[test]
+  fn computed_member_expr_on_namespace_import() {
+    let chains = |chains: &[&[&str]]| {
+      chains
+        .iter()
+        .map(|chain| chain.iter().map(ToString::to_string).collect::<Vec<_>>())
+        .collect::<Vec<_>>()
+    };
+    for (code, expected) in [
+      ("import * as ns from './a'; ns['foo'];", chains(&[&["foo"]])),
+      ("import * as ns from './a'; ns[`foo`];", chains(&[&["foo"]])),
+      ("import * as ns from './a'; ns.a['b'].c;", chains(&[&["a", "b", "c"]])),
+      ("import * as ns from './a'; ns['a'][key];", chains(&[&["a"]])),
+    ] {
+      assert_eq!(namespace_usage(&scan(code)), (false, expected), "{code}");
+    }
+    // A key that isn't a literal may read any export
+    for code in [
+      "import * as ns from './a'; ns[key];",
+      "import * as ns from './a'; ns[key].a;",
+      "import * as ns from './a'; ns[`${key}`];",
+    ] {
+      assert_eq!(namespace_usage(&scan(code)), (true, vec![]), "{code}");
+    }
+  }
+
+  #[test]
+  fn anonymous_default_export_name() {
+    let default_export_name = |code: &str| {
+      let result = scan(code);
+      assert!(result.named_exports.contains_key("default"), "{code}");
+      result.symbol_ref_db.scoping().symbol_name(result.default_export_ref.symbol).to_string()
+    };
+    // Named after the module, i.e. `noop` in these tests
+    for code in [
+      "export default function() {}",
+      "export default class {}",
+      "export default () => {}",
+      "export default 1 + 1",
+      // The scanner keeps the derived name even if a local binding takes it, the chunk renamer
+      // deconflicts them. See the `topics/deconflict/anonymous_default_export` fixture.
+      "export default function() {}; const noop_default = 1;",
+    ] {
+      assert_eq!(default_export_name(code), "noop_default", "{code}");
+    }
+    assert_eq!(default_export_name("export default function foo() {}"), "foo");
+    assert_eq!(default_export_name("export default class Foo {}"), "Foo");
+  }
+
+  #[test]
+  fn iterate_over_namespace_import() {
+    for code in [
+      "import * as ns from './a'; for (const k in ns) {}",
+      "import * as ns from './a'; for (const v of ns) {}",
+    ] {
+      assert_eq!(namespace_usage(&scan(code)), (true, vec![]));
+    }
+    for code in [
+      "import * as ns from './a'; for (const k in ns.obj) {}",
+      "import * as ns from './a'; for (const v of ns.obj) {}",
+    ] {
+      assert_eq!(namespace_usage(&scan(code)), (false, vec![vec!["obj".to_string()]]));
+    }
+  }
+
+  #[test]
+  fn deprecated_exports() {
+    let result = scan(
+      "/** @deprecated Use `b` instead. */
+      export const a = 1;
+      /**
+       * Some description.
+       * @deprecated Will be removed
+       *   in the next major.
+       * @see c
+       */
+      export function b() {}
+      /** Not deprecated. */
+      export const c = 1;
+      /** @deprecated */
+      export default class {}",
+    );
+    let deprecation_of = |name: &str| {
+      let local = result.named_exports[name].referenced.symbol;
+      result.deprecated_exports.get(&local).map(ToString::to_string)
+    };
+    assert_eq!(deprecation_of("a").as_deref(), Some("Use `b` instead."));
+    assert_eq!(deprecation_of("b").as_deref(), Some("Will be removed in the next major."));
+    assert_eq!(deprecation_of("c"), None);
+    assert_eq!(deprecation_of("default").as_deref(), Some(""));
+  }
+
+  #[test]
+  fn source_mapping_url() {
+    let inline = "data:application/json;base64,eyJ2ZXJzaW9uIjozfQ==";
+    let result = scan(&format!("console.log(1);\n//# sourceMappingURL={inline}\n"));
+    assert_eq!(result.source_mapping_url.as_deref(), Some(inline));
+
+    let result = scan("console.log(1);\n/*# sourceMappingURL=main.js.map */");
+    assert_eq!(result.source_mapping_url.as_deref(), Some("main.js.map"));
+
+    let result = scan("console.log(1);\n// a normal comment\n");
+    assert_eq!(result.source_mapping_url, None);
+
+    // Only the comment at the end of the module counts.
+    let result = scan("//# sourceMappingURL=main.js.map\nconsole.log(1);");
+    assert_eq!(result.source_mapping_url, None);
+  }
+
+  #[test]
+  fn exports_and_module_value_reads() {
+    for code in ["console.log(Object.keys(exports))", "function f() { return exports }"] {
+      let result = scan(code);
+      assert!(matches!(result.exports_kind, ExportsKind::CommonJs), "{code}");
+      assert!(result.ast_usage.contains(EcmaModuleAstUsage::UnknownExportsRead), "{code}");
+    }
+
+    let result = scan("console.log(typeof module)");
+    assert!(matches!(result.exports_kind, ExportsKind::CommonJs));
+    assert!(result.ast_usage.contains(EcmaModuleAstUsage::ModuleRef));
+
+    // A local `exports` binding isn't the CommonJS `exports` object.
+    let result = scan("const exports = {}; console.log(Object.keys(exports))");
+    assert!(matches!(result.exports_kind, ExportsKind::None));
+    assert!(!result.ast_usage.intersects(EcmaModuleAstUsage::ModuleOrExports));
+  }
+
+  #[test]
+  fn merge_import_decls_of_same_module() {
+    let result = scan(
+      "import { a } from './m';
+      import b, { c } from './m';
+      import * as ns from './m';
+      import './m';
+      console.log(a, b, c, ns);",
+    );
+    // The namespace and plain imports keep their own records.
+    assert_eq!(result.import_records.len(), 3);
+    let mut decls = result.imports.iter().collect::<Vec<_>>();
+    decls.sort_unstable_by_key(|(span, _)| span.start);
+    assert_eq!(decls.len(), 4);
+    let merged = *decls[0].1;
+    assert_eq!(*decls[1].1, merged);
+    assert_ne!(*decls[2].1, merged);
+    assert_eq!(result.named_imports.values().filter(|named| named.record_id == merged).count(), 3);
+    // The source span of the merged declaration is kept for diagnostics.
+    assert_eq!(result.import_records[merged].merged_spans.len(), 1);
+  }
+
+  #[test]
+  fn nested_export_is_an_error() {
+    // The parser rejects `{ export const x = 1 }`, so move a top-level export into a block by hand
+    // like a plugin returning a modified AST could.
+    let mut ast =
+      EcmaCompiler::parse("<Noop>", "{}\nexport const x = 1;", SourceType::default()).unwrap();
+    ast.program.with_mut(|fields| {
+      let export_stmt = fields.program.body.pop().unwrap();
+      let Statement::BlockStatement(block) = &mut fields.program.body[0] else {
+        unreachable!("the first statement should be a block")
+      };
+      block.body.push(export_stmt);
+    });
+    let result = scan_ast(&ast, ModuleDefFormat::Unknown, NormalizedBundlerOptions::default());
+    assert_eq!(result.errors.len(), 1);
+    assert!(matches!(result.errors[0].kind(), EventKind::NestedExportError));
+    assert!(result.named_exports.is_empty());
+
+    let result = scan("export const x = 1;");
+    assert!(result.errors.is_empty());
+    assert!(result.named_exports.contains_key("x"));
+  }
+
+  #[test]
+  fn virtual_module_request() {
+    let code = "import a from 'virtual:my-mod'; import b from './real'; import c from 'react';";
+    let options =
+      NormalizedBundlerOptions { virtual_prefixes: vec!["virtual:".into()], ..Default::default() };
+    let result = scan_with_options(code, options);
+    let meta_of = |module_request: &str| {
+      result.import_records.iter().find(|rec| rec.module_request == module_request).unwrap().meta
+    };
+    assert!(meta_of("virtual:my-mod").contains(ImportRecordMeta::IsVirtualModuleRequest));
+    assert!(!meta_of("virtual:my-mod").contains(ImportRecordMeta::IsBareSpecifier));
+    assert!(!meta_of("./real").contains(ImportRecordMeta::IsVirtualModuleRequest));
+    assert!(!meta_of("react").contains(ImportRecordMeta::IsVirtualModuleRequest));
+
+    let result = scan(code);
+    assert!(
+      result
+        .import_records
+        .iter()
+        .all(|rec| !rec.meta.contains(ImportRecordMeta::IsVirtualModuleRequest))
+    );
+  }
+
+  #[test]
+  fn commonjs_export_assignment_used_as_value() {
+    let result = scan("const x = (exports.y = f()); console.log(x);");
+    assert!(matches!(result.exports_kind, ExportsKind::CommonJs));
+    assert!(result.commonjs_exports.contains_key("y"));
+    let (_, decl_stmt) = result.stmt_infos.iter_enumerated_without_namespace_stmt().next().unwrap();
+    assert!(decl_stmt.side_effect.contains(SideEffectDetail::Unknown | SideEffectDetail::PureCjs));
+  }
+
+  #[test]
+  fn imports_referenced_at_init() {
+    let result = scan(
+      "import a from './a';
+      import b from './b';
+      import { c, d, e } from './c';
+      console.log(a);
+      function f() { return b }
+      const g = () => c;
+      class H { static { d } }
+      export { e };",
+    );
+    let scoping = result.symbol_ref_db.scoping();
+    let mut names = result
+      .imports_referenced_at_init
+      .keys()
+      .map(|&symbol_id| scoping.symbol_name(symbol_id).to_string())
+      .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, ["a", "d"]);
+  }
+
+  #[test]
+  fn constant_folded_module_requests() {
+    let result = scan(
+      "require('./a' + '.js');
+      require(('./b' + `.js`));
+      import('./c' + '.js');
+      require('./' + variable);",
+    );
+    let requests =
+      result.import_records.iter().map(|rec| rec.module_request.as_str()).collect::<Vec<_>>();
+    assert_eq!(requests, ["./a.js", "./b.js", "./c.js"]);
+    let warnings =
+      result.warnings.iter().map(|warning| warning.kind().to_string()).collect::<Vec<_>>();
+    assert_eq!(warnings, ["DYNAMIC_REQUIRE"]);
+  }
+
+  #[test]
+  fn dynamic_requires() {
+    let code = "require(name);
+      require('./locale/' + lang);
+      require(`./locale/${lang}.js`);
+      require(name + '.js');
+      require('./a');";
+    let result = scan(code);
+    let arguments = result
+      .dynamic_requires
+      .iter()
+      .map(|span| &code[span.start as usize..span.end as usize])
+      .collect::<Vec<_>>();
+    assert_eq!(arguments, ["name", "'./locale/' + lang", "`./locale/${lang}.js`", "name + '.js'"]);
+
+    let is_prefixed_concatenation = result
+      .warnings
+      .iter()
+      .map(|warning| {
+        warning.to_diagnostic().to_string().contains("Only the prefix of this path is known")
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(is_prefixed_concatenation, [false, true, true, false]);
+  }
+
+  #[test]
+  fn direct_and_indirect_eval() {
+    let has_eval = |code: &str| {
+      let result = scan(code);
+      let stmt_has_eval = result
+        .stmt_infos
+        .iter_enumerated_without_namespace_stmt()
+        .any(|(_, stmt_info)| stmt_info.meta.contains(StmtInfoMeta::HasEval));
+      assert_eq!(stmt_has_eval, result.ecma_view_meta.contains(EcmaViewMeta::Eval));
+      let warnings =
+        result.warnings.iter().map(|warning| warning.kind().to_string()).collect::<Vec<_>>();
+      assert_eq!(warnings, ["EVAL"], "{code}");
+      stmt_has_eval
+    };
+
+    assert!(has_eval("eval('x')"));
+    assert!(has_eval("(eval)('x')"));
+    assert!(!has_eval("(0, eval)('x')"));
+    assert!(!has_eval("(0, (eval))('x')"));
+    assert!(!has_eval("eval?.('x')"));
+
+    let result = scan("foo(eval)");
+    assert!(result.warnings.is_empty());
+    assert!(!result.ecma_view_meta.contains(EcmaViewMeta::Eval));
+  }
+
+  #[test]
+  fn module_value_and_member_reads() {
+    for code in ["console.log(module.id)", "register(module)", "if (module.hot) {}"] {
+      let result = scan(code);
+      assert!(matches!(result.exports_kind, ExportsKind::CommonJs), "{code}");
+      assert!(result.ast_usage.contains(EcmaModuleAstUsage::ModuleRef), "{code}");
+    }
+
+    // A local `module` binding isn't the CommonJS `module` object.
+    for code in [
+      "const module = { id: 1 }; console.log(module.id)",
+      "function f(module) { return module.id }",
+    ] {
+      let result = scan(code);
+      assert!(matches!(result.exports_kind, ExportsKind::None), "{code}");
+      assert!(!result.ast_usage.contains(EcmaModuleAstUsage::ModuleRef), "{code}");
+    }
+  }
+
+  #[test]
+  fn assignment_to_namespace_import_member() {
+    let assign_to_import_errors = |code: &str| {
+      let result = scan(code);
+      result
+        .errors
+        .iter()
+        .filter(|error| matches!(error.kind(), EventKind::AssignToImportError))
+        .map(|error| error.to_diagnostic().to_string())
+        .collect::<Vec<_>>()
+    };
+
+    for code in [
+      "import * as ns from './m'; ns.foo = 1",
+      "import * as ns from './m'; ns['foo'] += 1",
+      "import * as ns from './m'; delete ns.foo",
+    ] {
+      let errors = assign_to_import_errors(code);
+      assert_eq!(errors.len(), 1, "{code}");
+      assert!(errors[0].contains("Cannot assign to import 'foo'"), "{code}");
+    }
+
+    // Reading a member, or mutating the object it holds, leaves the namespace untouched.
+    for code in [
+      "import * as ns from './m'; console.log(ns.foo)",
+      "import * as ns from './m'; ns.foo.bar = 1",
+      "import * as ns from './m'; const { foo } = ns; foo.bar = 1",
+    ] {
+      assert!(assign_to_import_errors(code).is_empty(), "{code}");
+    }
+  }
+
+  #[test]
+  fn top_level_await_with_cjs_format() {
+    let code = "const a = await Promise.resolve(1); for await (const b of []) {}";
+    let ast = EcmaCompiler::parse("<Noop>", code, SourceType::default()).unwrap();
+    let unsupported_features = |options: NormalizedBundlerOptions| {
+      let result = scan_ast(&ast, ModuleDefFormat::Unknown, options);
+      assert!(result.ast_usage.contains(EcmaModuleAstUsage::TopLevelAwait));
+      result
+        .errors
+        .iter()
+        .filter(|error| matches!(error.kind(), EventKind::UnsupportedFeatureError))
+        .count()
+    };
+
+    assert_eq!(unsupported_features(NormalizedBundlerOptions::default()), 0);
+    let cjs = || NormalizedBundlerOptions { format: OutputFormat::Cjs, ..Default::default() };
+    assert_eq!(unsupported_features(cjs()), 2);
+    assert_eq!(
+      unsupported_features(NormalizedBundlerOptions { top_level_await: true, ..cjs() }),
+      0
+    );
+    assert_eq!(
+      unsupported_features(NormalizedBundlerOptions {
+        format: OutputFormat::Iife,
+        top_level_await: true,
+        ..Default::default()
+      }),
+      2
+    );
+  }
+
+  #[test]
+  fn merged_typescript_declaration_exports() {
+    let code = "export interface Foo { a: number }\nexport const Foo = { a: 1 };\n\
+                export type Bar = typeof Foo;\nexport function Bar() {}";
+    let options = NormalizedBundlerOptions::default();
+    let ast = EcmaCompiler::parse("main.ts", code, SourceType::ts()).unwrap();
+    let ret = PreProcessEcmaAst::default()
+      .build(ast, "main.ts", &OxcParseType::Ts, None, &options, false)
+      .expect("merged declarations shouldn't be reported as duplicate exports");
+
+    // Only the value declarations are left after the transform, each exported once.
+    let export_decls = ret
+      .ast
+      .program()
+      .body
+      .iter()
+      .filter(|stmt| matches!(stmt, Statement::ExportNamedDeclaration(_)))
+      .count();
+    assert_eq!(export_decls, 2);
+
+    let result = scan_ast(&ret.ast, ModuleDefFormat::Unknown, options);
+    assert!(result.errors.is_empty());
+    let mut exports = result.named_exports.keys().map(ToString::to_string).collect::<Vec<_>>();
+    exports.sort();
+    assert_eq!(exports, ["Bar", "Foo"]);
+  }
+
+  #[test]
+  fn object_define_property_on_exports() {
+    for code in [
+      "Object.defineProperty(exports, 'foo', { enumerable: true, get: () => foo })",
+      "Object.defineProperty(module.exports, 'foo', { enumerable: true, get: () => foo })",
+      "Object.defineProperties(exports, { foo: { get: () => foo } })",
+    ] {
+      let result = scan(code);
+      assert!(matches!(result.exports_kind, ExportsKind::CommonJs), "{code}");
+      assert!(result.ast_usage.intersects(EcmaModuleAstUsage::ModuleOrExports), "{code}");
+      assert!(!result.ast_usage.contains(EcmaModuleAstUsage::EsModuleFlag), "{code}");
+    }
+
+    // Defining a named export isn't a read of the whole `exports` object.
+    let result =
+      scan("Object.defineProperty(exports, 'foo', { enumerable: true, get: () => foo })");
+    assert!(!result.ast_usage.contains(EcmaModuleAstUsage::UnknownExportsRead));
+
+    // `__esModule` is an interop marker, not an export.
+    let result =
+      scan("Object.defineProperty(exports, '__esModule', { value: true }); exports.a = 1");
+    assert!(matches!(result.exports_kind, ExportsKind::CommonJs));
+    assert!(result.ast_usage.contains(EcmaModuleAstUsage::EsModuleFlag));
+    assert!(!result.ast_usage.contains(EcmaModuleAstUsage::UnknownExportsRead));
+    assert!(!result.commonjs_exports.contains_key("__esModule"));
+  }
+
+  #[test]
+  fn truthy_es_module_flag() {
+    for code in [
+      "exports.__esModule = !0; exports.default = 1;",
+      "module.exports.__esModule = 1; exports.default = 1;",
+      "Object.defineProperty(exports, '__esModule', { value: !0 }); exports.default = 1;",
+    ] {
+      let result = scan(code);
+      assert!(result.ast_usage.contains(EcmaModuleAstUsage::EsModuleFlag), "{code}");
+    }
+
+    for code in ["exports.__esModule = !1;", "exports.__esModule = flag;"] {
+      let result = scan(code);
+      assert!(!result.ast_usage.contains(EcmaModuleAstUsage::EsModuleFlag), "{code}");
+    }
+  }
+
+  #[test]
+  fn frozen_exports() {
+    for code in [
+      "exports.a = 1; exports.b = 2; exports.c = 3; Object.freeze(exports);",
+      "exports.a = 1; exports.b = 2; exports.c = 3; Object.seal(module.exports);",
+    ] {
+      let result = scan(code);
+      assert!(matches!(result.exports_kind, ExportsKind::CommonJs), "{code}");
+      assert!(result.ast_usage.contains(EcmaModuleAstUsage::ExportsFrozen), "{code}");
+      assert!(
+        result.ast_usage.contains(EcmaModuleAstUsage::AllStaticExportPropertyAccess),
+        "{code}"
+      );
+      assert!(!result.ast_usage.contains(EcmaModuleAstUsage::UnknownExportsRead), "{code}");
+      let mut exports = result.commonjs_exports.keys().map(ToString::to_string).collect::<Vec<_>>();
+      exports.sort();
+      assert_eq!(exports, ["a", "b", "c"], "{code}");
+    }
+
+    // The frozen object escapes, or `Object` isn't the global one.
+    for code in [
+      "exports.a = 1; const e = Object.freeze(exports);",
+      "const Object = {}; exports.a = 1; Object.freeze(exports);",
+    ] {
+      let result = scan(code);
+      assert!(!result.ast_usage.contains(EcmaModuleAstUsage::ExportsFrozen), "{code}");
+      assert!(result.ast_usage.contains(EcmaModuleAstUsage::UnknownExportsRead), "{code}");
+    }
+  }
+
+  #[test]
+  fn arguments_callee_in_esm() {
+    let code = "function foo() { return arguments.callee; }";
+    let result = scan_with_module_type(code, ModuleDefFormat::EsmMjs);
+    assert_eq!(result.errors.len(), 1);
+    assert!(matches!(result.errors[0].kind(), EventKind::SloppyModeSyntaxInEsmError));
+
+    let result = scan(&format!("export {{ foo }}; {code}"));
+    assert_eq!(result.errors.len(), 1);
+
+    for module_type in [ModuleDefFormat::CJS, ModuleDefFormat::Unknown] {
+      assert!(scan_with_module_type(code, module_type).errors.is_empty());
+    }
+    // `arguments.caller` doesn't exist anymore and reads as `undefined`, even in strict mode.
+    let code = "function foo() { return arguments.caller; }";
+    assert!(scan_with_module_type(code, ModuleDefFormat::EsmMjs).errors.is_empty());
+  }
+
+  #[test]
+  fn scopes_with_direct_eval() {
+    let code =
+      "function outer() { function inner() { eval('x'); } } function sibling() { (0, eval)('x'); }";
+    let ast = EcmaCompiler::parse("<Noop>", code, SourceType::default()).unwrap();
+    let scoping = ast.make_scoping();
+    let root = scoping.root_scope_id();
+    let &[outer, sibling] = scoping.get_scope_child_ids(root) else { unreachable!() };
+    let inner = scoping.get_scope_child_ids(outer)[0];
+
+    let result = scan_ast(&ast, ModuleDefFormat::Unknown, NormalizedBundlerOptions::default());
+    assert_eq!(result.scopes_with_direct_eval, FxHashSet::from_iter([root, outer, inner]));
+    assert!(!result.scopes_with_direct_eval.contains(&sibling));
+  }
+
+  #[test]
+  fn top_level_symbols() {
+    let code = "import { imported } from 'mod';
+var a = 1, { b, c: [d] } = {};
+var a;
+function foo(param) { const nested = 1; }
+export class Bar {}
+export default function baz() {}
+if (true) { let blockScoped; }";
+    let result = scan(code);
+    let symbols = result
+      .top_level_symbols
+      .iter()
+      .map(|(name, span)| {
+        assert_eq!(&code[span.start as usize..span.end as usize], name.as_str());
+        name.as_str()
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(symbols, ["a", "b", "d", "foo", "Bar", "baz"]);
+    let (_, span) = &result.top_level_symbols[0];
+    assert_eq!(span.start as usize, code.find("a = 1").unwrap());
+  }
+
+  #[test]
+  fn dynamic_import_of_template_literal_without_expressions() {
+    let code = "import(`./locales/en.js`); import(`./locales/${lang}.js`); import(tag`./b.js`);";
+    let result = scan(code);
+    assert_eq!(result.import_records.len(), 1);
+    let rec = result.import_records.iter().next().unwrap();
+    assert_eq!(rec.module_request, "./locales/en.js");
+    assert!(matches!(rec.kind, ImportKind::DynamicImport));
+    assert!(!rec.meta.contains(ImportRecordMeta::IsUnspannedImport));
+    assert_eq!(rec.state.span, Span::new(7, 24));
+    assert_eq!(result.dynamic_import_exprs.len(), 2);
+  }
+
+  #[test]
+  fn dynamic_import_of_non_static_specifier() {
+    let code =
+      "import('./a'); import('./' + 'b'); import(`./c`); import(/* @vite-ignore */ ignored);
+      import(name); import('./' + name); import(`./${name}.js`);";
+    let result = scan(code);
+    let diagnostics = result
+      .warnings
+      .iter()
+      .map(|warning| {
+        assert!(matches!(warning.kind(), EventKind::DynamicImport));
+        warning.to_diagnostic().to_string()
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(diagnostics.len(), 3);
+    for (diagnostic, specifier) in diagnostics.iter().zip(["name", "'./' + name", "`./${name}.js`"])
+    {
+      assert!(diagnostic.contains(&format!("The specifier `{specifier}` of `import()`")));
+    }
+  }
+
+  #[test]
+  fn unused_trailing_params_of_exported_functions() {
+    let unused_params = |code: &str| check_warnings(code, EventKind::UnusedFunctionParameter);
+
+    let warnings = unused_params("export function foo(a, b, c) { return a; }");
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("Trailing parameters `b`, `c` of exported function `foo`"));
+    let warnings = unused_params("function foo(a, ...rest) {} export { foo as bar };");
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("Trailing parameters `a`, `rest` of exported function `foo`"));
+
+    for code in [
+      "export function foo(a, b) { return a + b; }",
+      // Unused parameters followed by a used one can't be removed
+      "export function foo(a, b) { return b; }",
+      "export function foo(a, { b }) { return a; }",
+      "export function foo(a, b = a) { return a; }",
+      "export function foo(a, b) { return arguments.length; }",
+      "export function foo(a, b) { return eval('b'); }",
+      // Not exported
+      "function foo(a, b) { return a; } foo();",
+      "export const foo = (a, b) => a;",
+    ] {
+      assert!(unused_params(code).is_empty(), "{code}");
+    }
+  }
+
+  #[test]
+  fn module_exports_reassigned_after_property_exports() {
+    let reassignment_warnings =
+      |code: &str| check_warnings(code, EventKind::ModuleExportsReassignment);
+
+    let warnings = reassignment_warnings("exports.a = 1; module.exports = { b: 2 };");
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("`module.exports` is reassigned"), "{}", warnings[0]);
+    // Reported once per module
+    let warnings = reassignment_warnings(
+      "module.exports.a = 1; exports.b = 2; module.exports = {}; module.exports = [];",
+    );
+    assert_eq!(warnings.len(), 1);
+
+    for code in [
+      "module.exports = {}; module.exports.a = 1;",
+      "module.exports = {}; module.exports = [];",
+      "exports.a = 1; exports.b = 2;",
+      // `module` and `exports` are plain globals in an ES module
+      "exports.a = 1; module.exports = {}; export const b = 2;",
+      "function f(exports) { exports.a = 1; } module.exports = f;",
+    ] {
+      assert!(reassignment_warnings(code).is_empty(), "{code}");
+    }
+  }
+
+  #[test]
+  fn deep_member_expr_chain_on_namespace() {
+    let result = scan("import * as ns from './foo'; ns.a.b;");
+    assert_eq!(namespace_usage(&result), (false, vec![vec!["a".to_string(), "b".to_string()]]));
+
+    let props = ".a".repeat(DEFAULT_MAX_MEMBER_EXPR_CHAIN_LENGTH);
+    let result = scan(&format!("import * as ns from './foo'; ns{props};"));
+    let (is_referenced_as_whole, member_chains) = namespace_usage(&result);
+    assert!(!is_referenced_as_whole);
+    assert_eq!(member_chains[0].len(), DEFAULT_MAX_MEMBER_EXPR_CHAIN_LENGTH);
+
+    let props = ".a".repeat(1000);
+    let result = scan(&format!("import * as ns from './foo'; ns{props};"));
+    assert_eq!(namespace_usage(&result), (true, vec![]));
+
+    // The limit is configured by `optimization.maxMemberExprChainLength`
+    let options = NormalizedBundlerOptions {
+      optimization: NormalizedOptimizationConfig {
+        max_member_expr_chain_length: 2,
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+    let result = scan_with_options("import * as ns from './foo'; ns.a.b.c;", options);
+    assert_eq!(namespace_usage(&result), (true, vec![]));
+  }
+
+  #[test]
+  fn module_always_throws() {
+    let throw_warnings = |code: &str| check_warnings(code, EventKind::ModuleAlwaysThrows);
+
+    let warnings =
+      throw_warnings("export const a = 1; throw new Error('not implemented'); throw 1;");
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("always throws"));
+
+    for code in [
+      "if (a) { throw new Error('a'); }",
+      "if (a) throw new Error('a');",
+      "try { throw new Error('a'); } catch {}",
+      "export function foo() { throw new Error('a'); }",
+      "const foo = () => { throw new Error('a'); };",
+    ] {
+      assert!(throw_warnings(code).is_empty(), "{code}");
+    }
+  }
+
+  #[test]
+  fn destructured_import_meta() {
+    let scan_cjs = |code: &str| {
+      scan_with_options(
+        code,
+        NormalizedBundlerOptions { format: OutputFormat::Cjs, ..Default::default() },
+      )
+    };
+    let empty_import_meta_warnings = |result: &ScanResult| {
+      result
+        .warnings
+        .iter()
+        .filter(|warning| matches!(warning.kind(), EventKind::EmptyImportMeta))
+        .count()
+    };
+
+    let result = scan_cjs("const { url, dirname: dir } = import.meta;");
+    let keys = result.destructured_import_meta.values().collect::<Vec<_>>();
+    assert_eq!(keys, [&["url", "dirname"].map(CompactStr::new).to_vec()]);
+    assert_eq!(empty_import_meta_warnings(&result), 0);
+
+    let result = scan_cjs("const { url, resolve } = import.meta;");
+    assert_eq!(result.destructured_import_meta.len(), 1);
+    assert_eq!(empty_import_meta_warnings(&result), 1);
+
+    for code in ["const meta = import.meta;", "let url; ({ url } = import.meta);"] {
+      let result = scan_cjs(code);
+      assert!(result.destructured_import_meta.is_empty(), "{code}");
+      assert_eq!(empty_import_meta_warnings(&result), 1, "{code}");
+    }
+
+    // `import.meta` is kept as it is for esm output
+    let result = scan("const { url } = import.meta;");
+    assert!(result.destructured_import_meta.is_empty());
+  }
+
+  #[test]
+  fn new_url_with_import_meta_url() {
+    let scan_new_url = |code: &str| {
+      let options = NormalizedBundlerOptions {
+        experimental: ExperimentalOptions {
+          resolve_new_url_to_asset: Some(true),
+          ..Default::default()
+        },
+        ..Default::default()
+      };
+      scan_with_options(code, options)
+    };
+
+    let result = scan_new_url("new URL('./foo.png', import.meta.url);");
+    assert_eq!(result.new_url_references.len(), 1);
+    let rec = &result.import_records[*result.new_url_references.values().next().unwrap()];
+    assert_eq!(rec.module_request.as_str(), "./foo.png");
+    assert!(matches!(rec.kind, ImportKind::NewUrl));
+    assert!(matches!(rec.asserted_module_type, Some(ModuleType::Asset)));
+
+    for code in [
+      // The path must be a static string
+      "new URL(`./${name}.png`, import.meta.url);",
+      "new URL(name, import.meta.url);",
+      // The base must be exactly `import.meta.url`
+      "const url = import.meta.url; new URL('./foo.png', url);",
+      "new URL('./foo.png', location.href);",
+      "new URL('./foo.png');",
+      // The callee must be the global `URL`
+      "const URL = Foo; new URL('./foo.png', import.meta.url);",
+      "new URL('data:text/plain,foo', import.meta.url);",
+      // Left to be resolved at runtime
+      "const url = /* @url-ignore */ new URL('./foo.png', import.meta.url);",
+      "new URL(/* @vite-ignore */ './foo.png', import.meta.url);",
+    ] {
+      let result = scan_new_url(code);
+      assert!(result.new_url_references.is_empty(), "{code}");
+      assert!(result.import_records.is_empty(), "{code}");
+    }
+
+    // Not recorded unless `experimental.resolveNewUrlToAsset` is enabled
+    assert!(scan("new URL('./foo.png', import.meta.url);").new_url_references.is_empty());
+
+    // Other comments don't matter, and the ignore comment is configurable
+    let result = scan_new_url("const url = /* foo */ new URL('./foo.png', import.meta.url);");
+    assert_eq!(result.new_url_references.len(), 1);
+    let code = "const url = /* @runtime */ new URL('./foo.png', import.meta.url);";
+    let options = NormalizedBundlerOptions {
+      experimental: ExperimentalOptions {
+        resolve_new_url_to_asset: Some(true),
+        new_url_ignore_comment: Some("@runtime".to_string()),
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+    assert!(scan_with_options(code, options).new_url_references.is_empty());
+  }
+
+  #[test]
+  fn require_resolve_with_string_literal() {
+    let scan_require_resolve = |code: &str| {
+      let options = NormalizedBundlerOptions {
+        experimental: ExperimentalOptions {
+          resolve_require_resolve_to_asset: Some(true),
+          ..Default::default()
+        },
+        ..Default::default()
+      };
+      scan_with_options(code, options)
+    };
+
+    let result = scan_require_resolve("const worker = require.resolve('./worker.js');");
+    assert_eq!(result.require_resolve_references.len(), 1);
+    let rec = &result.import_records[*result.require_resolve_references.values().next().unwrap()];
+    assert_eq!(rec.module_request.as_str(), "./worker.js");
+    assert!(matches!(rec.kind, ImportKind::RequireResolve));
+    assert!(matches!(rec.asserted_module_type, Some(ModuleType::Asset)));
+
+    let result = scan_require_resolve("try { require.resolve('./worker.js') } catch {}");
+    let rec = &result.import_records[*result.require_resolve_references.values().next().unwrap()];
+    assert!(rec.meta.contains(ImportRecordMeta::InTryCatchBlock));
+
+    for code in [
+      // The path must be a static string
+      "require.resolve(`./${name}.js`);",
+      "require.resolve(name);",
+      "require.resolve();",
+      "require.resolve('./worker.js', { paths: [dir] });",
+      // `require` must be the global one
+      "const require = createRequire(import.meta.url); require.resolve('./worker.js');",
+      "function f(require) { require.resolve('./worker.js') }",
+      "foo.resolve('./worker.js');",
+    ] {
+      let result = scan_require_resolve(code);
+      assert!(result.require_resolve_references.is_empty(), "{code}");
+      assert!(result.import_records.is_empty(), "{code}");
+    }
+
+    // Not recorded unless `experimental.resolveRequireResolveToAsset` is enabled
+    assert!(scan("require.resolve('./worker.js');").require_resolve_references.is_empty());
+  }
+
+  #[test]
+  fn native_addon_import_records() {
+    let is_native_addon = |code: &str| {
+      let result = scan(code);
+      let rec = result.import_records.iter().next().expect("should record the import");
+      rec.meta.contains(ImportRecordMeta::IsNativeAddon)
+    };
+
+    assert!(is_native_addon("require('./addon.node');"));
+    assert!(is_native_addon("import addon from './build/Release/addon.node';"));
+    assert!(!is_native_addon("require('./addon.js');"));
+    assert!(!is_native_addon("require('node');"));
+  }
+
+  #[test]
+  fn dynamic_import_in_try_block() {
+    let in_try_catch_block = |code: &str| {
+      let result = scan(code);
+      let (_, rec) = result
+        .import_records
+        .iter_enumerated()
+        .find(|(_, rec)| matches!(rec.kind, ImportKind::DynamicImport))
+        .expect("should have a dynamic import record");
+      rec.meta.contains(ImportRecordMeta::InTryCatchBlock)
+    };
+
+    assert!(in_try_catch_block("try { await import('./optional') } catch {}"));
+    assert!(in_try_catch_block("try { if (a) { import('./optional') } } catch {}"));
+    assert!(!in_try_catch_block("await import('./required')"));
+    // A function declared inside `try` may be called outside of it
+    assert!(!in_try_catch_block("try { var load = () => import('./required') } catch {}"));
+  }
+
+  #[test]
+  fn side_effect_free_stmts() {
+    let result = scan("const a = 1;\nconsole.log(a);\nfunction foo() {}\nfoo();");
+    let stmts = result.side_effect_free_stmts();
+    let indices = stmts.iter().map(|(idx, _)| idx.index()).collect::<Vec<_>>();
+    assert_eq!(indices, [1, 3]);
+    assert_eq!(result.stmt_spans.len(), 4);
+    assert!(stmts[0].1.contains("const a = 1"));
+    assert!(stmts[1].1.contains("function foo()"));
+  }
+
+  #[test]
+  fn conditional_commonjs_exports() {
+    let is_conditional = |code: &str, name: &str| {
+      scan(code).commonjs_exports.get(name).expect("should have the export").is_conditional
+    };
+
+    assert!(is_conditional("if (cond) exports.foo = 1;", "foo"));
+    assert!(is_conditional("cond ? (exports.foo = 1) : 0;", "foo"));
+    assert!(is_conditional("try {} catch { exports.foo = 1 }", "foo"));
+    assert!(!is_conditional("exports.foo = 1;", "foo"));
+    assert!(!is_conditional("{ exports.foo = 1; }", "foo"));
+    // An unconditional assignment before or after a conditional one
+    assert!(!is_conditional("exports.foo = 1; if (cond) exports.foo = 2;", "foo"));
+    assert!(!is_conditional("if (cond) exports.foo = 1; exports.foo = 2;", "foo"));
+  }
+}
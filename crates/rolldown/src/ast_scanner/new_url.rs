@@ -38,7 +38,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       Some(|comment: &Comment| comment.is_vite()),
     )
     .is_some();
-    if has_leading_ignore_comment {
+    if has_leading_ignore_comment || self.is_new_url_ignored_by_comment(expr) {
       return;
     }
     let path = &first_arg_string_literal.value;
@@ -52,4 +52,18 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     self.result.import_records[idx].asserted_module_type = Some(ModuleType::Asset);
     self.result.new_url_references.insert(expr.span, idx);
   }
+
+  /// `/* @url-ignore */ new URL(...)`, the comment text is configured by
+  /// `experimental.newUrlIgnoreComment`.
+  fn is_new_url_ignored_by_comment(&self, expr: &NewExpression<'ast>) -> bool {
+    let ignore_comment = self.immutable_ctx.options.experimental.new_url_ignore_comment();
+    get_leading_comment(
+      self.immutable_ctx.comments,
+      expr.span,
+      Some(|comment: &Comment| {
+        comment.content_span().source_text(self.immutable_ctx.source).contains(ignore_comment)
+      }),
+    )
+    .is_some()
+  }
 }
@@ -0,0 +1,28 @@
+use oxc::ast::ast::CallExpression;
+use rolldown_common::{ImportKind, ImportRecordMeta, ModuleType};
+use rolldown_ecmascript_utils::{CallExpressionExt, ExpressionExt};
+
+use super::AstScanner;
+
+impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
+  /// Handle exact `require.resolve('path')` pattern
+  pub fn handle_require_resolve_with_string_literal(&mut self, expr: &CallExpression<'ast>) {
+    if !expr.is_global_require_resolve_call(&self.result.symbol_ref_db.ast_scopes) {
+      return;
+    }
+    // `require.resolve('path', { paths })` resolves from directories only known at runtime.
+    let [arg] = expr.arguments.as_slice() else {
+      return;
+    };
+    let Some(string_literal) = arg.as_expression().and_then(|expr| expr.as_string_literal()) else {
+      return;
+    };
+
+    let mut meta = ImportRecordMeta::empty();
+    meta.set(ImportRecordMeta::InTryCatchBlock, self.in_side_try_catch_block());
+    let idx =
+      self.add_import_record(&string_literal.value, ImportKind::RequireResolve, expr.span, meta);
+    self.result.import_records[idx].asserted_module_type = Some(ModuleType::Asset);
+    self.result.require_resolve_references.insert(expr.span, idx);
+  }
+}
@@ -7,12 +7,13 @@ use oxc::ast::ast::{
   ChainElement, Expression, IdentifierReference, PropertyKey, UnaryOperator,
   VariableDeclarationKind,
 };
-use oxc::ast::{match_expression, match_member_expression};
+use oxc::ast::{Comment, match_expression, match_member_expression};
+use oxc::span::Span;
 use oxc_allocator::Address;
 use rolldown_common::{AstScopes, FlatOptions, SharedNormalizedBundlerOptions, SideEffectDetail};
 use rolldown_utils::global_reference::{
-  is_global_ident_ref, is_side_effect_free_member_expr_of_len_three,
-  is_side_effect_free_member_expr_of_len_two,
+  is_global_ident_ref, is_side_effect_free_global_call,
+  is_side_effect_free_member_expr_of_len_three, is_side_effect_free_member_expr_of_len_two,
 };
 use rustc_hash::FxHashSet;
 use utils::{
@@ -39,6 +40,9 @@ pub struct SideEffectDetector<'a> {
   pub scope: &'a AstScopes,
   options: &'a SharedNormalizedBundlerOptions,
   flat_options: FlatOptions,
+  /// Comments of the module and its source, used to match `treeshake.pureAnnotations`.
+  comments: &'a [Comment],
+  source: &'a str,
   /// This field is only used for `LinkStage#cross_module_optimization`.
   side_effect_free_function_symbol_ref: Option<&'a FxHashSet<Address>>,
 }
@@ -48,9 +52,26 @@ impl<'a> SideEffectDetector<'a> {
     scope: &'a AstScopes,
     flat_options: FlatOptions,
     options: &'a SharedNormalizedBundlerOptions,
+    comments: &'a [Comment],
+    source: &'a str,
     side_effect_free_function_symbol_ref: Option<&'a FxHashSet<Address>>,
   ) -> Self {
-    Self { scope, options, flat_options, side_effect_free_function_symbol_ref }
+    Self { scope, options, flat_options, comments, source, side_effect_free_function_symbol_ref }
+  }
+
+  /// Whether the expression is annotated by a comment listed in `treeshake.pureAnnotations`. The
+  /// standard `#__PURE__` and `@__PURE__` forms are recognized by the parser as `pure` already.
+  fn has_configured_pure_annotation(&self, span: Span) -> bool {
+    let Some(annotations) = self.options.treeshake.pure_annotations() else {
+      return false;
+    };
+    let start = self.comments.partition_point(|comment| comment.attached_to < span.start);
+    self.comments[start..].iter().take_while(|comment| comment.attached_to == span.start).any(
+      |comment| {
+        let text = comment.content_span().source_text(self.source).trim();
+        annotations.iter().any(|annotation| annotation == text)
+      },
+    )
   }
 
   #[inline]
@@ -271,6 +292,11 @@ impl<'a> SideEffectDetector<'a> {
     if !property_access_side_effects {
       return;
     }
+    if side_effects_detail.contains(SideEffectDetail::GlobalVarAccess)
+      && self.is_pure_global_member_chain(chains)
+    {
+      return;
+    }
     *side_effects_detail |= (match chains.len() {
       2 => !is_side_effect_free_member_expr_of_len_two(chains),
       3 => !is_side_effect_free_member_expr_of_len_three(chains),
@@ -339,6 +365,7 @@ impl<'a> SideEffectDetector<'a> {
 
     let is_pure = !self.flat_options.ignore_annotations()
       && (expr.pure
+        || self.has_configured_pure_annotation(expr.span)
         || self
           .side_effect_free_function_symbol_ref
           .is_some_and(|map| map.contains(&Address::from_ptr(expr))));
@@ -357,11 +384,45 @@ impl<'a> SideEffectDetector<'a> {
         }
       }
       detail
+    } else if self.is_side_effect_free_global_callee(&expr.callee) {
+      let mut detail = SideEffectDetail::GlobalVarAccess;
+      for arg in &expr.arguments {
+        detail |= match arg {
+          Argument::SpreadElement(_) => true.into(),
+          _ => self.detect_side_effect_of_expr(arg.to_expression()),
+        };
+        if detail.has_side_effect() {
+          break;
+        }
+      }
+      detail
     } else {
       true.into()
     }
   }
 
+  /// e.g. `Math.max`, see `rolldown_utils::global_reference::is_side_effect_free_global_call`
+  fn is_side_effect_free_global_callee(&self, callee: &Expression) -> bool {
+    let Some(member_expr) = callee.as_member_expression() else {
+      return false;
+    };
+    extract_member_expr_chain(member_expr, 2).is_some_and(|(ref_id, chain)| {
+      self.scope.is_unresolved(ref_id) && is_side_effect_free_global_call(&chain)
+    })
+  }
+
+  /// Whether the member chain is covered by `treeshake.pureGlobals`, an entry covers itself and the
+  /// members below it, e.g. `document` covers `document.cookie`.
+  fn is_pure_global_member_chain(&self, chain: &[ast::Atom]) -> bool {
+    let Some(pure_globals) = self.options.treeshake.pure_globals() else {
+      return false;
+    };
+    pure_globals.iter().any(|path| {
+      path.split('.').count() <= chain.len()
+        && path.split('.').zip(chain).all(|(part, atom)| part == atom.as_str())
+    })
+  }
+
   fn is_expr_manual_pure_functions(&self, expr: &'a Expression) -> bool {
     if self.flat_options.is_manual_pure_functions_empty() {
       return false;
@@ -371,7 +432,45 @@ impl<'a> SideEffectDetector<'a> {
     let Some(first_part) = Self::extract_first_part_of_member_expr_like(expr) else {
       return false;
     };
-    manual_pure_functions.contains(first_part)
+    if manual_pure_functions.contains(first_part) {
+      return true;
+    }
+    // Entries like `console.log` match the static member chain of the callee and the members below
+    // it, e.g. `console.log.call(...)`.
+    let mut dotted_paths =
+      manual_pure_functions.iter().filter(|path| path.contains('.')).peekable();
+    if dotted_paths.peek().is_none() {
+      return false;
+    }
+    let Some(chain) = Self::extract_static_member_chain(expr) else {
+      return false;
+    };
+    dotted_paths.any(|path| {
+      path.split('.').count() <= chain.len()
+        && path.split('.').zip(&chain).all(|(part, name)| part == *name)
+    })
+  }
+
+  /// `a.b.c` => `["a", "b", "c"]`, `None` if the expression isn't made of static member accesses
+  /// on an identifier.
+  fn extract_static_member_chain(expr: &'a Expression) -> Option<Vec<&'a str>> {
+    let mut chain = vec![];
+    let mut cur = expr;
+    loop {
+      match cur {
+        Expression::Identifier(ident) => {
+          chain.push(ident.name.as_str());
+          break;
+        }
+        Expression::StaticMemberExpression(member_expr) => {
+          chain.push(member_expr.property.name.as_str());
+          cur = &member_expr.object;
+        }
+        _ => return None,
+      }
+    }
+    chain.reverse();
+    Some(chain)
   }
 
   fn extract_first_part_of_member_expr_like(expr: &'a Expression) -> Option<&'a str> {
@@ -446,6 +545,8 @@ impl<'a> SideEffectDetector<'a> {
         ast::UnaryOperator::Typeof if matches!(unary_expr.argument, Expression::Identifier(_)) => {
           false.into()
         }
+        // `delete a.b` mutates `a`, even if reading `a.b` is considered side-effect free.
+        ast::UnaryOperator::Delete if unary_expr.argument.is_member_expression() => true.into(),
         _ => self.detect_side_effect_of_expr(&unary_expr.argument),
       },
       oxc::ast::match_member_expression!(Expression) => self
@@ -653,12 +754,15 @@ impl<'a> SideEffectDetector<'a> {
       Expression::NewExpression(expr) => {
         let is_side_effect_free_global_constructor =
           maybe_side_effect_free_global_constructor(self.scope, expr);
-        let is_pure = expr.pure || is_side_effect_free_global_constructor;
+        let is_pure_annotated = expr.pure
+          || (!self.flat_options.ignore_annotations()
+            && self.has_configured_pure_annotation(expr.span));
+        let is_pure = is_pure_annotated || is_side_effect_free_global_constructor;
 
         let mut detail = SideEffectDetail::empty();
         detail.set(SideEffectDetail::GlobalVarAccess, is_side_effect_free_global_constructor);
         detail.set(SideEffectDetail::Unknown, !is_pure);
-        detail.set(SideEffectDetail::PureAnnotation, expr.pure);
+        detail.set(SideEffectDetail::PureAnnotation, is_pure_annotated);
 
         for arg in &expr.arguments {
           detail |= match arg {
@@ -812,7 +916,8 @@ impl<'a> SideEffectDetector<'a> {
         SideEffectDetail::Unknown,
         detail.contains(SideEffectDetail::GlobalVarAccess)
           && self.options.treeshake.unknown_global_side_effects()
-          && !is_global_ident_ref(&ident_ref.name),
+          && !is_global_ident_ref(&ident_ref.name)
+          && !self.is_pure_global_member_chain(&[ident_ref.name]),
       );
     }
     detail
@@ -960,23 +1065,32 @@ mod test {
 
   use itertools::Itertools;
   use oxc::{parser::Parser, span::SourceType};
-  use rolldown_common::{AstScopes, NormalizedBundlerOptions, SideEffectDetail};
+  use rolldown_common::{
+    AstScopes, InnerOptions, NormalizedBundlerOptions, PropertyReadSideEffects, SideEffectDetail,
+  };
   use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
 
   use super::SideEffectDetector;
   use rolldown_common::FlatOptions;
 
   fn get_statements_side_effect(code: &str) -> bool {
+    get_statements_side_effect_with_options(code, NormalizedBundlerOptions::default())
+  }
+
+  fn get_statements_side_effect_with_options(
+    code: &str,
+    options: NormalizedBundlerOptions,
+  ) -> bool {
     let source_type = SourceType::tsx();
     let ast = EcmaCompiler::parse("<Noop>", code, source_type).unwrap();
     let semantic = EcmaAst::make_semantic(ast.program(), false);
     let scoping = semantic.into_scoping();
     let ast_scopes = AstScopes::new(scoping);
 
-    let options = Arc::new(NormalizedBundlerOptions::default());
+    let options = Arc::new(options);
     let flags = FlatOptions::from_shared_options(&options);
     ast.program().body.iter().any(|stmt| {
-      SideEffectDetector::new(&ast_scopes, flags, &options, None)
+      SideEffectDetector::new(&ast_scopes, flags, &options, &ast.program().comments, code, None)
         .detect_side_effect_of_stmt(stmt)
         .has_side_effect()
     })
@@ -996,7 +1110,8 @@ mod test {
       .body
       .iter()
       .map(|stmt| {
-        SideEffectDetector::new(&ast_scopes, flags, &options, None).detect_side_effect_of_stmt(stmt)
+        SideEffectDetector::new(&ast_scopes, flags, &options, &ast.program().comments, code, None)
+          .detect_side_effect_of_stmt(stmt)
       })
       .collect_vec()
   }
@@ -1203,6 +1318,16 @@ mod test {
     assert!(get_statements_side_effect("const a = 1; const b = 2; a + b"));
   }
 
+  #[test]
+  fn test_delete_expression() {
+    assert!(get_statements_side_effect("delete globalThis.foo"));
+    assert!(get_statements_side_effect("delete Object.assign"));
+    assert!(get_statements_side_effect("const a = {}; delete a.b"));
+    assert!(get_statements_side_effect("const a = {}; delete a['b']"));
+    assert!(!get_statements_side_effect("const a = {}; a"));
+    assert!(!get_statements_side_effect("delete 1"));
+  }
+
   #[test]
   fn test_private_in_expression() {
     assert!(!get_statements_side_effect("#privateField in this"));
@@ -1319,6 +1444,174 @@ mod test {
     );
   }
 
+  #[test]
+  fn test_unknown_global_side_effects() {
+    let unknown_global_side_effects = |value: bool| NormalizedBundlerOptions {
+      treeshake: InnerOptions { unknown_global_side_effects: Some(value), ..Default::default() }
+        .into(),
+      ..Default::default()
+    };
+    // Member reads on unknown globals are still governed by `propertyReadSideEffects`, like Rollup.
+    let without_property_read_side_effects = |value: bool| NormalizedBundlerOptions {
+      treeshake: InnerOptions {
+        unknown_global_side_effects: Some(value),
+        property_read_side_effects: Some(PropertyReadSideEffects::False),
+        ..Default::default()
+      }
+      .into(),
+      ..Default::default()
+    };
+    // `const x = SomeGlobal.foo` is droppable when the option is false, kept when true.
+    assert!(!get_statements_side_effect_with_options(
+      "const x = SomeGlobal.foo",
+      without_property_read_side_effects(false)
+    ));
+    assert!(get_statements_side_effect_with_options(
+      "const x = SomeGlobal.foo",
+      without_property_read_side_effects(true)
+    ));
+    // With the default `propertyReadSideEffects`, the member read keeps it either way.
+    assert!(get_statements_side_effect_with_options(
+      "const x = SomeGlobal.foo",
+      unknown_global_side_effects(true)
+    ));
+    assert!(get_statements_side_effect_with_options(
+      "const x = SomeGlobal.foo",
+      unknown_global_side_effects(false)
+    ));
+    assert!(get_statements_side_effect_with_options(
+      "const x = SomeGlobal",
+      unknown_global_side_effects(true)
+    ));
+    assert!(!get_statements_side_effect_with_options(
+      "const x = SomeGlobal['foo']",
+      without_property_read_side_effects(false)
+    ));
+    assert!(!get_statements_side_effect_with_options(
+      "const x = SomeGlobal",
+      unknown_global_side_effects(false)
+    ));
+    // Writing to or calling unknown globals still has side effects.
+    assert!(get_statements_side_effect_with_options(
+      "SomeGlobal.foo = 1",
+      unknown_global_side_effects(false)
+    ));
+    assert!(get_statements_side_effect_with_options(
+      "const x = SomeGlobal.foo()",
+      unknown_global_side_effects(false)
+    ));
+    // Local bindings are not affected.
+    assert!(get_statements_side_effect_with_options(
+      "let a; const x = a.foo",
+      unknown_global_side_effects(false)
+    ));
+  }
+
+  #[test]
+  fn test_side_effect_free_global_call() {
+    assert!(!get_statements_side_effect("const x = Math.max(1, 2)"));
+    assert!(!get_statements_side_effect("const x = Math['floor'](1.5)"));
+    assert!(!get_statements_side_effect("const x = Array.isArray([])"));
+    assert!(get_statements_side_effect("const x = Math.max(foo())"));
+    assert!(get_statements_side_effect("const x = Math.max(...args)"));
+    assert!(get_statements_side_effect("const x = Math.random()"));
+    assert!(get_statements_side_effect("const x = Math.PI()"));
+    // Shadowed globals are not affected.
+    assert!(get_statements_side_effect("let Math; const x = Math.max(1, 2)"));
+  }
+
+  #[test]
+  fn test_pure_annotated_call_and_new_expr() {
+    assert!(get_statements_side_effect("let a; const x = a()"));
+    assert!(!get_statements_side_effect("let a; const x = /*#__PURE__*/ a()"));
+    assert!(!get_statements_side_effect("let a; const x = /*@__PURE__*/ a()"));
+    assert!(get_statements_side_effect("let A; const x = new A()"));
+    assert!(!get_statements_side_effect("let A; const x = /*#__PURE__*/ new A()"));
+    // The annotation covers the outer call, including the call producing its callee.
+    assert!(!get_statements_side_effect("let a; /*#__PURE__*/ a()()"));
+    assert!(get_statements_side_effect("let a; a()()"));
+    // Arguments are still checked.
+    assert!(get_statements_side_effect("let a, b; /*#__PURE__*/ a(b())"));
+    assert!(get_statements_side_effect("let A, b; /*#__PURE__*/ new A(b())"));
+  }
+
+  #[test]
+  fn test_pure_annotations() {
+    let pure_annotations = |annotations: &[&str]| NormalizedBundlerOptions {
+      treeshake: InnerOptions {
+        pure_annotations: Some(annotations.iter().map(ToString::to_string).collect()),
+        ..Default::default()
+      }
+      .into(),
+      ..Default::default()
+    };
+    assert!(get_statements_side_effect("/*#PURE#*/ foo()"));
+    assert!(!get_statements_side_effect_with_options(
+      "/*#PURE#*/ foo()",
+      pure_annotations(&["#PURE#"])
+    ));
+    assert!(!get_statements_side_effect_with_options(
+      "const a = /* #PURE# */ new Foo()",
+      pure_annotations(&["#PURE#"])
+    ));
+    // Arguments are still checked.
+    assert!(get_statements_side_effect_with_options(
+      "/*#PURE#*/ foo(bar())",
+      pure_annotations(&["#PURE#"])
+    ));
+    assert!(get_statements_side_effect_with_options(
+      "/*#NOT_PURE#*/ foo()",
+      pure_annotations(&["#PURE#"])
+    ));
+    // The standard annotations keep working.
+    assert!(!get_statements_side_effect_with_options(
+      "/*#__PURE__*/ foo()",
+      pure_annotations(&["#PURE#"])
+    ));
+  }
+
+  #[test]
+  fn test_pure_globals() {
+    let pure_globals = |globals: &[&str]| NormalizedBundlerOptions {
+      treeshake: InnerOptions {
+        pure_globals: Some(globals.iter().map(ToString::to_string).collect()),
+        ..Default::default()
+      }
+      .into(),
+      ..Default::default()
+    };
+    assert!(get_statements_side_effect("const x = document.cookie"));
+    assert!(!get_statements_side_effect_with_options(
+      "const x = document.cookie",
+      pure_globals(&["document"])
+    ));
+    assert!(!get_statements_side_effect_with_options(
+      "const x = document.cookie",
+      pure_globals(&["document.cookie"])
+    ));
+    assert!(!get_statements_side_effect_with_options(
+      "const x = document['cookie'].length",
+      pure_globals(&["document.cookie"])
+    ));
+    assert!(get_statements_side_effect_with_options(
+      "const x = document.body",
+      pure_globals(&["document.cookie"])
+    ));
+    assert!(get_statements_side_effect_with_options(
+      "const x = documentElement.cookie",
+      pure_globals(&["document"])
+    ));
+    // Only reads are covered.
+    assert!(get_statements_side_effect_with_options(
+      "document.cookie = 'a=1'",
+      pure_globals(&["document"])
+    ));
+    assert!(get_statements_side_effect_with_options(
+      "const x = document.createElement('div')",
+      pure_globals(&["document"])
+    ));
+  }
+
   #[test]
   fn test_object_expression() {
     assert!(!get_statements_side_effect("const of = { [1]: 'hi'}"));
@@ -1360,6 +1653,16 @@ mod test {
       vec![SideEffectDetail::Unknown]
     );
 
+    // The assignment is still a CommonJS export when its value is used.
+    assert_eq!(
+      get_statements_side_effect_details("const x = (exports.y = 5)"),
+      vec![SideEffectDetail::PureCjs]
+    );
+    assert_eq!(
+      get_statements_side_effect_details("const x = (exports.y = f())"),
+      vec![SideEffectDetail::Unknown | SideEffectDetail::PureCjs]
+    );
+
     assert_eq!(
       get_statements_side_effect_details(
         r"
@@ -1371,6 +1674,24 @@ mod test {
     );
   }
 
+  #[test]
+  fn test_export_named_declaration() {
+    assert!(get_statements_side_effect("export const x = sideEffect()"));
+    assert!(get_statements_side_effect("export let a = 1, b = sideEffect()"));
+    assert!(get_statements_side_effect("export class A { static x = sideEffect() }"));
+    assert!(!get_statements_side_effect("export const x = 1"));
+    assert!(!get_statements_side_effect("export function f() { sideEffect() }"));
+    // Re-exports don't evaluate anything in this module
+    assert!(!get_statements_side_effect("export { x } from './x'"));
+  }
+
+  #[test]
+  fn test_export_all_declaration() {
+    // Whether the importee has side effects is decided in the link stage
+    assert!(!get_statements_side_effect("export * from './x'"));
+    assert!(!get_statements_side_effect("export * as ns from './x'"));
+  }
+
   #[test]
   fn test_class_expr() {
     assert!(!get_statements_side_effect(
@@ -1390,6 +1711,45 @@ let remove15 = class {
     ));
   }
 
+  #[test]
+  fn test_manual_pure_functions() {
+    let manual_pure_functions = |functions: &[&str]| NormalizedBundlerOptions {
+      treeshake: InnerOptions {
+        manual_pure_functions: Some(functions.iter().map(ToString::to_string).collect()),
+        ..Default::default()
+      }
+      .into(),
+      ..Default::default()
+    };
+    assert!(get_statements_side_effect("console.log('x')"));
+    assert!(!get_statements_side_effect_with_options(
+      "console.log('x')",
+      manual_pure_functions(&["console.log"])
+    ));
+    assert!(!get_statements_side_effect_with_options(
+      "console.log.call(console, 'x')",
+      manual_pure_functions(&["console.log"])
+    ));
+    assert!(get_statements_side_effect_with_options(
+      "console.warn('x')",
+      manual_pure_functions(&["console.log"])
+    ));
+    assert!(get_statements_side_effect_with_options(
+      "console['log']('x')",
+      manual_pure_functions(&["console.log"])
+    ));
+    // A plain name covers every member below it.
+    assert!(!get_statements_side_effect_with_options(
+      "console.warn('x'); assert(1)",
+      manual_pure_functions(&["console", "assert"])
+    ));
+    // Arguments are not checked.
+    assert!(!get_statements_side_effect_with_options(
+      "console.log(foo())",
+      manual_pure_functions(&["console.log"])
+    ));
+  }
+
   #[test]
   fn test_extract_first_part_of_member_expr_like() {
     assert!(extract_first_part_of_member_expr_like_helper("a.b") == "a");
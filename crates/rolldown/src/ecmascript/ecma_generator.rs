@@ -7,8 +7,8 @@ use crate::{
 
 use anyhow::Result;
 use rolldown_common::{
-  AddonRenderContext, EcmaAssetMeta, InstantiatedChunk, InstantiationKind, ModuleId, ModuleIdx,
-  OutputFormat, RenderedModule,
+  AddonRenderContext, EcmaAssetMeta, ExportsKind, InstantiatedChunk, InstantiationKind, ModuleId,
+  ModuleIdx, NormalModule, OutputFormat, RenderedModule,
 };
 use rolldown_error::BuildResult;
 use rolldown_plugin::HookAddonArgs;
@@ -18,7 +18,10 @@ use rolldown_utils::rayon::IndexedParallelIterator;
 use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
 use rustc_hash::FxHashMap;
 
-use super::format::{cjs::render_cjs, esm::render_esm, iife::render_iife, umd::render_umd};
+use super::format::{
+  cjs::render_cjs, esm::render_esm, iife::render_iife, umd::render_umd,
+  utils::is_use_strict_directive,
+};
 
 pub type RenderedModuleSources = Vec<RenderedModuleSource>;
 
@@ -98,24 +101,7 @@ impl Generator for EcmaGenerator {
       },
     );
 
-    let directives: Vec<_> = ctx
-      .chunk
-      .user_defined_entry_module(&ctx.link_output.module_table)
-      .or_else(|| {
-        ctx.options.preserve_modules.then_some({
-          let first_idx = *ctx.chunk.modules.first()?;
-          ctx.link_output.module_table[first_idx].as_normal()?
-        })
-      })
-      .map(|normal_module| {
-        normal_module
-          .ecma_view
-          .directive_range
-          .iter()
-          .map(|range| &normal_module.source[range.start as usize..range.end as usize])
-          .collect::<_>()
-      })
-      .unwrap_or_default();
+    let directives = collect_chunk_directives(ctx);
 
     let banner = {
       let injection = match ctx.options.banner.as_ref() {
@@ -244,3 +230,62 @@ impl Generator for EcmaGenerator {
     }))
   }
 }
+
+/// Collects the directives rendered at the top of the chunk.
+///
+/// Directives are dropped from the concatenated module bodies, so only the ones of the entry module
+/// (or the first module with `preserveModules`) are kept. For script outputs, the `"use strict"`
+/// directives of all modules collapse into one chunk level `"use strict"`, as long as every module
+/// in the chunk is strict anyway.
+fn collect_chunk_directives<'a>(ctx: &GenerateContext<'a>) -> Vec<&'a str> {
+  let module_table = &ctx.link_output.module_table;
+  let module_directives = |normal_module: &'a NormalModule| {
+    normal_module
+      .ecma_view
+      .directive_range
+      .iter()
+      .map(|range| &normal_module.source[range.start as usize..range.end as usize])
+  };
+
+  let mut directives: Vec<_> = ctx
+    .chunk
+    .user_defined_entry_module(module_table)
+    .or_else(|| {
+      ctx.options.preserve_modules.then_some({
+        let first_idx = *ctx.chunk.modules.first()?;
+        module_table[first_idx].as_normal()?
+      })
+    })
+    .map(|normal_module| module_directives(normal_module).collect())
+    .unwrap_or_default();
+
+  // ES modules are always strict.
+  // https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/linker/linker.go#L5686-L5698
+  if matches!(ctx.options.format, OutputFormat::Esm) {
+    directives.retain(|d| !is_use_strict_directive(d));
+    return directives;
+  }
+
+  let mut has_use_strict = false;
+  directives
+    .retain(|d| !is_use_strict_directive(d) || !std::mem::replace(&mut has_use_strict, true));
+  if has_use_strict {
+    return directives;
+  }
+
+  let mut use_strict = None;
+  for normal_module in ctx.chunk.modules.iter().filter_map(|idx| module_table[*idx].as_normal()) {
+    match module_directives(normal_module).find(|d| is_use_strict_directive(d)) {
+      Some(d) => {
+        use_strict.get_or_insert(d);
+      }
+      // Hoisting `"use strict"` would change the semantics of a sloppy module.
+      None if !matches!(normal_module.exports_kind, ExportsKind::Esm) => return directives,
+      None => {}
+    }
+  }
+  if let Some(use_strict) = use_strict {
+    directives.insert(0, use_strict);
+  }
+  directives
+}
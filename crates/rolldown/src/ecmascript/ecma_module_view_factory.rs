@@ -1,10 +1,11 @@
+use oxc::span::GetSpan;
 use oxc_index::IndexVec;
 use rolldown_common::{
   EcmaModuleAstUsage, EcmaRelated, EcmaView, EcmaViewMeta, ImportRecordIdx, ModuleId, ModuleType,
-  RawImportRecord, ResolvedId, SharedNormalizedBundlerOptions, SideEffectDetail,
-  side_effects::{DeterminedSideEffects, HookSideEffects},
+  RawImportRecord, ResolvedId, SharedNormalizedBundlerOptions, SideEffectDetail, StmtInfoIdx,
+  side_effects::{DeterminedSideEffects, HookSideEffects, SideEffects},
 };
-use rolldown_error::BuildResult;
+use rolldown_error::{BuildDiagnostic, BuildResult, EventKindSwitcher};
 use rolldown_std_utils::PathExt;
 use rolldown_utils::{ecmascript::legitimize_identifier_name, indexmap::FxIndexSet};
 use sugar_path::SugarPath;
@@ -27,9 +28,14 @@ pub async fn create_ecma_view(
   args: CreateModuleViewArgs,
 ) -> BuildResult<CreateEcmaViewReturn> {
   let CreateModuleViewArgs { source, sourcemap_chain, hook_side_effects } = args;
-  let ParseToEcmaAstResult { ast, scoping, has_lazy_export, warnings } =
+  let ParseToEcmaAstResult { ast, scoping, has_lazy_export, warnings, pre_process_timings } =
     parse_to_ecma_ast(ctx, source).await?;
 
+  if let Some(timings) = pre_process_timings {
+    let module = ctx.stable_id;
+    tracing::debug!(name = "pre process timings", module, phases = ?timings.phases);
+  }
+
   ctx.warnings.extend(warnings);
 
   let module_id = ModuleId::new(&ctx.resolved_id.id);
@@ -69,6 +75,7 @@ pub async fn create_ecma_view(
     ecma_view_meta,
     dynamic_import_rec_exports_usage,
     new_url_references: new_url_imports,
+    require_resolve_references,
     this_expr_replace_map,
     hmr_info,
     hmr_hot_ref,
@@ -76,6 +83,18 @@ pub async fn create_ecma_view(
     dummy_record_set,
     constant_export_map,
     import_attribute_map,
+    // Not consumed yet, it only describes how the required values are accessed
+    require_usage: _,
+    dynamic_import_exprs,
+    import_meta_env_references,
+    destructured_import_meta,
+    deprecated_exports,
+    imports_referenced_at_init,
+    source_mapping_url,
+    scopes_with_direct_eval,
+    top_level_symbols,
+    dynamic_requires,
+    stmt_spans,
   } = scanner.scan(ast.program())?;
   named_exports.extend(commonjs_exports);
 
@@ -94,7 +113,42 @@ pub async fn create_ecma_view(
   )
   .await?;
 
-  // TODO: Should we check if there are `check_side_effects_for` returns false but there are side effects in the module?
+  if ctx.options.checks.contains(EventKindSwitcher::SideEffectsInPurePackage)
+    && matches!(side_effects, DeterminedSideEffects::UserDefined(false))
+    && package_json_side_effects(ctx.resolved_id) == Some(false)
+  {
+    let body = &ast.program().body;
+    // The namespace stmt info comes first, the rest of them are one-to-one mapped to the program body.
+    debug_assert_eq!(stmt_infos.len(), body.len() + 1);
+    for (idx, stmt) in body.iter().enumerate() {
+      let stmt_info = &stmt_infos[StmtInfoIdx::from_usize(idx + 1)];
+      if stmt_info.side_effect.contains(SideEffectDetail::Unknown) {
+        ctx.warnings.push(
+          BuildDiagnostic::side_effects_in_pure_package(
+            ctx.resolved_id.id.to_string(),
+            ast.source().clone(),
+            stmt.span(),
+          )
+          .with_severity_warning(),
+        );
+      }
+    }
+  }
+
+  if ctx.options.treeshake.is_none()
+    && ctx.options.checks.contains(EventKindSwitcher::SideEffectsWithoutTreeshake)
+    && let Some(package_json) = ctx.resolved_id.package_json.as_ref()
+    && matches!(package_json.side_effects, Some(SideEffects::Bool(false)))
+  {
+    // Deduplicated per package by the module loader
+    ctx.warnings.push(
+      BuildDiagnostic::side_effects_without_treeshake(
+        package_json.realpath.to_string_lossy().into_owned(),
+      )
+      .with_severity_warning(),
+    );
+  }
+
   let ecma_view = EcmaView {
     source: ast.source().clone(),
     named_imports,
@@ -128,6 +182,7 @@ pub async fn create_ecma_view(
     hashbang_range,
     mutations: vec![],
     new_url_references: new_url_imports,
+    require_resolve_references,
     this_expr_replace_map,
     hmr_info,
     hmr_hot_ref,
@@ -136,6 +191,16 @@ pub async fn create_ecma_view(
     constant_export_map,
     depended_runtime_helper: Box::default(),
     import_attribute_map,
+    dynamic_import_exprs,
+    import_meta_env_references,
+    destructured_import_meta,
+    deprecated_exports,
+    imports_referenced_at_init,
+    source_mapping_url,
+    scopes_with_direct_eval,
+    top_level_symbols,
+    dynamic_requires,
+    stmt_spans,
   };
 
   let ecma_related = EcmaRelated { ast, symbols, dynamic_import_rec_exports_usage };
@@ -208,22 +273,24 @@ pub fn lazy_check_side_effects(
     // CSS modules are considered to have side effects by default
     return DeterminedSideEffects::Analyzed(true);
   }
-  resolved_id
-    .package_json
-    .as_ref()
-    .and_then(|p| {
-      // the glob expr is based on parent path of package.json, which is package path
-      // so we should use the relative path of the module to package path
-      let module_path_relative_to_package = resolved_id.id.as_path().relative(p.realpath.parent()?);
-      p.check_side_effects_for(&module_path_relative_to_package.to_string_lossy())
-        .map(DeterminedSideEffects::UserDefined)
-    })
-    .unwrap_or_else(|| {
+  package_json_side_effects(resolved_id).map(DeterminedSideEffects::UserDefined).unwrap_or_else(
+    || {
       // when determining cjs module side effects:
       // we don't considered `exports.a` has side effects
       let analyzed_side_effects = stmt_infos
         .iter()
         .any(|stmt_info| stmt_info.side_effect.contains(SideEffectDetail::Unknown));
       DeterminedSideEffects::Analyzed(analyzed_side_effects)
-    })
+    },
+  )
+}
+
+/// The `sideEffects` field of the nearest package.json for the module
+fn package_json_side_effects(resolved_id: &ResolvedId) -> Option<bool> {
+  resolved_id.package_json.as_ref().and_then(|p| {
+    // the glob expr is based on parent path of package.json, which is package path
+    // so we should use the relative path of the module to package path
+    let module_path_relative_to_package = resolved_id.id.as_path().relative(p.realpath.parent()?);
+    p.check_side_effects_for(&module_path_relative_to_package.to_string_lossy())
+  })
 }
@@ -7,10 +7,11 @@ use crate::{
   ecmascript::ecma_generator::RenderedModuleSources, types::generator::GenerateContext,
   utils::chunk::render_chunk_exports::render_chunk_exports,
 };
-use rolldown_common::{AddonRenderContext, OutputExports};
+use rolldown_common::{AddonRenderContext, ChunkIdx, OutputExports};
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_sourcemap::SourceJoiner;
 use rolldown_utils::concat_string;
+use rustc_hash::FxHashSet;
 
 use super::utils::{render_chunk_directives, render_modules_with_peek_runtime_module_at_first};
 
@@ -68,6 +69,18 @@ pub fn render_cjs<'code>(
     }
   };
 
+  // With `output.topLevelAwait`, chunks that need top-level await are evaluated in an async IIFE.
+  // Exports are assigned inside of it, once the awaited modules have been executed. The promise of
+  // the IIFE is exported as `__tla`, so the chunks requiring this one can await it.
+  let needs_async_wrapper = is_async_wrapped(ctx, ctx.chunk_idx);
+  if needs_async_wrapper {
+    source_joiner.append_source(concat_string!(
+      "Object.defineProperty(exports, '",
+      TOP_LEVEL_AWAIT_EXPORT,
+      "', { value: (async () => {"
+    ));
+  }
+
   // Runtime module should be placed before the generated `requires` in CJS format.
   // Because, we might need to generate `__toESM(require(...))` that relies on the runtime module.
   render_modules_with_peek_runtime_module_at_first(
@@ -85,6 +98,16 @@ pub fn render_cjs<'code>(
     source_joiner.append_source(exports);
   }
 
+  if needs_async_wrapper {
+    // Rethrow outside of the promise chain, so a failing module crashes the process like a
+    // synchronous `cjs` chunk would, instead of becoming an unhandled rejection.
+    source_joiner.append_source(concat_string!(
+      "})() });\nexports.",
+      TOP_LEVEL_AWAIT_EXPORT,
+      ".catch((err) => {\n  setTimeout(() => {\n    throw err;\n  });\n});"
+    ));
+  }
+
   if let Some(outro) = outro {
     source_joiner.append_source(outro);
   }
@@ -96,6 +119,35 @@ pub fn render_cjs<'code>(
   Ok(source_joiner)
 }
 
+/// The non-enumerable export holding the promise of the async IIFE of a wrapped chunk.
+const TOP_LEVEL_AWAIT_EXPORT: &str = "__tla";
+
+/// A chunk is wrapped in an async IIFE if one of its modules uses top-level await, directly or
+/// through its imports. Chunks importing a wrapped chunk are wrapped as well, since they have to
+/// await it before reading its exports.
+fn is_async_wrapped(ctx: &GenerateContext<'_>, chunk_idx: ChunkIdx) -> bool {
+  if !ctx.options.top_level_await {
+    return false;
+  }
+  let mut visited = FxHashSet::default();
+  let mut stack = vec![chunk_idx];
+  while let Some(chunk_idx) = stack.pop() {
+    if !visited.insert(chunk_idx) {
+      continue;
+    }
+    let chunk = &ctx.chunk_graph.chunk_table[chunk_idx];
+    if chunk
+      .modules
+      .iter()
+      .any(|module_idx| ctx.link_output.metas[*module_idx].is_tla_or_contains_tla_dependency)
+    {
+      return true;
+    }
+    stack.extend(chunk.imports_from_other_chunks.iter().map(|(importee_idx, _)| *importee_idx));
+  }
+  false
+}
+
 // Make sure the imports generate stmts keep live bindings.
 fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
   let mut s = String::new();
@@ -104,14 +156,21 @@ fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
   ctx.chunk.imports_from_other_chunks.iter().for_each(|(exporter_id, items)| {
     let importee_chunk = &ctx.chunk_graph.chunk_table[*exporter_id];
     let require_path_str =
-      concat_string!("require('", ctx.chunk.import_path_for(importee_chunk), "');\n");
+      concat_string!("require('", ctx.chunk.import_path_for(importee_chunk), "')");
+    // The exports of a wrapped chunk are only assigned once its async IIFE settles.
+    let is_importee_async_wrapped = is_async_wrapped(ctx, *exporter_id);
     if items.is_empty() {
-      s.push_str(&require_path_str);
+      if is_importee_async_wrapped {
+        s.push_str(&concat_string!("await ", require_path_str, ".", TOP_LEVEL_AWAIT_EXPORT, ";\n"));
+      } else {
+        s.push_str(&concat_string!(require_path_str, ";\n"));
+      }
     } else {
-      s.push_str("const ");
-      s.push_str(&ctx.chunk.require_binding_names_for_other_chunks[exporter_id]);
-      s.push_str(" = ");
-      s.push_str(&require_path_str);
+      let binding_name = &ctx.chunk.require_binding_names_for_other_chunks[exporter_id];
+      s.push_str(&concat_string!("const ", binding_name, " = ", require_path_str, ";\n"));
+      if is_importee_async_wrapped {
+        s.push_str(&concat_string!("await ", binding_name, ".", TOP_LEVEL_AWAIT_EXPORT, ";\n"));
+      }
     }
   });
   // render external imports
@@ -34,13 +34,8 @@ pub fn render_esm<'code>(
     source_joiner.append_source(banner);
   }
 
-  // https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/linker/linker.go#L5686-L5698
   if !directives.is_empty() {
-    source_joiner.append_source(render_chunk_directives(directives.iter().filter(|d| {
-      let normalized_directive =
-        d.trim_start_matches(['\'', '"']).trim_end_matches(['\'', '"', ';']);
-      normalized_directive != "use strict"
-    })));
+    source_joiner.append_source(render_chunk_directives(directives.iter()));
     source_joiner.append_source("");
   }
 
@@ -14,7 +14,7 @@
 //!    Note that in IIFE, the external imports are directly assigned to the global variables.
 //!    And in the wrapper function, the global variables are passed as arguments.
 //! 4. Check if the chunk is suitable for strict mode, and add `"use strict";` if necessary.
-//! 5. Render the intro if it exists.
+//! 5. Render the intro if it exists, and capture `document.currentScript` if `import.meta.url` is used in browser.
 //! 6. Render the chunk content.
 //! 7. Render the exports if it exists. If you are using named export, it will modify the `exports` object.
 //!    If you are using default export, it will return the default value.
@@ -34,7 +34,9 @@ use crate::{
     determine_export_mode::determine_export_mode, render_chunk_exports::render_chunk_exports,
   },
 };
-use rolldown_common::{AddonRenderContext, ExternalModule, OutputExports};
+use rolldown_common::{
+  AddonRenderContext, EcmaModuleAstUsage, ExternalModule, OutputExports, Platform,
+};
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_sourcemap::SourceJoiner;
 use rolldown_utils::concat_string;
@@ -109,7 +111,7 @@ pub async fn render_iife<'code>(
   // The function argument and the external imports are passed as arguments to the wrapper function.
   let factory_parameters = render_factory_parameters(ctx, &externals, exports_prefix.is_some());
 
-  source_joiner.append_source(concat_string!(
+  let wrapper_start = concat_string!(
     definition,
     if (ctx.options.extend && named_exports) || !has_exports || assignment.is_empty() {
       // If facing following situations, there shouldn't an assignment for the wrapper function:
@@ -123,12 +125,31 @@ pub async fn render_iife<'code>(
     "(function(",
     factory_parameters,
     ") {\n"
-  ));
+  );
+  // The modules only guard their own code, the wrapper itself may continue the banner.
+  if ctx.options.defensive_semicolons && wrapper_start.starts_with('(') {
+    source_joiner.append_source(";");
+  }
+  source_joiner.append_source(wrapper_start);
 
   if let Some(intro) = intro {
     source_joiner.append_source(intro);
   }
 
+  // `document.currentScript` is only set while the script is evaluated, so it's read once here
+  // instead of in the deferred code that accesses `import.meta.url`.
+  if matches!(ctx.options.platform, Platform::Browser)
+    && ctx.chunk.modules.iter().any(|idx| {
+      ctx.link_output.module_table[*idx]
+        .as_normal()
+        .is_some_and(|module| module.ast_usage.contains(EcmaModuleAstUsage::ImportMetaUrl))
+    })
+  {
+    source_joiner.append_source(
+      "var _documentCurrentScript = typeof document !== 'undefined' ? document.currentScript : null;",
+    );
+  }
+
   if named_exports && entry_module.exports_kind.is_esm() {
     if let Some(marker) = render_namespace_markers(
       ctx.options.es_module,
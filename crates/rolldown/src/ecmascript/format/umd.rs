@@ -82,6 +82,10 @@ pub async fn render_umd<'code>(
   let iife_end = if need_global { ")" } else { "" };
   let iife_export =
     render_iife_export(warnings, ctx, &externals, has_exports, named_exports).await?;
+  // The modules only guard their own code, the wrapper itself may continue the banner.
+  if ctx.options.defensive_semicolons {
+    source_joiner.append_source(";");
+  }
   source_joiner.append_source(format!(
     "(function({wrapper_parameters}) {{
   {cjs_intro}
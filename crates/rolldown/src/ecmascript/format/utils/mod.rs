@@ -98,6 +98,10 @@ pub fn render_modules_with_peek_runtime_module_at_first<'a>(
   );
 }
 
+pub fn is_use_strict_directive(directive: &str) -> bool {
+  directive.trim_start_matches(['\'', '"']).trim_end_matches(['\'', '"', ';']) == "use strict"
+}
+
 pub fn render_chunk_directives<'a, T: Iterator<Item = &'a &'a str>>(directives: T) -> String {
   let mut ret = String::new();
   for d in directives {
@@ -7,7 +7,7 @@
 use std::fmt::Write as _;
 
 use arcstr::ArcStr;
-use rolldown_common::OutputExports;
+use rolldown_common::{OutputExports, Platform};
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_utils::{
   concat_string,
@@ -86,9 +86,11 @@ pub fn generate_identifier(
     return Ok((String::new(), String::new()));
   };
 
+  let global = global_object(ctx);
+
   // It is same as Rollup.
   if name.contains('.') {
-    let (stmts, namespace) = generate_namespace_definition(name, "this", ";\n");
+    let (stmts, namespace) = generate_namespace_definition(name, global, ";\n");
     // Extend the object if the `extend` option is enabled.
     let final_expr = if ctx.options.extend && matches!(export_mode, OutputExports::Named) {
       format!("{namespace} = {namespace} || {{}}")
@@ -104,12 +106,12 @@ pub fn generate_identifier(
     let final_expr = if matches!(export_mode, OutputExports::Named) {
       // In named exports, the `extend` option will make the assignment disappear and
       // the modification will be done extending the existed object (the `name` option).
-      format!("this{property} = this{property} || {{}}")
+      format!("{global}{property} = {global}{property} || {{}}")
     } else {
       // If there isn't a name in default export, we shouldn't assign the function to `this[""]`.
       // If there is, we should assign the function to `this["name"]`,
       // because there isn't an object that we can extend.
-      if name.is_empty() { String::new() } else { format!("this{property}") }
+      if name.is_empty() { String::new() } else { format!("{global}{property}") }
     };
 
     return Ok((String::new(), final_expr));
@@ -125,6 +127,21 @@ pub fn generate_identifier(
   }
 }
 
+/// The object that the namespaced or extended `name` is defined on.
+///
+/// It is `this` by default, which is the global object when the IIFE runs as a classic script.
+/// With `output.globalThis`, it is `globalThis`, or `window` for the `browser` platform, so the
+/// output also works where `this` is `undefined`, e.g. when it is evaluated as an ES module.
+fn global_object(ctx: &GenerateContext<'_>) -> &'static str {
+  if !ctx.options.global_this {
+    return "this";
+  }
+  match ctx.options.platform {
+    Platform::Browser => "window",
+    Platform::Node | Platform::Neutral => "globalThis",
+  }
+}
+
 /// It is a helper function to generate a caller for the given name.
 ///
 /// - If the name is not an invalid identifier, it will generate a caller like `.name`.
@@ -148,6 +165,16 @@ mod tests {
     assert_eq!(result.1, "this.a.b.c");
   }
 
+  #[test]
+  fn test_global_this_as_global() {
+    let result = generate_namespace_definition("a.b.c", "globalThis", ";\n");
+    assert_eq!(
+      result.0,
+      "globalThis.a = globalThis.a || {};\nglobalThis.a.b = globalThis.a.b || {};\n"
+    );
+    assert_eq!(result.1, "globalThis.a.b.c");
+  }
+
   #[test]
   fn test_non_identifier_as_name() {
     let result = generate_namespace_definition("1.2.3", "this", ";\n");
@@ -350,6 +350,7 @@ impl<'a> HmrStage<'a> {
             sourcemap: enable_sourcemap,
             filename: affected_module.id.to_string(),
             print_legal_comments: false, // ignore hmr chunk comments
+            print_normal_comments: false,
             initial_indent: 0,
           },
         );
@@ -64,6 +64,7 @@ impl<'me> ScopeHoistingFinalizerContext<'me> {
         scope: ast_scope,
         snippet: AstSnippet::new(alloc),
         generated_init_esm_importee_ids: FxHashSet::default(),
+        generated_cjs_import_record_ids: FxHashSet::default(),
         scope_stack: vec![],
         top_level_var_bindings: FxIndexSet::default(),
         state: TraverseState::empty(),
@@ -6,7 +6,7 @@ use oxc::span::{Atom, CompactStr};
 use oxc::{
   allocator::{self, IntoIn, TakeIn},
   ast::{
-    NONE,
+    CommentContent, NONE,
     ast::{self, BindingPatternKind, Expression, SimpleAssignmentTarget, Statement},
     match_member_expression,
   },
@@ -92,6 +92,15 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
     // them in chunk level
     program.hashbang.take();
     program.directives.clear();
+
+    // Normal comments are only printed when `keepComments` is set, so drop the ones it doesn't match.
+    if let Some(pattern) = &self.ctx.options.keep_comments {
+      let source_text = program.source_text;
+      program.comments.retain(|comment| {
+        !matches!(comment.content, CommentContent::None)
+          || pattern.matches(comment.span.source_text(source_text))
+      });
+    }
     // init namespace_alias_symbol_id
 
     let last_import_stmt_idx = self.remove_unused_top_level_stmt(program);
@@ -376,9 +385,18 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
   }
 
   fn visit_expression(&mut self, expr: &mut ast::Expression<'ast>) {
+    if let Some(new_expr) = self.try_rewrite_import_meta_env(expr) {
+      *expr = new_expr;
+      return;
+    }
+    if let Some(new_expr) = self.try_rewrite_destructured_import_meta(expr) {
+      *expr = new_expr;
+      return;
+    }
     match expr {
       ast::Expression::CallExpression(call_expr) => {
         self.rewrite_hot_accept_call_deps(call_expr);
+        self.handle_require_resolve_with_string_literal(call_expr);
         if let Some(new_expr) = self.try_rewrite_global_require_call(call_expr) {
           *expr = new_expr;
         } else if let Some(ident_ref) = call_expr.callee.as_identifier_mut() {
@@ -1,3 +1,4 @@
+use arcstr::ArcStr;
 use bitflags::bitflags;
 use oxc::semantic::ScopeFlags;
 use oxc::{
@@ -9,12 +10,13 @@ use oxc::{
       MemberExpression, NumberBase, Statement, VariableDeclarationKind,
     },
   },
-  span::{Atom, GetSpan, GetSpanMut, SPAN},
+  span::{Atom, GetSpan, GetSpanMut, SPAN, Span},
+  syntax::operator::LogicalOperator,
 };
 use rolldown_common::{
   AstScopes, ConcatenateWrappedModuleKind, ExportsKind, ImportRecordIdx, ImportRecordMeta,
   MemberExprRefResolution, Module, ModuleIdx, ModuleNamespaceIncludedReason, ModuleType,
-  OutputFormat, Platform, RenderedConcatenatedModuleParts, SymbolRef, WrapKind,
+  OutputFormat, Platform, RenderedConcatenatedModuleParts, RequireEsmInterop, SymbolRef, WrapKind,
 };
 use rolldown_ecmascript::ToSourceString;
 use rolldown_ecmascript_utils::{
@@ -25,6 +27,7 @@ mod finalizer_context;
 mod impl_visit_mut;
 pub use finalizer_context::{FinalizerMutableState, ScopeHoistingFinalizerContext};
 use oxc::span::CompactStr;
+use rolldown_utils::concat_string;
 use rolldown_utils::ecmascript::is_validate_identifier_name;
 use rolldown_utils::indexmap::{FxIndexMap, FxIndexSet};
 use rustc_hash::FxHashSet;
@@ -56,6 +59,9 @@ pub struct ScopeHoistingFinalizer<'me, 'ast: 'me> {
   pub alloc: &'ast Allocator,
   pub snippet: AstSnippet<'ast>,
   pub generated_init_esm_importee_ids: FxHashSet<ModuleIdx>,
+  /// Import records whose `var import_foo = __toESM(require_foo())` is already generated. Import
+  /// declarations of the same module can share a record.
+  pub generated_cjs_import_record_ids: FxHashSet<ImportRecordIdx>,
   pub scope_stack: Vec<ScopeFlags>,
   pub state: TraverseState,
   pub top_level_var_bindings: FxIndexSet<Atom<'ast>>,
@@ -134,6 +140,10 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
           }
         }
 
+        if !self.generated_cjs_import_record_ids.insert(rec_id) {
+          return true;
+        }
+
         // Replace the statement with something like `var import_foo = __toESM(require_foo())`
 
         // `__toESM`
@@ -388,17 +398,23 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
 
     // if there is no export, we should generate `var ns = {}` instead of `var ns = __export({}, {})`
     // else construct `__export(ns_name, { prop_name: () => returned, ... })`
-    let module_namespace_rhs = if arg_obj_expr.properties.is_empty() {
+    // With `namespaceToStringTag`, construct `__export({ ... }, true)` even if there is no export.
+    let namespace_to_string_tag = self.ctx.options.namespace_to_string_tag;
+    let module_namespace_rhs = if arg_obj_expr.properties.is_empty() && !namespace_to_string_tag {
       Expression::ObjectExpression(self.builder().alloc(arg_obj_expr))
     } else {
+      let mut args = self
+        .snippet
+        .builder
+        .vec1(ast::Argument::ObjectExpression(arg_obj_expr.into_in(self.alloc)));
+      if namespace_to_string_tag {
+        args.push(ast::Argument::from(self.snippet.builder.expression_boolean_literal(SPAN, true)));
+      }
       self.snippet.builder.expression_call_with_pure(
         SPAN,
         self.finalized_expr_for_runtime_symbol("__export"),
         NONE,
-        self
-          .snippet
-          .builder
-          .vec_from_array([ast::Argument::ObjectExpression(arg_obj_expr.into_in(self.alloc))]),
+        args,
         false,
         true,
       )
@@ -485,82 +501,203 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     ret
   }
 
+  // Handle `import.meta.env` references recorded by the scanner for the `importMetaEnv` option
+  pub fn try_rewrite_import_meta_env(&self, expr: &Expression<'ast>) -> Option<Expression<'ast>> {
+    let member_expr = expr.as_member_expression()?;
+    let key = self.ctx.module.ecma_view.import_meta_env_references.get(&member_expr.span())?;
+    let import_meta_env = &self.ctx.options.import_meta_env;
+    if let Some(key) = key {
+      return Some(self.snippet.string_literal_expr(&import_meta_env[key.as_str()], SPAN));
+    }
+    // construct `{ KEY: "value", ... }`
+    let mut obj_expr = ast::ObjectExpression::dummy(self.alloc);
+    obj_expr.properties.extend(import_meta_env.iter().map(|(key, value)| {
+      ast::ObjectPropertyKind::ObjectProperty(
+        ast::ObjectProperty {
+          key: if is_validate_identifier_name(key) {
+            ast::PropertyKey::StaticIdentifier(self.snippet.id_name(key, SPAN).into_in(self.alloc))
+          } else {
+            ast::PropertyKey::StringLiteral(self.snippet.alloc_string_literal(key, SPAN))
+          },
+          value: self.snippet.string_literal_expr(value, SPAN),
+          ..ast::ObjectProperty::dummy(self.alloc)
+        }
+        .into_in(self.alloc),
+      )
+    }));
+    Some(Expression::ObjectExpression(self.builder().alloc(obj_expr)))
+  }
+
   // Handle `import.meta.xxx` expression
   pub fn try_rewrite_import_meta_prop_expr(
     &self,
     member_expr: &ast::StaticMemberExpression<'ast>,
   ) -> Option<Expression<'ast>> {
     if member_expr.object.is_import_meta() {
-      let original_expr_span = member_expr.span;
-      let is_node_cjs = matches!(
-        (self.ctx.options.platform, &self.ctx.options.format),
-        (Platform::Node, OutputFormat::Cjs)
-      );
-
       let property_name = member_expr.property.name.as_str();
-      match property_name {
-        // Try to polyfill `import.meta.url`
-        "url" => {
-          let new_expr = if is_node_cjs {
-            // Replace it with `require('url').pathToFileURL(__filename).href`
-
-            // require('url')
-            let require_call = self.snippet.builder.alloc_call_expression(
-              SPAN,
-              self.snippet.builder.expression_identifier(SPAN, "require"),
-              oxc::ast::NONE,
-              self.snippet.builder.vec1(ast::Argument::StringLiteral(
-                self.snippet.builder.alloc_string_literal(SPAN, "url", None),
-              )),
-              false,
-            );
+      if matches!(property_name, "url" | "dirname" | "filename") {
+        return self.polyfill_import_meta_prop(property_name, member_expr.span);
+      }
+      return self.rewrite_rollup_file_url(property_name);
+    }
+    None
+  }
 
-            // require('url').pathToFileURL
-            let require_path_to_file_url = self.snippet.builder.alloc_static_member_expression(
-              SPAN,
-              ast::Expression::CallExpression(require_call),
-              self.snippet.builder.identifier_name(SPAN, "pathToFileURL"),
-              false,
-            );
+  // Handle `import.meta` destructured by a variable declaration, e.g. `const { url } = import.meta`
+  pub fn try_rewrite_destructured_import_meta(
+    &self,
+    expr: &Expression<'ast>,
+  ) -> Option<Expression<'ast>> {
+    let Expression::MetaProperty(meta) = expr else {
+      return None;
+    };
+    let keys = self.ctx.module.ecma_view.destructured_import_meta.get(&meta.span)?;
+    // construct `{ url: ..., dirname: ... }` with the properties that could be polyfilled
+    let mut obj_expr = ast::ObjectExpression::dummy(self.alloc);
+    obj_expr.properties.extend(keys.iter().filter_map(|key| {
+      let value = self.polyfill_import_meta_prop(key, SPAN)?;
+      Some(ast::ObjectPropertyKind::ObjectProperty(
+        ast::ObjectProperty {
+          key: ast::PropertyKey::StaticIdentifier(
+            self.snippet.id_name(key, SPAN).into_in(self.alloc),
+          ),
+          value,
+          ..ast::ObjectProperty::dummy(self.alloc)
+        }
+        .into_in(self.alloc),
+      ))
+    }));
+    // Keep `import.meta` as it is if nothing could be polyfilled, same as `import.meta.xxx`.
+    if obj_expr.properties.is_empty() {
+      return None;
+    }
+    obj_expr.span = meta.span;
+    Some(Expression::ObjectExpression(self.builder().alloc(obj_expr)))
+  }
 
-            // require('url').pathToFileURL(__filename)
-            let require_path_to_file_url_call = self.snippet.builder.alloc_call_expression(
-              SPAN,
-              ast::Expression::StaticMemberExpression(require_path_to_file_url),
-              oxc::ast::NONE,
-              self.snippet.builder.vec1(ast::Argument::Identifier(
-                self.snippet.builder.alloc_identifier_reference(SPAN, "__filename"),
-              )),
-              false,
-            );
+  // Polyfill `import.meta.url`, `import.meta.dirname` and `import.meta.filename` for the output
+  // format and platform. Returns `None` if the property isn't supported in the output.
+  fn polyfill_import_meta_prop(&self, property_name: &str, span: Span) -> Option<Expression<'ast>> {
+    let is_node_cjs = matches!(
+      (self.ctx.options.platform, &self.ctx.options.format),
+      (Platform::Node, OutputFormat::Cjs)
+    );
 
-            // require('url').pathToFileURL(__filename).href
-            let require_path_to_file_url_href =
-              self.snippet.builder.alloc_static_member_expression(
-                original_expr_span,
-                ast::Expression::CallExpression(require_path_to_file_url_call),
-                self.snippet.builder.identifier_name(SPAN, "href"),
-                false,
-              );
-            Some(ast::Expression::StaticMemberExpression(require_path_to_file_url_href))
-          } else {
-            // If we don't support polyfill `import.meta.url` in this platform and format, we just keep it as it is
-            // so users may handle it in their own way.
-            None
-          };
-          return new_expr;
-        }
-        "dirname" | "filename" => {
-          let name = self.snippet.atom(&format!("__{property_name}"));
-          return is_node_cjs.then_some(ast::Expression::Identifier(
-            self.snippet.builder.alloc_identifier_reference(SPAN, name),
-          ));
+    match property_name {
+      // Try to polyfill `import.meta.url`
+      "url" => {
+        if is_node_cjs {
+          // Replace it with `require('url').pathToFileURL(__filename).href`
+
+          // require('url')
+          let require_call = self.snippet.builder.alloc_call_expression(
+            SPAN,
+            self.snippet.builder.expression_identifier(SPAN, "require"),
+            oxc::ast::NONE,
+            self.snippet.builder.vec1(ast::Argument::StringLiteral(
+              self.snippet.builder.alloc_string_literal(SPAN, "url", None),
+            )),
+            false,
+          );
+
+          // require('url').pathToFileURL
+          let require_path_to_file_url = self.snippet.builder.alloc_static_member_expression(
+            SPAN,
+            ast::Expression::CallExpression(require_call),
+            self.snippet.builder.identifier_name(SPAN, "pathToFileURL"),
+            false,
+          );
+
+          // require('url').pathToFileURL(__filename)
+          let require_path_to_file_url_call = self.snippet.builder.alloc_call_expression(
+            SPAN,
+            ast::Expression::StaticMemberExpression(require_path_to_file_url),
+            oxc::ast::NONE,
+            self.snippet.builder.vec1(ast::Argument::Identifier(
+              self.snippet.builder.alloc_identifier_reference(SPAN, "__filename"),
+            )),
+            false,
+          );
+
+          // require('url').pathToFileURL(__filename).href
+          let require_path_to_file_url_href = self.snippet.builder.alloc_static_member_expression(
+            span,
+            ast::Expression::CallExpression(require_path_to_file_url_call),
+            self.snippet.builder.identifier_name(SPAN, "href"),
+            false,
+          );
+          Some(ast::Expression::StaticMemberExpression(require_path_to_file_url_href))
+        } else if matches!(
+          (self.ctx.options.platform, &self.ctx.options.format),
+          (Platform::Browser, OutputFormat::Iife)
+        ) {
+          Some(self.browser_import_meta_url(span))
+        } else {
+          // If we don't support polyfill `import.meta.url` in this platform and format, we just keep it as it is
+          // so users may handle it in their own way.
+          None
         }
-        _ => {}
       }
-      return self.rewrite_rollup_file_url(property_name);
+      "dirname" | "filename" => {
+        let name = self.snippet.atom(&format!("__{property_name}"));
+        is_node_cjs.then_some(ast::Expression::Identifier(
+          self.snippet.builder.alloc_identifier_reference(span, name),
+        ))
+      }
+      _ => None,
     }
-    None
+  }
+
+  // Create `(_documentCurrentScript && _documentCurrentScript.src || new URL('<chunk filename>', document.baseURI).href)`,
+  // `_documentCurrentScript` is captured at the start of the iife chunk.
+  fn browser_import_meta_url(&self, span: Span) -> Expression<'ast> {
+    let document_prop = |object: Expression<'ast>, name: &'static str| {
+      ast::Expression::StaticMemberExpression(self.snippet.builder.alloc_static_member_expression(
+        SPAN,
+        object,
+        self.snippet.builder.identifier_name(SPAN, name),
+        false,
+      ))
+    };
+    let document = || self.snippet.builder.expression_identifier(SPAN, "document");
+
+    let current_script =
+      || self.snippet.builder.expression_identifier(SPAN, "_documentCurrentScript");
+
+    // _documentCurrentScript && _documentCurrentScript.src
+    let current_script_src = self.snippet.builder.expression_logical(
+      SPAN,
+      current_script(),
+      LogicalOperator::And,
+      document_prop(current_script(), "src"),
+    );
+
+    // new URL('<chunk filename>', document.baseURI).href
+    let chunk_filename =
+      self.ctx.chunk.preliminary_filename.as_deref().map(ArcStr::as_str).unwrap_or_default();
+    let chunk_url = document_prop(
+      self.snippet.builder.expression_new(
+        SPAN,
+        self.snippet.builder.expression_identifier(SPAN, "URL"),
+        NONE,
+        self.snippet.builder.vec_from_array([
+          ast::Argument::StringLiteral(self.snippet.builder.alloc_string_literal(
+            SPAN,
+            self.snippet.builder.atom(chunk_filename),
+            None,
+          )),
+          ast::Argument::from(document_prop(document(), "baseURI")),
+        ]),
+      ),
+      "href",
+    );
+
+    self.snippet.builder.expression_logical(
+      span,
+      current_script_src,
+      LogicalOperator::Or,
+      chunk_url,
+    )
   }
 
   fn rewrite_rollup_file_url(&self, property_name: &str) -> Option<Expression<'ast>> {
@@ -648,6 +785,35 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     None
   }
 
+  pub fn handle_require_resolve_with_string_literal(
+    &self,
+    call_expr: &mut ast::CallExpression<'ast>,
+  ) -> Option<()> {
+    let &rec_idx = self.ctx.module.require_resolve_references.get(&call_expr.span)?;
+    let rec = &self.ctx.module.import_records[rec_idx];
+    if !call_expr.is_global_require_resolve_call(self.scope) {
+      return None;
+    }
+
+    let ast::Argument::StringLiteral(string_literal) = call_expr.arguments.first_mut()? else {
+      return None;
+    };
+
+    let importee = &self.ctx.modules[rec.resolved_module].as_normal()?;
+    let chunk_idx = &self.ctx.chunk_graph.module_to_chunk[importee.idx]?;
+    let chunk = &self.ctx.chunk_graph.chunk_table[*chunk_idx];
+    let asset_filename = &chunk.asset_absolute_preliminary_filenames[&importee.idx];
+    let import_path = self.ctx.chunk.relative_path_for(asset_filename.as_path());
+
+    // `require.resolve('assets/foo.txt')` would look up a package named `assets`.
+    string_literal.value = if import_path.starts_with("../") {
+      self.snippet.atom(&import_path)
+    } else {
+      self.snippet.atom(&concat_string!("./", import_path))
+    };
+    None
+  }
+
   /// try rewrite `foo_exports.bar` or `foo_exports['bar']`  to `bar` directly
   /// try rewrite `import.meta`
   fn try_rewrite_member_expr(
@@ -864,8 +1030,16 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
                     to_commonjs_call_expr
                   };
 
-                  // `(init_xxx(), __toCommonJS(xxx_exports))`
-                  Some(self.snippet.seq2_in_paren_expr(wrap_ref_call_expr, final_expr))
+                  if importee_linking_info.is_tla_or_contains_tla_dependency
+                    && matches!(self.ctx.options.require_esm_interop, RequireEsmInterop::Wrap)
+                  {
+                    // `init_xxx()` is async for modules using top-level await
+                    // `init_xxx().then(() => __toCommonJS(xxx_exports))`
+                    Some(self.snippet.callee_then_call_expr(wrap_ref_call_expr, final_expr))
+                  } else {
+                    // `(init_xxx(), __toCommonJS(xxx_exports))`
+                    Some(self.snippet.seq2_in_paren_expr(wrap_ref_call_expr, final_expr))
+                  }
                 }
               }
             }
@@ -1302,7 +1476,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
         && let Some(rec_id) = self.ctx.module.imports.get(&expr.span)
       {
         // Make sure the import expression is in correct form. If it's not, we should leave it as it is.
-        if let Some(str) = expr.source.as_static_module_request() {
+        if let Some(str) = expr.source.as_constant_module_request() {
           let mut needs_to_esm_helper = false;
           let rec = &self.ctx.module.import_records[*rec_id];
           let importee_id = rec.resolved_module;
@@ -1320,7 +1494,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
             }
             Module::External(importee) => {
               let import_path = importee.get_import_path(self.ctx.chunk);
-              if str != import_path {
+              if str.as_ref() != import_path.as_str() {
                 expr.source = Expression::StringLiteral(
                   self.snippet.alloc_string_literal(&import_path, expr.source.span()),
                 );
@@ -16,7 +16,7 @@ use rolldown_common::{
   StmtInfoIdx, SymbolRef, SymbolRefDb, SymbolRefDbForModule,
 };
 use rolldown_ecmascript::EcmaAst;
-use rolldown_error::{BuildDiagnostic, BuildResult};
+use rolldown_error::{BuildDiagnostic, BuildResult, EventKind};
 use rolldown_fs::OsFileSystem;
 use rolldown_plugin::SharedPluginDriver;
 use rolldown_utils::indexmap::FxIndexSet;
@@ -30,6 +30,7 @@ use crate::module_loader::task_context::TaskContext;
 use crate::stages::scan_stage::resolve_user_defined_entries;
 use crate::types::scan_stage_cache::ScanStageCache;
 use crate::utils::load_entry_module::load_entry_module;
+use crate::utils::validate_define_keys::validate_define_keys;
 use crate::{SharedOptions, SharedResolver};
 
 use super::external_module_task::ExternalModuleTask;
@@ -169,6 +170,7 @@ impl<'a> ModuleLoader<'a> {
         replace_global_define_config: if options.define.is_empty() {
           None
         } else {
+          validate_define_keys(&options.define)?;
           ReplaceGlobalDefinesConfig::new(&options.define).map(Some).map_err(|errs| {
             errs
               .into_iter()
@@ -749,6 +751,14 @@ impl<'a> ModuleLoader<'a> {
       RuntimeModuleBrief::dummy()
     };
 
+    // Every module of a package reports the same `sideEffects` declaration, keep one warning per
+    // `package.json`.
+    let mut reported_package_jsons = FxHashSet::default();
+    all_warnings.retain(|warning| {
+      !matches!(warning.kind(), EventKind::SideEffectsWithoutTreeshake)
+        || reported_package_jsons.insert(warning.id())
+    });
+
     Ok(ModuleLoaderOutput {
       runtime,
       entry_points,
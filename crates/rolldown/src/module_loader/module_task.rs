@@ -6,8 +6,8 @@ use std::sync::Arc;
 use sugar_path::SugarPath;
 
 use rolldown_common::{
-  FlatOptions, ImportKind, ModuleId, ModuleIdx, ModuleInfo, ModuleLoaderMsg, ModuleType,
-  NormalModule, NormalModuleTaskResult, ResolvedId, StrOrBytes,
+  FlatOptions, ImportKind, ImportRecordMeta, ModuleId, ModuleIdx, ModuleInfo, ModuleLoaderMsg,
+  ModuleType, NormalModule, NormalModuleTaskResult, ResolvedId, StrOrBytes,
 };
 use rolldown_error::{
   BuildDiagnostic, BuildResult, UnloadableDependencyContext, downcast_napi_error_diagnostics,
@@ -167,9 +167,32 @@ impl ModuleTask {
     .await?;
 
     if css_view.is_none() {
+      // `export * from './self'` would add the exports of the module to themselves endlessly.
+      let self_reexports = raw_import_records
+        .iter()
+        .zip(&resolved_deps)
+        .filter(|(record, info)| {
+          record.meta.contains(ImportRecordMeta::IsExportStar) && info.id == self.resolved_id.id
+        })
+        .map(|(record, _)| {
+          BuildDiagnostic::self_reexport(
+            self.resolved_id.id.clone(),
+            ecma_view.source.clone(),
+            record.span,
+            record.module_request.to_string(),
+          )
+        })
+        .collect::<Vec<_>>();
+      if !self_reexports.is_empty() {
+        Err(self_reexports)?;
+      }
+
       for (record, info) in raw_import_records.iter().zip(&resolved_deps) {
         match record.kind {
-          ImportKind::Import | ImportKind::Require | ImportKind::NewUrl => {
+          ImportKind::Import
+          | ImportKind::Require
+          | ImportKind::RequireResolve
+          | ImportKind::NewUrl => {
             ecma_view.imported_ids.insert(ArcStr::clone(&info.id).into());
           }
           ImportKind::DynamicImport => {
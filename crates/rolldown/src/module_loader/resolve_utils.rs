@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{iter, sync::Arc};
 
 use arcstr::ArcStr;
 use futures::future::join_all;
@@ -76,6 +76,26 @@ pub async fn resolve_dependencies(
 
     match resolved_id {
       Ok(info) => {
+        let dep = &dependencies[idx];
+        // Resolving to a plugin defined id that isn't a `.node` file is fine.
+        if dep.meta.contains(ImportRecordMeta::IsNativeAddon)
+          && !info.external.is_external()
+          && info.id.ends_with(".node")
+        {
+          for importee in diagnosable_importees(dep, is_css_module) {
+            build_errors.push(BuildDiagnostic::resolve_error(
+              source.clone(),
+              self_resolved_id.id.clone(),
+              importee,
+              "Native addons can't be bundled.".into(),
+              EventKind::ResolveError,
+              Some(
+                "Mark it as external with the `external` option, e.g. `external: [/\\.node$/]`."
+                  .to_string(),
+              ),
+            ));
+          }
+        }
         ret.push(info);
       }
       Err(e) => {
@@ -86,40 +106,46 @@ pub async fn resolve_dependencies(
             // NOTE: IN_TRY_CATCH_BLOCK meta if it is a `require` import
             // record
             if !dep.meta.contains(ImportRecordMeta::InTryCatchBlock) {
-              // https://github.com/rollup/rollup/blob/49b57c2b30d55178a7316f23cc9ccc457e1a2ee7/src/ModuleLoader.ts#L643-L646
-              if ecmascript::is_path_like_specifier(specifier) {
-                // Unlike rollup, we also emit errors for absolute path
-                build_errors.push(BuildDiagnostic::resolve_error(
-                  source.clone(),
-                  self_resolved_id.id.clone(),
-                  if dep.is_unspanned() || is_css_module {
-                    DiagnosableArcstr::String(specifier.as_str().into())
-                  } else {
-                    DiagnosableArcstr::Span(dep.state.span)
-                  },
-                  "Module not found.".into(),
-                  EventKind::UnresolvedImport,
-                  None,
-                ));
-              } else {
-                let help = matches!(options.platform, rolldown_common::Platform::Neutral).then(|| {
-                  r#"The "main" field here was ignored. Main fields must be configured explicitly when using the "neutral" platform."#.to_string()
-                });
-                warnings.push(
-                  BuildDiagnostic::resolve_error(
+              for importee in diagnosable_importees(dep, is_css_module) {
+                if dep.meta.contains(ImportRecordMeta::IsVirtualModuleRequest) {
+                  // There's no file or package to fall back to, so don't treat it as external.
+                  build_errors.push(BuildDiagnostic::resolve_error(
+                    source.clone(),
+                    self_resolved_id.id.clone(),
+                    importee,
+                    "Virtual module not found.".into(),
+                    EventKind::UnresolvedImport,
+                    Some(
+                      "Its id starts with one of `virtualPrefixes`, so it must be resolved by a plugin's `resolveId` hook.".to_string(),
+                    ),
+                  ));
+                } else if ecmascript::is_path_like_specifier(specifier) {
+                  // https://github.com/rollup/rollup/blob/49b57c2b30d55178a7316f23cc9ccc457e1a2ee7/src/ModuleLoader.ts#L643-L646
+                  // Unlike rollup, we also emit errors for absolute path
+                  build_errors.push(BuildDiagnostic::resolve_error(
                     source.clone(),
                     self_resolved_id.id.clone(),
-                    if dep.is_unspanned() || is_css_module {
-                      DiagnosableArcstr::String(specifier.as_str().into())
-                    } else {
-                      DiagnosableArcstr::Span(dep.state.span)
-                    },
-                    "Module not found, treating it as an external dependency".into(),
+                    importee,
+                    "Module not found.".into(),
                     EventKind::UnresolvedImport,
-                    help,
-                  )
-                  .with_severity_warning(),
-                );
+                    None,
+                  ));
+                } else {
+                  let help = matches!(options.platform, rolldown_common::Platform::Neutral).then(|| {
+                    r#"The "main" field here was ignored. Main fields must be configured explicitly when using the "neutral" platform."#.to_string()
+                  });
+                  warnings.push(
+                    BuildDiagnostic::resolve_error(
+                      source.clone(),
+                      self_resolved_id.id.clone(),
+                      importee,
+                      "Module not found, treating it as an external dependency".into(),
+                      EventKind::UnresolvedImport,
+                      help,
+                    )
+                    .with_severity_warning(),
+                  );
+                }
               }
             }
             ret.push(ResolvedId {
@@ -129,33 +155,29 @@ pub async fn resolve_dependencies(
             });
           }
           ResolveError::MatchedAliasNotFound(..) => {
-            build_errors.push(BuildDiagnostic::resolve_error(
+            for importee in diagnosable_importees(dep, is_css_module) {
+              build_errors.push(BuildDiagnostic::resolve_error(
                 source.clone(),
                 self_resolved_id.id.clone(),
-                if dep.is_unspanned() || is_css_module {
-                  DiagnosableArcstr::String(specifier.as_str().into())
-                } else {
-                  DiagnosableArcstr::Span(dep.state.span)
-                },
+                importee,
                 format!("Matched alias not found for '{specifier}'"),
-                    EventKind::ResolveError,
+                EventKind::ResolveError,
                 Some("May be you expected `resolve.alias` to call other plugins resolveId hook? see the docs https://rolldown.rs/reference/config-options#resolve-alias for more details".to_string()),
               ));
+            }
           }
           e => {
             let reason = rolldown_resolver::error::oxc_resolve_error_to_reason(e);
-            build_errors.push(BuildDiagnostic::resolve_error(
-              source.clone(),
-              self_resolved_id.id.clone(),
-              if dep.is_unspanned() || is_css_module {
-                DiagnosableArcstr::String(specifier.as_str().into())
-              } else {
-                DiagnosableArcstr::Span(dep.state.span)
-              },
-              reason,
-              EventKind::ResolveError,
-              None,
-            ));
+            for importee in diagnosable_importees(dep, is_css_module) {
+              build_errors.push(BuildDiagnostic::resolve_error(
+                source.clone(),
+                self_resolved_id.id.clone(),
+                importee,
+                reason.clone(),
+                EventKind::ResolveError,
+                None,
+              ));
+            }
           }
         }
       }
@@ -164,3 +186,16 @@ pub async fn resolve_dependencies(
 
   if build_errors.is_empty() { Ok(ret) } else { Err(build_errors.into()) }
 }
+
+/// Where the diagnostics of `dep` point to. Import declarations merged into one record are
+/// reported once for each declaration.
+fn diagnosable_importees(dep: &RawImportRecord, is_css_module: bool) -> Vec<DiagnosableArcstr> {
+  if dep.is_unspanned() || is_css_module {
+    vec![DiagnosableArcstr::String(dep.module_request.as_str().into())]
+  } else {
+    iter::once(dep.state.span)
+      .chain(dep.state.merged_spans.iter().copied())
+      .map(DiagnosableArcstr::Span)
+      .collect()
+  }
+}
@@ -143,6 +143,7 @@ impl RuntimeModuleTask {
         meta: ecma_view_meta,
         mutations: vec![],
         new_url_references,
+        require_resolve_references: FxHashMap::default(),
         this_expr_replace_map: FxHashMap::default(),
         hmr_info: scan_result.hmr_info,
         hmr_hot_ref: None,
@@ -151,6 +152,16 @@ impl RuntimeModuleTask {
         constant_export_map: FxHashMap::default(),
         depended_runtime_helper: Box::default(),
         import_attribute_map: FxHashMap::default(),
+        dynamic_import_exprs: vec![],
+        import_meta_env_references: FxHashMap::default(),
+        destructured_import_meta: FxHashMap::default(),
+        deprecated_exports: FxHashMap::default(),
+        imports_referenced_at_init: FxHashMap::default(),
+        source_mapping_url: None,
+        scopes_with_direct_eval: FxHashSet::default(),
+        top_level_symbols: vec![],
+        dynamic_requires: vec![],
+        stmt_spans: vec![],
       },
       css_view: None,
       asset_view: None,
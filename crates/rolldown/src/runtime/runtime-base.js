@@ -14,10 +14,11 @@ export var __commonJS = (cb, mod) => function () {
   return mod || (0, cb[__getOwnPropNames(cb)[0]])((mod = { exports: {} }).exports, mod), mod.exports
 }
 export var __commonJSMin = (cb, mod) => () => (mod || cb((mod = { exports: {} }).exports, mod), mod.exports)
-export var __export = (all) => {
+export var __export = (all, symbols) => {
   let target = {}
   for (var name in all)
     __defProp(target, name, { get: all[name], enumerable: true })
+  if (symbols) __defProp(target, Symbol.toStringTag, { value: 'Module' })
   return target;
 }
 export var __copyProps = (to, from, except, desc) => {
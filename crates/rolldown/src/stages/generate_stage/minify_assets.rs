@@ -44,7 +44,7 @@ impl GenerateStage<'_> {
           let codegen_options = CodegenOptions {
             minify: remove_whitespace,
             comments: CommentOptions {
-              normal: false,
+              normal: options.keep_comments.is_some(),
               jsdoc: false,
               annotation: !remove_whitespace,
               legal: if matches!(options.legal_comments, LegalComments::Inline)
@@ -58,6 +58,11 @@ impl GenerateStage<'_> {
             ..CodegenOptions::default()
           };
 
+          let keep_comment =
+            options.keep_comments.as_ref().map(|pattern| |comment: &str| pattern.matches(comment));
+          let keep_var_name =
+            options.keep_var_names.as_ref().map(|pattern| |name: &str| pattern.matches(name));
+
           let allocator_guard = allocator_pool.get();
           // TODO: Do we need to ensure `asset.filename` to be absolute path?
           let (minified_content, new_map) = EcmaCompiler::dce_or_minify(
@@ -69,6 +74,8 @@ impl GenerateStage<'_> {
             compress,
             minify_option.clone(),
             codegen_options,
+            keep_comment.as_ref().map(|keep| keep as &dyn Fn(&str) -> bool),
+            keep_var_name.as_ref().map(|keep| keep as &dyn Fn(&str) -> bool),
           );
           asset.content = minified_content.into();
           match (&asset.map, &new_map) {
@@ -89,7 +89,7 @@ impl<'a> GenerateStage<'a> {
     self.patch_asset_modules(&chunk_graph);
     set_emitted_chunk_preliminary_filenames(&self.plugin_driver.file_emitter, &chunk_graph);
 
-    debug_span!("deconflict_chunk_symbols").in_scope(|| {
+    let deconflict_warnings = debug_span!("deconflict_chunk_symbols").in_scope(|| {
       let module_scope_symbol_id_map = self
         .link_output
         .symbol_db
@@ -113,16 +113,21 @@ impl<'a> GenerateStage<'a> {
         })
         .collect::<FxHashMap<ModuleIdx, IndexVec<ScopeId, Vec<(SymbolId, &str)>>>>();
 
-      chunk_graph.chunk_table.par_iter_mut().for_each(|chunk| {
-        deconflict_chunk_symbols(
-          chunk,
-          self.link_output,
-          self.options.format,
-          &index_chunk_id_to_name,
-          &module_scope_symbol_id_map,
-        );
-      });
+      chunk_graph
+        .chunk_table
+        .par_iter_mut()
+        .flat_map_iter(|chunk| {
+          deconflict_chunk_symbols(
+            chunk,
+            self.link_output,
+            self.options.format,
+            &index_chunk_id_to_name,
+            &module_scope_symbol_id_map,
+          )
+        })
+        .collect::<Vec<_>>()
     });
+    self.link_output.warnings.extend(deconflict_warnings);
     let side_effect_free_function_symbols = self
       .link_output
       .module_table
@@ -1,17 +1,19 @@
 use std::{ops::Deref, sync::Arc};
 
 use futures::future::{try_join_all, try_join3};
-use oxc::span::CompactStr;
+use oxc::span::{CompactStr, SourceType};
 use oxc_index::{IndexVec, index_vec};
 use rolldown_common::{
   Asset, ChunkIdx, ConcatenateWrappedModuleKind, EmittedChunkInfo, InstantiationKind,
-  ModuleRenderArgs, ModuleRenderOutput, Output, OutputAsset, OutputChunk, SharedFileEmitter,
-  SymbolRef,
+  ModuleRenderArgs, ModuleRenderOutput, Output, OutputAsset, OutputChunk, OutputFormat,
+  SharedFileEmitter, SymbolRef,
 };
 use rolldown_debug::{action, trace_action, trace_action_enabled};
+use rolldown_ecmascript::EcmaCompiler;
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_utils::{
   indexmap::{FxIndexMap, FxIndexSet},
+  integrity::sha384_integrity,
   rayon::{IntoParallelRefIterator, ParallelIterator},
 };
 
@@ -25,7 +27,10 @@ use crate::{
   types::generator::{GenerateContext, Generator},
   utils::{
     augment_chunk_hash::augment_chunk_hash,
-    chunk::{finalize_chunks::finalize_assets, render_chunk_exports::get_export_items},
+    chunk::{
+      finalize_chunks::finalize_assets,
+      render_chunk_exports::{get_chunk_export_map, get_export_items},
+    },
     render_chunks::render_chunks,
   },
 };
@@ -63,11 +68,23 @@ impl GenerateStage<'_> {
 
     let mut output = Vec::with_capacity(assets.len());
     let mut output_assets: Vec<Output> = vec![];
+    if let Some(exports_manifest) = &self.options.exports_manifest {
+      output_assets.push(Output::Asset(Arc::new(OutputAsset {
+        filename: exports_manifest.as_str().into(),
+        source: self.render_exports_manifest(chunk_graph, &assets).into(),
+        original_file_names: vec![],
+        names: vec![],
+      })));
+    }
     for Asset { map, meta: rendered_chunk, content: code, filename, .. } in assets {
       match rendered_chunk {
         InstantiationKind::Ecma(ecma_meta) => {
           let code = code.try_into_string()?;
+          if self.options.validate {
+            errors.extend(validate_chunk_code(&filename, &code, self.options.format));
+          }
           let rendered_chunk = ecma_meta.rendered_chunk;
+          let integrity = self.options.integrity.then(|| sha384_integrity(code.as_bytes()));
           output.push(Output::Chunk(Arc::new(OutputChunk {
             name: rendered_chunk.name.clone(),
             filename: filename.clone(),
@@ -83,6 +100,7 @@ impl GenerateStage<'_> {
             map,
             sourcemap_filename: ecma_meta.sourcemap_filename,
             preliminary_filename: ecma_meta.preliminary_filename.to_string(),
+            integrity,
           })));
         }
         InstantiationKind::Css(_css_meta) => {
@@ -277,6 +295,26 @@ impl GenerateStage<'_> {
       .collect::<Vec<_>>()
   }
 
+  /// Render the `output.exportsManifest` asset, which maps the file name of each chunk to its
+  /// exports, from the original export names to the output names.
+  fn render_exports_manifest(&self, chunk_graph: &ChunkGraph, assets: &AssetVec) -> String {
+    let manifest = assets
+      .iter()
+      .filter(|asset| matches!(asset.meta, InstantiationKind::Ecma(_)))
+      .filter_map(|asset| {
+        let chunk = &chunk_graph.chunk_table[asset.originate_from?];
+        let exports = get_chunk_export_map(chunk, self.link_output)
+          .into_iter()
+          .map(|(original_name, output_name)| {
+            (original_name.to_string(), serde_json::Value::String(output_name.to_string()))
+          })
+          .collect::<serde_json::Map<_, _>>();
+        Some((asset.filename.to_string(), serde_json::Value::Object(exports)))
+      })
+      .collect::<serde_json::Map<_, _>>();
+    serde_json::to_string_pretty(&manifest).expect("the exports manifest should be serializable")
+  }
+
   fn trace_action_assets_ready(index_assets: &AssetVec) {
     if trace_action_enabled!() {
       let mut assets = vec![];
@@ -314,6 +352,16 @@ fn get_sorting_file_type(output: &Output) -> SortingFileType {
   }
 }
 
+/// Re-parse the final code of a chunk for `output.validate`, so bugs in code generation or in
+/// `renderChunk` hooks fail the build instead of shipping broken output.
+fn validate_chunk_code(filename: &str, code: &str, format: OutputFormat) -> Vec<BuildDiagnostic> {
+  let source_type = SourceType::mjs().with_module(matches!(format, OutputFormat::Esm));
+  match EcmaCompiler::parse(filename, code, source_type) {
+    Ok(_) => vec![],
+    Err(errors) => errors.into_vec(),
+  }
+}
+
 pub fn set_emitted_chunk_preliminary_filenames(
   file_emitter: &SharedFileEmitter,
   chunk_graph: &ChunkGraph,
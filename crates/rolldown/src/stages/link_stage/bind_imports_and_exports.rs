@@ -6,11 +6,12 @@ use oxc::span::CompactStr;
 // TODO: The current implementation for matching imports is enough so far but incomplete. It needs to be refactored
 // if we want more enhancements related to exports.
 use rolldown_common::{
-  EcmaModuleAstUsage, ExportsKind, IndexModules, MemberExprRefResolution, Module, ModuleIdx,
-  ModuleType, NamespaceAlias, NormalModule, OutputFormat, ResolvedExport, Specifier,
-  SymbolOrMemberExprRef, SymbolRef, SymbolRefDb,
+  EcmaModuleAstUsage, EntryPointKind, ExportsKind, ImportRecordMeta, IndexModules,
+  MemberExprRefResolution, Module, ModuleIdx, ModuleType, NamedImport, NamespaceAlias,
+  NormalModule, OutputFormat, ResolvedExport, Specifier, SymbolOrMemberExprRef, SymbolRef,
+  SymbolRefDb,
 };
-use rolldown_error::{AmbiguousExternalNamespaceModule, BuildDiagnostic};
+use rolldown_error::{AmbiguousExternalNamespaceModule, BuildDiagnostic, EventKindSwitcher};
 use rolldown_utils::{
   ecmascript::{is_validate_identifier_name, legitimize_identifier_name},
   index_vec_ext::{IndexVecExt, IndexVecRefExt},
@@ -237,11 +238,48 @@ impl LinkStage<'_> {
       meta.sorted_and_non_ambiguous_resolved_exports =
         FxIndexMap::from_iter(sorted_and_non_ambiguous_resolved_exports);
     });
+    self.warn_entries_without_exports();
     self.update_cjs_module_meta();
     self.resolve_member_expr_refs(&side_effects_modules, &normal_symbol_exports_chain_map);
     self.normal_symbol_exports_chain_map = normal_symbol_exports_chain_map;
   }
 
+  /// An `esm` or `cjs` library entry that exports nothing is usually a misconfiguration.
+  fn warn_entries_without_exports(&mut self) {
+    let format = match self.options.format {
+      OutputFormat::Esm => "esm",
+      OutputFormat::Cjs => "cjs",
+      OutputFormat::Iife | OutputFormat::Umd => return,
+    };
+    for entry in &self.entries {
+      if !matches!(entry.kind, EntryPointKind::UserDefined)
+        || entry.name.as_ref().is_some_and(|name| {
+          self.options.side_effect_entries.iter().any(|entry_name| entry_name == name.as_str())
+        })
+      {
+        continue;
+      }
+      let Some(module) = self.module_table[entry.idx].as_normal() else {
+        continue;
+      };
+      let meta = &self.metas[entry.idx];
+      if module.exports_kind.is_commonjs()
+        || meta.has_dynamic_exports
+        || !meta.resolved_exports.is_empty()
+      {
+        continue;
+      }
+      self.warnings.push(
+        BuildDiagnostic::entry_without_exports(
+          module.id.to_string(),
+          module.stable_id.to_string(),
+          format,
+        )
+        .with_severity_warning(),
+      );
+    }
+  }
+
   /// Update the metadata of CommonJS modules.
   /// - Safe to eliminate interop default export
   ///   e.g.
@@ -322,8 +360,17 @@ impl LinkStage<'_> {
 
     let is_cjsreexports = module.ast_usage.contains(EcmaModuleAstUsage::IsCjsReexport);
 
-    let cjs_reexport_module =
-      is_cjsreexports.then(|| module.import_records.first().unwrap().resolved_module);
+    // The last `module.exports = require('mod')` wins at runtime.
+    let cjs_reexport_module = is_cjsreexports
+      .then(|| {
+        module
+          .import_records
+          .iter()
+          .rev()
+          .find(|rec| rec.meta.contains(ImportRecordMeta::IsCjsReexportTarget))
+      })
+      .flatten()
+      .map(|rec| rec.resolved_module);
 
     for dep_id in module.star_export_module_ids().chain(cjs_reexport_module) {
       let Module::Normal(dep_module) = &normal_modules[dep_id] else {
@@ -699,6 +746,33 @@ impl BindImportsAndExportsContext<'_> {
           }
           self.normal_symbol_exports_chain_map.insert(*imported_as_ref, reexports);
 
+          if let Some(deprecation) = self.index_modules[symbol.owner]
+            .as_normal()
+            .and_then(|exporter| exporter.deprecated_exports.get(&symbol.symbol))
+          {
+            self.warnings.push(
+              BuildDiagnostic::deprecated_export(
+                module.id.to_string(),
+                module.stable_id.to_string(),
+                self.index_modules[rec.resolved_module].stable_id().to_string(),
+                module.source.clone(),
+                named_import.imported.to_string(),
+                named_import.span_imported,
+                deprecation.clone(),
+              )
+              .with_severity_warning(),
+            );
+          }
+
+          if self.options.checks.contains(EventKindSwitcher::CircularImportAtInit) {
+            self.warnings.extend(self.check_circular_import_at_init(
+              module,
+              *imported_as_ref,
+              named_import,
+              symbol,
+            ));
+          }
+
           self.symbol_db.link(*imported_as_ref, symbol);
         }
         MatchImportKind::Namespace { namespace_ref } => {
@@ -724,7 +798,9 @@ impl BindImportsAndExportsContext<'_> {
             named_import.span_imported,
             is_ts_like_importing_ts_like.then(|| format!("If you meant to import a type rather than a value, make sure to add the `type` modifier (e.g. `import {{ type Foo }} from '{}'`).", rec.module_request))
           );
-          if is_ts_like_importing_ts_like {
+          // A missing export between TypeScript modules is usually a type, which has to be
+          // re-exported with the `type` modifier under `isolatedModules`
+          if is_ts_like_importing_ts_like && !self.options.isolated_modules {
             diagnostic = diagnostic.with_severity_warning();
             self.warnings.push(diagnostic);
           } else {
@@ -735,6 +811,52 @@ impl BindImportsAndExportsContext<'_> {
     }
   }
 
+  /// Warn about an import binding used during the initialization of `module`, if the module
+  /// declaring it is executed later because of a circular import, e.g.
+  ///
+  /// ```js
+  /// // a.js
+  /// import b from './b.js'
+  /// export default 'a'
+  /// // b.js
+  /// import a from './a.js'
+  /// console.log(a) // `a.js` hasn't been executed yet if it's the entry
+  /// export default 'b'
+  /// ```
+  ///
+  /// Static imports are always executed before the importer unless they are part of a cycle, so
+  /// comparing the execution order is enough. Function declarations are hoisted, so they are fine.
+  fn check_circular_import_at_init(
+    &self,
+    module: &NormalModule,
+    imported_as_ref: SymbolRef,
+    named_import: &NamedImport,
+    symbol: SymbolRef,
+  ) -> Option<BuildDiagnostic> {
+    let reference_span = module.imports_referenced_at_init.get(&imported_as_ref.symbol)?;
+    let exporter = self.index_modules[symbol.owner].as_normal()?;
+    if exporter.exec_order <= module.exec_order {
+      return None;
+    }
+    let is_hoisted_function = self.symbol_db[symbol.owner].as_ref().is_some_and(|db| {
+      !db.is_facade_symbol(symbol.symbol) && db.scoping().symbol_flags(symbol.symbol).is_function()
+    });
+    if is_hoisted_function {
+      return None;
+    }
+    Some(
+      BuildDiagnostic::circular_import_at_init(
+        module.id.to_string(),
+        module.stable_id.to_string(),
+        exporter.stable_id.to_string(),
+        module.source.clone(),
+        named_import.imported.to_string(),
+        *reference_span,
+      )
+      .with_severity_warning(),
+    )
+  }
+
   fn advance_import_tracker(&self, ctx: &MatchingContext) -> ImportStatus {
     let tracker = ctx.current_tracker();
     let importer = &self.index_modules[tracker.importer]
@@ -0,0 +1,95 @@
+use std::iter;
+
+use oxc_index::IndexVec;
+use rolldown_common::{ImportRecordMeta, ModuleIdx, NormalModule};
+use rolldown_error::{BuildDiagnostic, EventKindSwitcher};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::LinkStage;
+
+impl LinkStage<'_> {
+  /// Warn about modules that re-export from each other in a loop, e.g. `a.js` has
+  /// `export * from './b'` and `b.js` has `export { foo } from './a'`. Unlike circular imports,
+  /// which are fine at runtime as long as the bindings are read late enough, the exports forwarded
+  /// around such a loop may be ambiguous or never defined.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub(super) fn check_circular_reexports(&mut self) {
+    if !self.options.checks.contains(EventKindSwitcher::CircularReexport) {
+      return;
+    }
+    let reexports = self
+      .module_table
+      .modules
+      .iter()
+      .map(|module| module.as_normal().map(reexported_modules).unwrap_or_default())
+      .collect::<IndexVec<ModuleIdx, _>>();
+
+    let mut visited = FxHashSet::default();
+    let mut cycles = vec![];
+    for start in reexports.indices() {
+      if !visited.insert(start) {
+        continue;
+      }
+      // Modules on the current re-export chain and the index of the next edge to follow.
+      let mut path = vec![(start, 0)];
+      let mut indexes_on_path = FxHashMap::from_iter([(start, 0)]);
+      while let Some(&(idx, next_edge)) = path.last() {
+        let Some(&importee) = reexports[idx].get(next_edge) else {
+          indexes_on_path.remove(&idx);
+          path.pop();
+          continue;
+        };
+        let last = path.len() - 1;
+        path[last].1 += 1;
+        if let Some(&index) = indexes_on_path.get(&importee) {
+          cycles.push(
+            path[index..]
+              .iter()
+              .map(|(idx, _)| *idx)
+              .chain(iter::once(importee))
+              .collect::<Vec<_>>(),
+          );
+        } else if visited.insert(importee) {
+          indexes_on_path.insert(importee, path.len());
+          path.push((importee, 0));
+        }
+      }
+    }
+
+    for cycle in cycles {
+      let paths = cycle
+        .iter()
+        .filter_map(|id| self.module_table[*id].as_normal().map(|module| module.id.to_string()))
+        .collect::<Vec<_>>();
+      self.warnings.push(BuildDiagnostic::circular_reexport(paths).with_severity_warning());
+    }
+  }
+}
+
+/// Modules the exports of `module` are forwarded from, by `export * from` or by re-exporting an
+/// imported binding, in the order of the import records.
+fn reexported_modules(module: &NormalModule) -> Vec<ModuleIdx> {
+  let mut records = module
+    .import_records
+    .iter_enumerated()
+    .filter(|(_, rec)| rec.meta.contains(ImportRecordMeta::IsExportStar))
+    .map(|(rec_idx, _)| rec_idx)
+    .chain(
+      module
+        .named_exports
+        .values()
+        .filter_map(|export| module.named_imports.get(&export.referenced))
+        .map(|import| import.record_id),
+    )
+    .collect::<Vec<_>>();
+  records.sort_unstable();
+  records.dedup();
+  let mut importees = records
+    .into_iter()
+    .map(|rec_idx| module.import_records[rec_idx].resolved_module)
+    .collect::<Vec<_>>();
+  // Different specifiers may resolve to the same module
+  let mut seen = FxHashSet::default();
+  importees.retain(|idx| seen.insert(*idx));
+  importees
+}
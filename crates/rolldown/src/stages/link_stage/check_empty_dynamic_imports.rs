@@ -0,0 +1,62 @@
+use rolldown_common::{EcmaViewMeta, ExportsKind, ImportKind, ImportRecordMeta};
+use rolldown_error::{BuildDiagnostic, EventKindSwitcher};
+
+use super::LinkStage;
+
+impl LinkStage<'_> {
+  /// Warn about `import()`s of ES modules that have nothing left but their namespace object after
+  /// tree shaking, e.g. modules with only type declarations. The chunk created for such an import
+  /// is empty. Modules re-exporting bindings of other modules are never considered empty.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub(super) fn check_empty_dynamic_imports(&mut self) {
+    if !self.options.checks.contains(EventKindSwitcher::EmptyDynamicImport) {
+      return;
+    }
+    let mut warnings = vec![];
+    self.module_table.modules.iter().filter_map(|m| m.as_normal()).for_each(|module| {
+      if !module.meta.contains(EcmaViewMeta::Included) {
+        return;
+      }
+      let mut empty_importees = module
+        .imports
+        .iter()
+        .filter_map(|(span, rec_idx)| {
+          let rec = &module.import_records[*rec_idx];
+          if !matches!(rec.kind, ImportKind::DynamicImport)
+            || rec.meta.contains(ImportRecordMeta::DeadDynamicImport)
+            || !rec.related_stmt_info_idx.is_some_and(|idx| module.stmt_infos[idx].is_included)
+          {
+            return None;
+          }
+          let importee = self.module_table[rec.resolved_module].as_normal()?;
+          let is_empty = !matches!(importee.exports_kind, ExportsKind::CommonJs)
+            && importee.idx != self.runtime.id()
+            && importee
+              .stmt_infos
+              .iter_enumerated_without_namespace_stmt()
+              .all(|(_, stmt_info)| !stmt_info.is_included)
+            // Re-exports like `export { x } from './m'` are declared by other modules, a barrel
+            // module has no included statements of its own but its namespace isn't empty.
+            && self.metas[importee.idx]
+              .resolved_exports
+              .values()
+              .all(|export| export.symbol_ref.owner == importee.idx);
+          is_empty.then_some((*span, importee))
+        })
+        .collect::<Vec<_>>();
+      // `imports` is a hash map, sort by position to keep the diagnostics stable.
+      empty_importees.sort_unstable_by_key(|(span, _)| span.start);
+      warnings.extend(empty_importees.into_iter().map(|(span, importee)| {
+        BuildDiagnostic::empty_dynamic_import(
+          module.id.to_string(),
+          module.stable_id.to_string(),
+          importee.stable_id.to_string(),
+          module.source.clone(),
+          span,
+        )
+        .with_severity_warning()
+      }));
+    });
+    self.warnings.extend(warnings);
+  }
+}
@@ -0,0 +1,60 @@
+use oxc::span::{CompactStr, Span};
+use rolldown_common::{ImportKind, ImportRecordMeta, ModuleIdx};
+use rolldown_error::{BuildDiagnostic, EventKindSwitcher};
+use rolldown_utils::indexmap::FxIndexMap;
+
+use super::LinkStage;
+
+impl LinkStage<'_> {
+  /// Warn about modules that are both imported by `import`/`export ... from` and required by
+  /// `require()` in the same module. Import records are compared by the module they resolve to, so
+  /// `import './m'` and `require('./m.js')` are reported as well.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub(super) fn check_mixed_import_and_require(&mut self) {
+    if !self.options.checks.contains(EventKindSwitcher::MixedImportAndRequire) {
+      return;
+    }
+    let mut warnings = vec![];
+    self.module_table.modules.iter().filter_map(|m| m.as_normal()).for_each(|module| {
+      let mut imports = module.imports.iter().collect::<Vec<_>>();
+      // `imports` is a hash map, sort by position to find the first usages and keep the
+      // diagnostics stable.
+      imports.sort_unstable_by_key(|(span, _)| span.start);
+      // resolved module -> (request and span of the first import, span of the first require)
+      let mut usages: FxIndexMap<ModuleIdx, (Option<(&CompactStr, Span)>, Option<Span>)> =
+        FxIndexMap::default();
+      for (span, rec_idx) in imports {
+        let rec = &module.import_records[*rec_idx];
+        if rec.meta.contains(ImportRecordMeta::IsUnspannedImport) {
+          continue;
+        }
+        let usage = usages.entry(rec.resolved_module).or_default();
+        match rec.kind {
+          ImportKind::Import => {
+            usage.0.get_or_insert((&rec.module_request, *span));
+          }
+          ImportKind::Require => {
+            usage.1.get_or_insert(*span);
+          }
+          _ => {}
+        }
+      }
+      warnings.extend(usages.into_values().filter_map(|usage| {
+        let (Some((specifier, import_span)), Some(require_span)) = usage else {
+          return None;
+        };
+        Some(
+          BuildDiagnostic::mixed_import_and_require(
+            module.id.to_string(),
+            module.source.clone(),
+            specifier.as_str().into(),
+            import_span,
+            require_span,
+          )
+          .with_severity_warning(),
+        )
+      }));
+    });
+    self.warnings.extend(warnings);
+  }
+}
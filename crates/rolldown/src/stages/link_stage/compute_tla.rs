@@ -1,4 +1,7 @@
-use rolldown_common::{EcmaModuleAstUsage, ImportKind, ModuleIdx, ModuleTable};
+use rolldown_common::{
+  EcmaModuleAstUsage, ExportsKind, ImportKind, ModuleIdx, ModuleTable, RequireEsmInterop,
+};
+use rolldown_error::BuildDiagnostic;
 use rustc_hash::FxHashMap;
 
 use super::LinkStage;
@@ -30,7 +33,7 @@ impl LinkStage<'_> {
         let contains_tla_dependency = module
           .import_records()
           .iter()
-          // TODO: require TLA module should give a error
+          // `require()` of a TLA module is reported by `check_require_of_tla_modules`.
           .filter(|rec| matches!(rec.kind, ImportKind::Import))
           .any(|rec| {
             let importee = &module_table[rec.resolved_module];
@@ -49,4 +52,50 @@ impl LinkStage<'_> {
         is_tla(module.idx, &self.module_table, &mut visited_map);
     });
   }
+
+  /// A module using top-level await can't be evaluated synchronously, so `require()`ing it is
+  /// handled according to `requireEsmInterop`. `Wrap` is applied by the module finalizer.
+  ///
+  /// Must run after `determine_module_exports_kind`, so required modules without any ESM syntax
+  /// are already known to be CommonJS.
+  pub(super) fn check_require_of_tla_modules(&mut self) {
+    let interop = self.options.require_esm_interop;
+    if matches!(interop, RequireEsmInterop::Wrap) {
+      return;
+    }
+    let mut diagnostics = vec![];
+    self.module_table.modules.iter().filter_map(|m| m.as_normal()).for_each(|module| {
+      let mut required_tla_modules = module
+        .imports
+        .iter()
+        .filter_map(|(span, rec_idx)| {
+          let rec = &module.import_records[*rec_idx];
+          if !matches!(rec.kind, ImportKind::Require) {
+            return None;
+          }
+          let importee = self.module_table[rec.resolved_module].as_normal()?;
+          (!matches!(importee.exports_kind, ExportsKind::CommonJs)
+            && self.metas[importee.idx].is_tla_or_contains_tla_dependency)
+            .then_some((*span, importee))
+        })
+        .collect::<Vec<_>>();
+      // `imports` is a hash map, sort by position to keep the diagnostics stable.
+      required_tla_modules.sort_unstable_by_key(|(span, _)| span.start);
+      diagnostics.extend(required_tla_modules.into_iter().map(|(span, importee)| {
+        BuildDiagnostic::require_async_module(
+          module.id.to_string(),
+          module.stable_id.to_string(),
+          importee.stable_id.to_string(),
+          module.source.clone(),
+          span,
+        )
+      }));
+    });
+
+    if matches!(interop, RequireEsmInterop::Warn) {
+      self.warnings.extend(diagnostics.into_iter().map(BuildDiagnostic::with_severity_warning));
+    } else {
+      self.errors.extend(diagnostics);
+    }
+  }
 }
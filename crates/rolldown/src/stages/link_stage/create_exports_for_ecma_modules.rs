@@ -93,7 +93,7 @@ impl LinkStage<'_> {
           let meta = &mut self.metas[ecma_module.idx];
           let mut referenced_symbols = vec![];
           let mut declared_symbols = vec![];
-          if !meta.is_canonical_exports_empty() {
+          if !meta.is_canonical_exports_empty() || self.options.namespace_to_string_tag {
             referenced_symbols.push(self.runtime.resolve_symbol("__export").into());
             referenced_symbols
               .extend(meta.canonical_exports(false).map(|(_, export)| export.symbol_ref.into()));
@@ -1,7 +1,7 @@
 use oxc::{
   allocator::Address,
   ast::{
-    AstBuilder,
+    AstBuilder, Comment,
     ast::{
       BindingPatternKind, Declaration, ExportDefaultDeclaration, ExportDefaultDeclarationKind,
       ExportNamedDeclaration,
@@ -164,6 +164,8 @@ impl LinkStage<'_> {
             flat_options: self.flat_options,
             options: self.options,
             ast_scope: &self.symbols.local_db(module_idx).ast_scopes,
+            comments: &dep.program.comments,
+            source: &module.source,
           },
         };
         ctx.visit_program(&dep.program);
@@ -196,6 +198,8 @@ struct CrossModuleOptimizationImmutableCtx<'a, 'ast: 'a> {
   flat_options: FlatOptions,
   options: &'a SharedNormalizedBundlerOptions,
   ast_scope: &'a AstScopes,
+  comments: &'a [Comment],
+  source: &'a str,
 }
 
 struct CrossModuleOptimizationRunnerContext<'a, 'ast: 'a> {
@@ -243,6 +247,8 @@ impl<'a, 'ast: 'a> Visit<'ast> for CrossModuleOptimizationRunnerContext<'a, 'ast
           self.immutable_ctx.ast_scope,
           self.immutable_ctx.flat_options,
           self.immutable_ctx.options,
+          self.immutable_ctx.comments,
+          self.immutable_ctx.source,
           Some(&self.side_effect_free_call_expr_addr),
         )
         .detect_side_effect_of_stmt(stmt);
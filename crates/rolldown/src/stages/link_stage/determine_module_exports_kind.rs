@@ -76,7 +76,7 @@ impl LinkStage<'_> {
           ImportKind::UrlImport => {
             unreachable!("A Js module would never import a CSS module via `url()`");
           }
-          ImportKind::NewUrl | ImportKind::HotAccept => {}
+          ImportKind::RequireResolve | ImportKind::NewUrl | ImportKind::HotAccept => {}
         }
       });
 
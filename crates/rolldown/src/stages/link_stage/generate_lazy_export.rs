@@ -97,7 +97,12 @@ fn update_module_default_export_info(
 ) {
   module.named_exports.insert(
     "default".into(),
-    LocalExport { span: SPAN, referenced: default_symbol_ref, came_from_commonjs: false },
+    LocalExport {
+      span: SPAN,
+      referenced: default_symbol_ref,
+      came_from_commonjs: false,
+      is_conditional: false,
+    },
   );
   // needs to support `preferConst`, so default statement may not be the second stmt info
   module.stmt_infos.declare_symbol_for_stmt(idx, TaggedSymbolRef::Normal(default_symbol_ref));
@@ -242,7 +247,12 @@ fn json_object_expr_to_esm(link_staged: &mut LinkStage, module_idx: ModuleIdx) -
     module.stmt_infos.add_stmt_info(stmt_info);
     module.named_exports.insert(
       exported.clone(),
-      LocalExport { span: SPAN, referenced: symbol_ref, came_from_commonjs: false },
+      LocalExport {
+        span: SPAN,
+        referenced: symbol_ref,
+        came_from_commonjs: false,
+        is_conditional: false,
+      },
     );
   }
   // declare default export statement
@@ -253,7 +263,12 @@ fn json_object_expr_to_esm(link_staged: &mut LinkStage, module_idx: ModuleIdx) -
   module.stmt_infos.add_stmt_info(stmt_info);
   module.named_exports.insert(
     "default".into(),
-    LocalExport { span: SPAN, referenced: default_export_ref, came_from_commonjs: false },
+    LocalExport {
+      span: SPAN,
+      referenced: default_export_ref,
+      came_from_commonjs: false,
+      is_conditional: false,
+    },
   );
 
   // declare namespace object statement
@@ -27,6 +27,9 @@ use crate::{
 use super::scan_stage::NormalizedScanStageOutput;
 
 mod bind_imports_and_exports;
+mod check_circular_reexports;
+mod check_empty_dynamic_imports;
+mod check_mixed_import_and_require;
 mod compute_tla;
 mod create_exports_for_ecma_modules;
 mod cross_module_optimization;
@@ -169,8 +172,11 @@ impl<'a> LinkStage<'a> {
   #[tracing::instrument(level = "debug", skip_all)]
   pub fn link(mut self) -> LinkStageOutput {
     self.sort_modules();
+    self.check_circular_reexports();
+    self.check_mixed_import_and_require();
     self.compute_tla();
     self.determine_module_exports_kind();
+    self.check_require_of_tla_modules();
     self.wrap_modules();
     self.generate_lazy_export();
     self.determine_side_effects();
@@ -179,6 +185,7 @@ impl<'a> LinkStage<'a> {
     self.reference_needed_symbols();
     self.cross_module_optimization();
     self.include_statements();
+    self.check_empty_dynamic_imports();
     self.patch_module_dependencies();
 
     tracing::trace!("meta {:#?}", self.metas.iter_enumerated().collect::<Vec<_>>());
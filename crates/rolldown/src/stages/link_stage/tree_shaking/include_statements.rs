@@ -438,9 +438,6 @@ fn include_module(ctx: &mut Context, module: &NormalModule) {
     module.stmt_infos.iter_enumerated_without_namespace_stmt().for_each(
       |(stmt_info_id, stmt_info)| {
         // No need to handle the namespace statement specially, because it doesn't have side effects and will only be included if it is used.
-        let bail_eval = module.meta.has_eval()
-          && !stmt_info.declared_symbols.is_empty()
-          && stmt_info_id.index() != 0;
         let has_side_effects = if module.meta.contains(EcmaViewMeta::SafelyTreeshakeCommonjs)
           && ctx.options.treeshake.commonjs()
         {
@@ -448,7 +445,7 @@ fn include_module(ctx: &mut Context, module: &NormalModule) {
         } else {
           stmt_info.side_effect.has_side_effect()
         };
-        if has_side_effects || bail_eval {
+        if has_side_effects {
           include_statement(ctx, module, stmt_info_id);
         }
       },
@@ -488,12 +485,6 @@ fn include_module(ctx: &mut Context, module: &NormalModule) {
     module.stable_id,
     module_meta.dependencies.iter().map(|idx| { ctx.modules[*idx].id().to_string() }).collect_vec()
   );
-  if module.meta.has_eval() && matches!(module.module_type, ModuleType::Js | ModuleType::Jsx) {
-    module.named_imports.keys().for_each(|symbol| {
-      include_symbol(ctx, *symbol, SymbolIncludeReason::Normal);
-    });
-  }
-
   ctx.metas[module.idx].included_commonjs_export_symbol.iter().for_each(|symbol_ref| {
     include_symbol(ctx, *symbol_ref, SymbolIncludeReason::Normal);
   });
@@ -607,6 +598,23 @@ fn include_symbol(ctx: &mut Context, symbol_ref: SymbolRef, include_kind: Symbol
   }
 }
 
+/// A direct `eval(...)` could reference any top level binding of the module by name, so all the
+/// declarations and imports need to be kept once a statement containing it is included.
+fn include_bindings_reachable_by_eval(ctx: &mut Context, module: &NormalModule) {
+  module.stmt_infos.iter_enumerated_without_namespace_stmt().for_each(
+    |(stmt_info_id, stmt_info)| {
+      if !stmt_info.declared_symbols.is_empty() {
+        include_statement(ctx, module, stmt_info_id);
+      }
+    },
+  );
+  if matches!(module.module_type, ModuleType::Js | ModuleType::Jsx) {
+    module.named_imports.keys().for_each(|symbol| {
+      include_symbol(ctx, *symbol, SymbolIncludeReason::Normal);
+    });
+  }
+}
+
 fn include_statement(ctx: &mut Context, module: &NormalModule, stmt_info_id: StmtInfoIdx) {
   let is_included = &mut ctx.is_included_vec[module.idx][stmt_info_id];
 
@@ -619,6 +627,10 @@ fn include_statement(ctx: &mut Context, module: &NormalModule, stmt_info_id: Stm
   // include the statement itself
   *is_included = true;
 
+  if stmt_info.meta.contains(StmtInfoMeta::HasEval) {
+    include_bindings_reachable_by_eval(ctx, module);
+  }
+
   // FIXME: bailout for require() import for now
   // it is fine for now, since webpack did not support it either
   // ```js
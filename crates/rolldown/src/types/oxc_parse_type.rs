@@ -5,6 +5,8 @@ pub enum OxcParseType {
   Jsx,
   Ts,
   Tsx,
+  /// TypeScript declaration files, e.g. `.d.ts`, which have no runtime code.
+  Dts,
 }
 
 impl From<&ModuleType> for OxcParseType {
@@ -5,6 +5,7 @@ use arcstr::ArcStr;
 use rolldown_common::{
   Chunk, ChunkIdx, ChunkKind, GetLocalDb, ModuleScopeSymbolIdMap, OutputFormat, TaggedSymbolRef,
 };
+use rolldown_error::BuildDiagnostic;
 use rolldown_utils::ecmascript::legitimize_identifier_name;
 use rustc_hash::FxHashMap;
 
@@ -16,9 +17,46 @@ pub fn deconflict_chunk_symbols(
   format: OutputFormat,
   index_chunk_id_to_name: &FxHashMap<ChunkIdx, ArcStr>,
   map: &ModuleScopeSymbolIdMap<'_>,
-) {
+) -> Vec<BuildDiagnostic> {
   let mut renamer = Renamer::new(&link_output.symbol_db, format);
 
+  chunk
+    .modules
+    .iter()
+    .copied()
+    .filter_map(|id| link_output.module_table[id].as_normal())
+    .flat_map(|m| {
+      link_output.symbol_db[m.idx]
+        .as_ref()
+        .unwrap()
+        .ast_scopes
+        .scoping()
+        .root_unresolved_references()
+        .keys()
+    })
+    .for_each(|name| {
+      // global names should be reserved
+      renamer.reserve(CompactStr::new(name));
+    });
+
+  // Names visible to a direct `eval` are kept before any other symbol is named. Only reserved
+  // names, the global names above and other modules using `eval` can take them first.
+  let mut warnings = vec![];
+  chunk
+    .modules
+    .iter()
+    .copied()
+    .filter_map(|id| link_output.module_table[id].as_normal())
+    .filter(|module| !module.scopes_with_direct_eval.is_empty())
+    .for_each(|module| {
+      for name in renamer.preserve_names_visible_to_eval(module, map) {
+        warnings.push(
+          BuildDiagnostic::eval_binding_renamed(module.id.to_string(), name.to_string())
+            .with_severity_warning(),
+        );
+      }
+    });
+
   if matches!(format, OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Cjs) {
     // deconflict iife introduce symbols by external
     // Also AMD, but we don't support them yet.
@@ -55,25 +93,6 @@ pub fn deconflict_chunk_symbols(
     }
   }
 
-  chunk
-    .modules
-    .iter()
-    .copied()
-    .filter_map(|id| link_output.module_table[id].as_normal())
-    .flat_map(|m| {
-      link_output.symbol_db[m.idx]
-        .as_ref()
-        .unwrap()
-        .ast_scopes
-        .scoping()
-        .root_unresolved_references()
-        .keys()
-    })
-    .for_each(|name| {
-      // global names should be reserved
-      renamer.reserve(CompactStr::new(name));
-    });
-
   match chunk.kind {
     ChunkKind::EntryPoint { module, .. } => {
       let meta = &link_output.metas[module];
@@ -159,4 +178,5 @@ pub fn deconflict_chunk_symbols(
   renamer.rename_non_root_symbol(&chunk.modules, link_output, map);
 
   chunk.canonical_names = renamer.into_canonical_names();
+  warnings
 }
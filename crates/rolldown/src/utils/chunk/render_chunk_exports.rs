@@ -304,6 +304,46 @@ pub fn get_chunk_export_names(chunk: &Chunk, graph: &LinkStageOutput) -> Vec<Com
   get_export_items(chunk).into_iter().map(|(exported_name, _)| exported_name).collect::<Vec<_>>()
 }
 
+/// Pairs of the original name and the output name of each export of `chunk`, sorted by the output
+/// name. Exports of the entry module keep their names, while other exports might be renamed to
+/// avoid conflicts or by `minifyInternalExports`. Those are mapped back to the name exported by the
+/// module declaring the symbol, or to the name of the symbol if that module doesn't export it.
+pub fn get_chunk_export_map(
+  chunk: &Chunk,
+  graph: &LinkStageOutput,
+) -> Vec<(CompactStr, CompactStr)> {
+  if let ChunkKind::EntryPoint { module: entry_id, .. } = &chunk.kind {
+    let entry_meta = &graph.metas[*entry_id];
+    if matches!(entry_meta.wrap_kind(), WrapKind::Cjs) {
+      return vec![(CompactStr::new("default"), CompactStr::new("default"))];
+    }
+  }
+
+  let entry_meta = chunk.entry_module_idx().map(|entry_id| &graph.metas[entry_id]);
+  get_export_items(chunk)
+    .into_iter()
+    .map(|(exported_name, export_ref)| {
+      let is_entry_export = entry_meta.is_some_and(|meta| {
+        meta
+          .resolved_exports
+          .get(&exported_name)
+          .is_some_and(|export| graph.symbol_db.canonical_ref_for(export.symbol_ref) == export_ref)
+      });
+      if is_entry_export {
+        return (exported_name.clone(), exported_name);
+      }
+      let original_name = graph.metas[export_ref.owner]
+        .canonical_exports(false)
+        .find(|(_, export)| graph.symbol_db.canonical_ref_for(export.symbol_ref) == export_ref)
+        .map_or_else(
+          || CompactStr::new(export_ref.name(&graph.symbol_db)),
+          |(name, _)| name.clone(),
+        );
+      (original_name, exported_name)
+    })
+    .collect()
+}
+
 pub fn get_chunk_export_names_with_ctx(ctx: &GenerateContext<'_>) -> Vec<CompactStr> {
   let GenerateContext { chunk, link_output, render_export_items_index_vec, .. } = ctx;
   if let ChunkKind::EntryPoint { module: entry_id, .. } = &chunk.kind {
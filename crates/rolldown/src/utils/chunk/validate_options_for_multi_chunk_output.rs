@@ -9,6 +9,11 @@ pub fn validate_options_for_multi_chunk_output(
       options.format.to_string(),
     )))?;
   }
+  if options.inline_dynamic_imports {
+    Err(BuildDiagnostic::invalid_option(
+      InvalidOptionType::InlineDynamicImportsWithMultipleChunks,
+    ))?;
+  }
   if options.file.is_some() {
     Err(BuildDiagnostic::invalid_option(InvalidOptionType::InvalidOutputFile))?;
   }
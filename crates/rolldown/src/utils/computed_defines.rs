@@ -0,0 +1,82 @@
+use oxc::allocator::Allocator;
+use oxc::ast::ast::Expression;
+use oxc::ast_visit::{VisitMut, walk_mut};
+use oxc::diagnostics::OxcDiagnostic;
+use oxc::parser::Parser;
+use oxc::semantic::Scoping;
+use oxc::span::{CompactStr, SourceType};
+use rolldown_common::DefineResolver;
+use rustc_hash::FxHashMap;
+
+/// Replaces global identifiers with the expressions computed by a [`DefineResolver`], e.g.
+/// `__BUILD_TIME__` with the current timestamp.
+///
+/// Runs after the static `define` replacement, so only the identifiers it left alone are passed
+/// to the resolver. Each name is resolved once per module.
+pub struct ComputedDefines<'me, 'a> {
+  allocator: &'a Allocator,
+  scoping: &'me Scoping,
+  resolver: &'me DefineResolver,
+  resolved: FxHashMap<&'a str, Option<&'a str>>,
+  /// Replacements that aren't valid expressions, with the name of the replaced identifier.
+  pub errors: Vec<(CompactStr, Vec<OxcDiagnostic>)>,
+  /// Whether any identifier is replaced.
+  pub changed: bool,
+}
+
+impl<'me, 'a> ComputedDefines<'me, 'a> {
+  pub fn new(
+    allocator: &'a Allocator,
+    scoping: &'me Scoping,
+    resolver: &'me DefineResolver,
+  ) -> Self {
+    Self {
+      allocator,
+      scoping,
+      resolver,
+      resolved: FxHashMap::default(),
+      errors: vec![],
+      changed: false,
+    }
+  }
+
+  fn resolve(&mut self, name: &'a str) -> Option<&'a str> {
+    if let Some(resolved) = self.resolved.get(name) {
+      return *resolved;
+    }
+    let resolved = self.resolver.call(name).map(|code| self.allocator.alloc_str(&code) as &str);
+    self.resolved.insert(name, resolved);
+    resolved
+  }
+}
+
+impl<'a> VisitMut<'a> for ComputedDefines<'_, 'a> {
+  fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+    let Expression::Identifier(ident) = expr else {
+      walk_mut::walk_expression(self, expr);
+      return;
+    };
+    let is_global = ident
+      .reference_id
+      .get()
+      .is_some_and(|reference_id| self.scoping.get_reference(reference_id).symbol_id().is_none());
+    if !is_global {
+      return;
+    }
+    let name = ident.name.as_str();
+    let Some(code) = self.resolve(name) else {
+      return;
+    };
+    match Parser::new(self.allocator, code, SourceType::default()).parse_expression() {
+      Ok(replacement) => {
+        *expr = replacement;
+        self.changed = true;
+      }
+      Err(errors) => {
+        // Only report the first failure of each name.
+        self.resolved.insert(name, None);
+        self.errors.push((name.into(), errors));
+      }
+    }
+  }
+}
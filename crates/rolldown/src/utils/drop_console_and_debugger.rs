@@ -0,0 +1,91 @@
+use oxc::allocator::{Allocator, Vec as ArenaVec};
+use oxc::ast::AstBuilder;
+use oxc::ast::ast::{Expression, IdentifierReference, Statement};
+use oxc::ast_visit::{VisitMut, walk_mut};
+use oxc::semantic::Scoping;
+use oxc::span::GetSpan;
+
+/// Removes standalone `console.*(...)` calls and `debugger` statements for the `dropConsole` and
+/// `dropDebugger` options.
+///
+/// Only expression statements are removed, so the value of `const y = console.log(x)` is kept.
+/// `console` must be the global one, calls through an alias like `const c = console; c.log()`
+/// are left alone.
+pub struct DropConsoleAndDebugger<'me, 'a> {
+  builder: AstBuilder<'a>,
+  scoping: &'me Scoping,
+  drop_console: bool,
+  drop_debugger: bool,
+  /// Whether any statement is removed.
+  pub changed: bool,
+}
+
+impl<'me, 'a> DropConsoleAndDebugger<'me, 'a> {
+  pub fn new(
+    allocator: &'a Allocator,
+    scoping: &'me Scoping,
+    drop_console: bool,
+    drop_debugger: bool,
+  ) -> Self {
+    Self {
+      builder: AstBuilder::new(allocator),
+      scoping,
+      drop_console,
+      drop_debugger,
+      changed: false,
+    }
+  }
+
+  fn should_drop(&self, stmt: &Statement<'a>) -> bool {
+    match stmt {
+      Statement::DebuggerStatement(_) => self.drop_debugger,
+      Statement::ExpressionStatement(expr_stmt) => {
+        self.drop_console && self.is_console_call(&expr_stmt.expression)
+      }
+      _ => false,
+    }
+  }
+
+  /// `console.log(...)`, `console["log"](...)` or `console.log.call(...)`
+  fn is_console_call(&self, expr: &Expression<'a>) -> bool {
+    let Expression::CallExpression(call) = expr.without_parentheses() else {
+      return false;
+    };
+    let Some(mut object) = call.callee.as_member_expression().map(|member| member.object()) else {
+      return false;
+    };
+    while let Some(member) = object.as_member_expression() {
+      object = member.object();
+    }
+    let Expression::Identifier(ident) = object else {
+      return false;
+    };
+    ident.name == "console" && self.is_global(ident)
+  }
+
+  fn is_global(&self, ident: &IdentifierReference<'a>) -> bool {
+    ident
+      .reference_id
+      .get()
+      .is_some_and(|reference_id| self.scoping.get_reference(reference_id).symbol_id().is_none())
+  }
+}
+
+impl<'a> VisitMut<'a> for DropConsoleAndDebugger<'_, 'a> {
+  fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+    let len = stmts.len();
+    stmts.retain(|stmt| !self.should_drop(stmt));
+    self.changed |= stmts.len() != len;
+    walk_mut::walk_statements(self, stmts);
+  }
+
+  fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+    // Statements that aren't in a list, e.g. the body of `if (a) console.log(a)`.
+    if self.should_drop(stmt) {
+      *stmt = self.builder.statement_empty(stmt.span());
+      self.changed = true;
+      return;
+    }
+    walk_mut::walk_statement(self, stmt);
+  }
+}
@@ -1,6 +1,8 @@
 pub mod apply_inner_plugins;
 pub mod augment_chunk_hash;
 pub mod chunk;
+pub mod computed_defines;
+pub mod drop_console_and_debugger;
 pub mod load_entry_module;
 pub mod load_source;
 pub mod normalize_transform_options;
@@ -10,9 +12,12 @@ pub mod prepare_build_context;
 pub mod process_code_and_sourcemap;
 pub mod renamer;
 pub mod render_chunks;
+pub mod remove_dead_branches;
 pub mod render_ecma_module;
 pub mod resolve_id;
 pub mod text_to_esm;
 pub mod transform_source;
+pub mod trim_enum_reverse_mappings;
 pub mod tweak_ast_for_scanning;
 pub mod uuid;
+pub mod validate_define_keys;
@@ -8,7 +8,7 @@ use rolldown_plugin::HookTransformAstArgs;
 use rolldown_utils::mime::guess_mime;
 use sugar_path::SugarPath;
 
-use super::pre_process_ecma_ast::PreProcessEcmaAst;
+use super::pre_process_ecma_ast::{PreProcessEcmaAst, PreProcessTimings};
 
 use crate::{
   types::{module_factory::CreateModuleContext, oxc_parse_type::OxcParseType},
@@ -16,7 +16,7 @@ use crate::{
 };
 
 #[inline]
-fn pure_esm_js_oxc_source_type() -> OxcSourceType {
+pub(crate) fn pure_esm_js_oxc_source_type() -> OxcSourceType {
   let pure_esm_js = OxcSourceType::default().with_module(true);
   debug_assert!(pure_esm_js.is_javascript());
   debug_assert!(!pure_esm_js.is_jsx());
@@ -31,6 +31,8 @@ pub struct ParseToEcmaAstResult {
   pub scoping: Scoping,
   pub has_lazy_export: bool,
   pub warnings: Vec<BuildDiagnostic>,
+  /// Only collected by [`PreProcessEcmaAst::with_timings`]
+  pub pre_process_timings: Option<PreProcessTimings>,
 }
 
 pub async fn parse_to_ecma_ast(
@@ -62,6 +64,7 @@ pub async fn parse_to_ecma_ast(
       OxcParseType::Tsx => {
         default.with_typescript(true).with_jsx(!options.transform_options.is_jsx_disabled())
       }
+      OxcParseType::Dts => default.with_typescript_definition(true),
     }
   };
 
@@ -83,7 +86,14 @@ pub async fn parse_to_ecma_ast(
     })
     .await?;
 
-  PreProcessEcmaAst::default().build(
+  // Per-phase timings are only collected when debug tracing is on, so slow modules can be
+  // spotted with `RD_LOG=debug` without paying for it in normal builds.
+  let mut pre_process = if tracing::enabled!(tracing::Level::DEBUG) {
+    PreProcessEcmaAst::with_timings()
+  } else {
+    PreProcessEcmaAst::default()
+  };
+  pre_process.build(
     ecma_ast,
     stable_id,
     &parsed_type,
@@ -159,5 +169,19 @@ fn pre_process_source(
     }
   };
 
-  Ok((has_lazy_export, source, module_type.into()))
+  let parsed_type = if matches!(module_type, ModuleType::Ts) && is_declaration_file(path) {
+    OxcParseType::Dts
+  } else {
+    module_type.into()
+  };
+
+  Ok((has_lazy_export, source, parsed_type))
+}
+
+/// `.d.ts`, `.d.mts` and `.d.cts` files.
+fn is_declaration_file(path: &Path) -> bool {
+  path
+    .file_name()
+    .and_then(|name| name.to_str())
+    .is_some_and(|name| [".d.ts", ".d.mts", ".d.cts"].iter().any(|suffix| name.ends_with(suffix)))
 }
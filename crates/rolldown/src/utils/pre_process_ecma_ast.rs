@@ -1,24 +1,66 @@
 use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
+use arcstr::ArcStr;
 use itertools::Itertools;
-use oxc::ast_visit::VisitMut;
+use oxc::allocator::{Allocator, CloneIn};
+use oxc::ast::ast::{Program, Statement, TSEnumDeclaration};
+use oxc::ast_visit::{Visit, VisitMut, walk};
 use oxc::diagnostics::Severity as OxcSeverity;
-use oxc::minifier::{CompressOptions, Compressor, TreeShakeOptions};
+use oxc::minifier::{CompressOptions, Compressor, MinifierOptions, TreeShakeOptions};
 use oxc::semantic::{SemanticBuilder, Stats};
+use oxc::span::{GetSpan, Span};
 use oxc::transformer::Transformer;
 use oxc::transformer_plugins::{
   InjectGlobalVariables, ReplaceGlobalDefines, ReplaceGlobalDefinesConfig,
 };
 
-use rolldown_common::NormalizedBundlerOptions;
+use rolldown_common::{MinifyOptions, NormalizedBundlerOptions};
 use rolldown_ecmascript::{EcmaAst, WithMutFields};
-use rolldown_error::{BuildDiagnostic, BuildResult, Severity};
+use rolldown_error::{BatchedBuildDiagnostic, BuildDiagnostic, BuildResult, Severity};
 
 use crate::types::oxc_parse_type::OxcParseType;
 
+use super::computed_defines::ComputedDefines;
+use super::drop_console_and_debugger::DropConsoleAndDebugger;
 use super::parse_to_ecma_ast::ParseToEcmaAstResult;
+use super::remove_dead_branches::RemoveDeadBranches;
+use super::trim_enum_reverse_mappings::trim_enum_reverse_mappings;
 use super::tweak_ast_for_scanning::PreProcessor;
 
+/// Passes run by [`PreProcessEcmaAst::build`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreProcessPhase {
+  Semantic,
+  Define,
+  Transform,
+  TrimEnumReverseMappings,
+  Inject,
+  DropConsoleAndDebugger,
+  Compress,
+  CustomPasses,
+  TweakAstForScanning,
+  RebuildSemantic,
+}
+
+/// A custom pass, added by [`PreProcessEcmaAst::with_passes`] and applied by
+/// [`PreProcessEcmaAst::build`] after the built-in transforms (define, inject, compress, ...) and
+/// right before the AST is tweaked for scanning.
+///
+/// `oxc`'s `VisitMut` can't be used as a trait object, so implementors usually create their
+/// `VisitMut` in `run` and visit the program with it.
+pub trait PreProcessPass: Send + Sync {
+  fn run<'a>(&mut self, allocator: &'a Allocator, program: &mut Program<'a>);
+}
+
+/// Durations of the passes that actually ran for a module, in execution order.
+#[derive(Debug, Default)]
+pub struct PreProcessTimings {
+  pub phases: Vec<(PreProcessPhase, Duration)>,
+}
+
 #[derive(Default)]
 pub struct PreProcessEcmaAst {
   /// Only recreate semantic data if ast is changed.
@@ -26,9 +68,66 @@ pub struct PreProcessEcmaAst {
 
   /// Semantic statistics.
   stats: Stats,
+
+  /// Only collected when created by [`PreProcessEcmaAst::with_timings`], to avoid the overhead of
+  /// reading the clock for every pass by default.
+  timings: Option<PreProcessTimings>,
+
+  /// Set by the caller when the result isn't needed anymore, e.g. the watcher starts a new build.
+  /// Checked between the passes of [`PreProcessEcmaAst::build`].
+  cancellation: Option<Arc<AtomicBool>>,
+
+  /// Custom passes run right before the AST is tweaked for scanning.
+  passes: Vec<Box<dyn PreProcessPass>>,
 }
 
 impl PreProcessEcmaAst {
+  pub fn with_timings() -> Self {
+    Self { timings: Some(PreProcessTimings::default()), ..Default::default() }
+  }
+
+  /// Stop [`PreProcessEcmaAst::build`] between two passes once `token` is set. The build then
+  /// returns a `CANCELLED` error and drops the AST, so nothing is left half transformed.
+  #[must_use]
+  pub fn with_cancellation(mut self, token: Arc<AtomicBool>) -> Self {
+    self.cancellation = Some(token);
+    self
+  }
+
+  /// Run `passes` after the built-in transforms, in order. The semantic data is recreated
+  /// afterward, so the scanner sees scopes and references matching the changed AST.
+  #[must_use]
+  pub fn with_passes(mut self, passes: Vec<Box<dyn PreProcessPass>>) -> Self {
+    self.passes = passes;
+    self
+  }
+
+  /// The timings are returned with the result of a successful [`PreProcessEcmaAst::build`], this
+  /// is how to get them after a failed one.
+  pub fn take_timings(&mut self) -> Option<PreProcessTimings> {
+    self.timings.take()
+  }
+
+  #[inline]
+  fn check_cancelled(&self) -> BuildResult<()> {
+    if self.cancellation.as_ref().is_some_and(|token| token.load(Ordering::Relaxed)) {
+      Err(BuildDiagnostic::cancelled())?;
+    }
+    Ok(())
+  }
+
+  #[inline]
+  fn start_phase(&self) -> Option<Instant> {
+    self.timings.is_some().then(Instant::now)
+  }
+
+  #[inline]
+  fn end_phase(&mut self, phase: PreProcessPhase, start: Option<Instant>) {
+    if let (Some(timings), Some(start)) = (self.timings.as_mut(), start) {
+      timings.phases.push((phase, start.elapsed()));
+    }
+  }
+
   pub fn build(
     &mut self,
     mut ast: EcmaAst,
@@ -39,6 +138,21 @@ impl PreProcessEcmaAst {
     has_lazy_export: bool,
   ) -> BuildResult<ParseToEcmaAstResult> {
     let source = ast.source().clone();
+    // Declaration files have no runtime code. Only their side-effect imports, e.g.
+    // `import './polyfill'`, are kept so the imported modules are still included.
+    if matches!(parsed_type, OxcParseType::Dts) {
+      ast.program.with_mut(|WithMutFields { program, .. }| {
+        program.body.retain(|stmt| {
+          matches!(
+            stmt,
+            Statement::ImportDeclaration(decl)
+              if decl.specifiers.is_none() && decl.import_kind.is_value()
+          )
+        });
+      });
+    }
+    self.check_cancelled()?;
+    let start = self.start_phase();
     // Build initial semantic data and check for semantic errors.
     let semantic_ret = ast.program.with_mut(|WithMutFields { program, .. }| {
       SemanticBuilder::new().with_check_syntax_error(true).build(program)
@@ -46,32 +160,109 @@ impl PreProcessEcmaAst {
 
     let (errors, warnings): (Vec<_>, Vec<_>) =
       semantic_ret.errors.into_iter().partition(|w| w.severity == OxcSeverity::Error);
-    let warnings = if errors.is_empty() {
+    let mut warnings = if errors.is_empty() {
       BuildDiagnostic::from_oxc_diagnostics(warnings, &source, path, &Severity::Warning)
+    } else if bundle_options.experimental.is_semantic_errors_as_warnings_enabled() {
+      BuildDiagnostic::from_oxc_diagnostics(
+        errors.into_iter().chain(warnings),
+        &source,
+        path,
+        &Severity::Warning,
+      )
     } else {
       return Err(BuildDiagnostic::from_oxc_diagnostics(errors, &source, path, &Severity::Error))?;
     };
 
+    // Check before the TypeScript transform, which turns const enums into regular enums.
+    if bundle_options.isolated_modules && !matches!(parsed_type, OxcParseType::Js) {
+      let mut collector = ConstEnumCollector::default();
+      collector.visit_program(ast.program());
+      if !collector.const_enums.is_empty() {
+        let filename = ArcStr::from(path);
+        Err(
+          collector
+            .const_enums
+            .into_iter()
+            .map(|(name, span)| {
+              BuildDiagnostic::const_enum_in_isolated_modules(
+                filename.clone(),
+                source.clone(),
+                name,
+                span,
+              )
+            })
+            .collect_vec(),
+        )?;
+      }
+    }
+
+    // Check before the TypeScript transform, which turns `export =` into `module.exports =`.
+    if matches!(parsed_type, OxcParseType::Ts | OxcParseType::Tsx)
+      && let Some((export_assignment_span, export_span)) =
+        find_export_assignment_with_exports(ast.program())
+    {
+      Err(BuildDiagnostic::export_assignment_with_exports(
+        ArcStr::from(path),
+        source.clone(),
+        export_assignment_span,
+        export_span,
+      ))?;
+    }
+
     self.stats = semantic_ret.semantic.stats();
-    let scoping = semantic_ret.semantic.into_scoping();
+    let mut scoping = semantic_ret.semantic.into_scoping();
+    self.end_phase(PreProcessPhase::Semantic, start);
 
-    let mut scoping = ast.program.with_mut(|fields| {
-      let WithMutFields { allocator, program, .. } = fields;
-      // Use built-in define plugin.
-      if let Some(replace_global_define_config) = replace_global_define_config {
-        let ret = ReplaceGlobalDefines::new(allocator, replace_global_define_config.clone())
-          .build(scoping, program);
-        self.ast_changed = true;
-        ret.scoping
-      } else {
-        scoping
+    // Use built-in define plugin.
+    self.check_cancelled()?;
+    if let Some(replace_global_define_config) = replace_global_define_config {
+      let start = self.start_phase();
+      scoping = ast.program.with_mut(|fields| {
+        let WithMutFields { allocator, program, .. } = fields;
+        ReplaceGlobalDefines::new(allocator, replace_global_define_config.clone())
+          .build(scoping, program)
+          .scoping
+      });
+      self.ast_changed = true;
+      self.end_phase(PreProcessPhase::Define, start);
+    }
+    // Identifiers left by the static config are passed to the define resolver.
+    if let Some(define_resolver) = &bundle_options.define_resolver {
+      let start = self.start_phase();
+      let errors = ast.program.with_mut(|WithMutFields { allocator, program, .. }| {
+        let mut computed_defines = ComputedDefines::new(allocator, &scoping, define_resolver);
+        computed_defines.visit_program(program);
+        let ComputedDefines { changed, errors, .. } = computed_defines;
+        if changed {
+          // The replacements don't have references yet.
+          scoping =
+            SemanticBuilder::new().with_stats(self.stats).build(program).semantic.into_scoping();
+          self.ast_changed = true;
+        }
+        errors
+      });
+      if !errors.is_empty() {
+        Err(
+          errors
+            .into_iter()
+            .map(|(name, errors)| {
+              let reasons = errors.iter().map(|err| err.message.to_string()).join(", ");
+              BuildDiagnostic::invalid_define_config(format!(
+                "The computed replacement of `{name}` is not a valid expression: {reasons}"
+              ))
+            })
+            .collect_vec(),
+        )?;
       }
-    });
+      self.end_phase(PreProcessPhase::Define, start);
+    }
     // Transform TypeScript and jsx.
+    self.check_cancelled()?;
     // Note: Currently, oxc_transform supports es syntax up to ES2024 (unicode-sets-regex).
     if !matches!(parsed_type, OxcParseType::Js)
       || bundle_options.transform_options.env.regexp.set_notation
     {
+      let start = self.start_phase();
       let ret = ast.program.with_mut(|fields| {
         let transform_options = &bundle_options.transform_options;
 
@@ -79,25 +270,40 @@ impl PreProcessEcmaAst {
           .build_with_scoping(scoping, fields.program)
       });
 
-      // TODO: emit diagnostic, aiming to pass more tests,
-      // we ignore warning for now
-      let errors = ret
-        .errors
-        .into_iter()
-        .filter(|item| matches!(item.severity, OxcSeverity::Error))
-        .collect_vec();
+      let (errors, transform_warnings): (Vec<_>, Vec<_>) =
+        ret.errors.into_iter().partition(|item| matches!(item.severity, OxcSeverity::Error));
       if !errors.is_empty() {
         Err(BuildDiagnostic::from_oxc_diagnostics(errors, &source, path, &Severity::Error))?;
       }
+      warnings.extend(BuildDiagnostic::from_oxc_diagnostics(
+        transform_warnings,
+        &source,
+        path,
+        &Severity::Warning,
+      ));
 
       scoping = ret.scoping;
       self.ast_changed = true;
+      self.end_phase(PreProcessPhase::Transform, start);
+    }
+
+    // Enums only exist in TypeScript, the reverse mappings are generated by the transform above.
+    if bundle_options.optimization.is_trim_enum_reverse_mappings_enabled()
+      && matches!(parsed_type, OxcParseType::Ts | OxcParseType::Tsx)
+    {
+      let start = self.start_phase();
+      ast.program.with_mut(|fields| {
+        trim_enum_reverse_mappings(fields.allocator, fields.program, &scoping);
+      });
+      self.end_phase(PreProcessPhase::TrimEnumReverseMappings, start);
     }
 
+    self.check_cancelled()?;
     ast.program.with_mut(|fields| {
       let WithMutFields { allocator, program, .. } = fields;
 
       if !bundle_options.inject.is_empty() {
+        let start = self.start_phase();
         // if the define replace something, we need to recreate the semantic data.
         // to correct the `root_unresolved_references`
         // https://github.com/oxc-project/oxc/blob/0136431b31a1d4cc20147eb085d9314b224cc092/crates/oxc_transformer/src/plugins/inject_global_variables.rs#L184-L184
@@ -111,31 +317,93 @@ impl PreProcessEcmaAst {
         .build(scoping, program);
         scoping = ret.scoping;
         self.ast_changed = true;
+        self.end_phase(PreProcessPhase::Inject, start);
+      }
+
+      if bundle_options.drop_console || bundle_options.drop_debugger {
+        let start = self.start_phase();
+        let mut dropper = DropConsoleAndDebugger::new(
+          allocator,
+          &scoping,
+          bundle_options.drop_console,
+          bundle_options.drop_debugger,
+        );
+        dropper.visit_program(program);
+        self.ast_changed |= dropper.changed;
+        self.end_phase(PreProcessPhase::DropConsoleAndDebugger, start);
       }
 
+      self.check_cancelled()?;
       // avoid DCE for lazy export
       if bundle_options.treeshake.is_some() && !has_lazy_export {
-        // Perform dead code elimination.
-        // NOTE: `CompressOptions::dead_code_elimination` will remove `ParenthesizedExpression`s from the AST.
-        let options = CompressOptions {
-          treeshake: TreeShakeOptions::from(&bundle_options.treeshake),
-          ..CompressOptions::dce()
-        };
+        let start = self.start_phase();
         let compressor = Compressor::new(allocator);
         if self.ast_changed {
           let semantic_ret = SemanticBuilder::new().with_stats(self.stats).build(program);
           scoping = semantic_ret.semantic.into_scoping();
         }
-        compressor.dead_code_elimination_with_scoping(program, scoping, options);
+        // The compressor drops `"use strict"` from modules. Keep it, it's needed to render the
+        // chunk level `"use strict"` for script outputs.
+        let use_strict = program
+          .directives
+          .iter()
+          .find(|directive| directive.is_use_strict())
+          .map(|directive| directive.clone_in(allocator));
+        let treeshake = TreeShakeOptions::from(&bundle_options.treeshake);
+        match &bundle_options.minify {
+          // Run the compress passes configured by `minify`, e.g. constant folding or dropping `console`.
+          MinifyOptions::Enabled((MinifierOptions { compress: Some(compress), .. }, _)) => {
+            let options = CompressOptions { treeshake, ..compress.clone() };
+            compressor.build_with_scoping(program, scoping, options);
+            // The compressor doesn't report whether it changed anything, assume it did.
+            self.ast_changed = true;
+          }
+          // Keep `ParenthesizedExpression`s, which are removed by the dead code elimination below.
+          // It doesn't need to report changes, the semantic data is always recreated before
+          // scanning.
+          _ if bundle_options.experimental.is_preserve_parens_enabled() => {
+            RemoveDeadBranches::new(allocator, &scoping).visit_program(program);
+          }
+          // Perform dead code elimination only, which is always safe to do before scanning.
+          // NOTE: `CompressOptions::dead_code_elimination` will remove `ParenthesizedExpression`s from the AST.
+          _ => {
+            let options = CompressOptions { treeshake, ..CompressOptions::dce() };
+            compressor.dead_code_elimination_with_scoping(program, scoping, options);
+          }
+        }
+        if let Some(use_strict) = use_strict
+          && !program.has_use_strict_directive()
+        {
+          program.directives.insert(0, use_strict);
+        }
+        self.end_phase(PreProcessPhase::Compress, start);
       }
-    });
+      Ok::<(), BatchedBuildDiagnostic>(())
+    })?;
 
+    if !self.passes.is_empty() {
+      let start = self.start_phase();
+      let passes = &mut self.passes;
+      ast.program.with_mut(|fields| {
+        for pass in passes.iter_mut() {
+          pass.run(fields.allocator, fields.program);
+        }
+      });
+      // Scopes and references are recreated below, before the AST reaches the scanner.
+      self.ast_changed = true;
+      self.end_phase(PreProcessPhase::CustomPasses, start);
+    }
+
+    let start = self.start_phase();
     ast.program.with_mut(|fields| {
       let mut pre_processor = PreProcessor::new(fields.allocator, bundle_options.keep_names);
       pre_processor.visit_program(fields.program);
     });
+    self.end_phase(PreProcessPhase::TweakAstForScanning, start);
 
     // NOTE: Recreate semantic data because AST is changed in the transformations above.
+    self.check_cancelled()?;
+    let start = self.start_phase();
     let scoping = ast.program.with_dependent(|_owner, dep| {
       SemanticBuilder::new()
         // Required by `module.scope.get_child_ids` in `crates/rolldown/src/utils/renamer.rs`.
@@ -146,7 +414,612 @@ impl PreProcessEcmaAst {
         .semantic
         .into_scoping()
     });
+    self.end_phase(PreProcessPhase::RebuildSemantic, start);
+
+    Ok(ParseToEcmaAstResult {
+      ast,
+      scoping,
+      has_lazy_export,
+      warnings,
+      pre_process_timings: self.take_timings(),
+    })
+  }
+}
+
+/// Collects non-ambient `const enum` declarations, which can't be used under `isolatedModules`.
+#[derive(Default)]
+struct ConstEnumCollector {
+  const_enums: Vec<(ArcStr, Span)>,
+}
+
+impl<'a> Visit<'a> for ConstEnumCollector {
+  fn visit_ts_enum_declaration(&mut self, decl: &TSEnumDeclaration<'a>) {
+    if decl.r#const && !decl.declare {
+      self.const_enums.push((ArcStr::from(decl.id.name.as_str()), decl.id.span));
+    }
+    walk::walk_ts_enum_declaration(self, decl);
+  }
+}
 
-    Ok(ParseToEcmaAstResult { ast, scoping, has_lazy_export, warnings })
+/// Finds `export = ...` mixed with another export, e.g. `export = Foo; export const x = 1`, which
+/// TypeScript rejects. Type-only exports, including `export { type T }`, are erased by the
+/// transform, so they are allowed.
+fn find_export_assignment_with_exports(program: &Program) -> Option<(Span, Span)> {
+  let export_assignment_span = program.body.iter().find_map(|stmt| match stmt {
+    Statement::TSExportAssignment(assignment) => Some(assignment.span),
+    _ => None,
+  })?;
+  let export_span = program.body.iter().find_map(|stmt| match stmt {
+    Statement::ExportNamedDeclaration(decl)
+      if decl.declaration.is_none()
+        && !decl.specifiers.is_empty()
+        && decl.specifiers.iter().all(|specifier| specifier.export_kind.is_type()) =>
+    {
+      None
+    }
+    Statement::ExportNamedDeclaration(_)
+    | Statement::ExportDefaultDeclaration(_)
+    | Statement::ExportAllDeclaration(_)
+      if !stmt.is_typescript_syntax() =>
+    {
+      Some(stmt.span())
+    }
+    _ => None,
+  })?;
+  Some((export_assignment_span, export_span))
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::Arc;
+  use std::sync::atomic::{AtomicBool, Ordering};
+
+  use oxc::allocator::Allocator;
+  use oxc::ast::ast::{Expression, IdentifierReference, Program, Statement};
+  use oxc::ast_visit::VisitMut;
+  use oxc::minifier::{CompressOptions, MinifierOptions};
+  use oxc::span::{Atom, SourceType};
+  use oxc::transformer_plugins::{
+    InjectGlobalVariablesConfig, InjectImport as OxcInjectImport, ReplaceGlobalDefinesConfig,
+  };
+  use rolldown_common::{
+    DefineResolver, ExperimentalOptions, InjectImport, MinifyOptions, NormalizedBundlerOptions,
+    OptimizationOption, Platform, TreeshakeOptions, normalize_optimization_option,
+  };
+  use rolldown_ecmascript::{EcmaCompiler, PrintOptions};
+  use rolldown_error::{EventKind, Severity};
+
+  use crate::types::oxc_parse_type::OxcParseType;
+  use crate::utils::parse_to_ecma_ast::pure_esm_js_oxc_source_type;
+
+  use super::{PreProcessEcmaAst, PreProcessPass, PreProcessPhase};
+
+  #[test]
+  fn collect_timings_of_every_phase() {
+    let options = NormalizedBundlerOptions {
+      inject: vec![InjectImport::default("$".to_string(), "jquery".to_string())],
+      oxc_inject_global_variables_config: InjectGlobalVariablesConfig::new(vec![
+        OxcInjectImport::named_specifier("jquery", Some("default"), "$"),
+      ]),
+      treeshake: TreeshakeOptions::Boolean(true).into_normalized_options(),
+      ..Default::default()
+    };
+    let define_config =
+      ReplaceGlobalDefinesConfig::new(&[("DEBUG".to_string(), "false".to_string())]).unwrap();
+    let ast = EcmaCompiler::parse(
+      "main.ts",
+      "const a: number = DEBUG ? 1 : 2; console.log($(a));",
+      SourceType::ts(),
+    )
+    .unwrap();
+
+    let timings = PreProcessEcmaAst::with_timings()
+      .build(ast, "main.ts", &OxcParseType::Ts, Some(&define_config), &options, false)
+      .unwrap()
+      .pre_process_timings
+      .expect("timings should be collected");
+
+    let phases = timings.phases.iter().map(|(phase, _)| *phase).collect::<Vec<_>>();
+    assert_eq!(
+      phases,
+      [
+        PreProcessPhase::Semantic,
+        PreProcessPhase::Define,
+        PreProcessPhase::Transform,
+        PreProcessPhase::Inject,
+        PreProcessPhase::Compress,
+        PreProcessPhase::TweakAstForScanning,
+        PreProcessPhase::RebuildSemantic,
+      ]
+    );
+  }
+
+  #[test]
+  fn no_timings_by_default() {
+    let options = NormalizedBundlerOptions::default();
+    let ast = EcmaCompiler::parse("main.js", "console.log(1)", SourceType::mjs()).unwrap();
+    let result = PreProcessEcmaAst::default()
+      .build(ast, "main.js", &OxcParseType::Js, None, &options, false)
+      .unwrap();
+    assert!(result.pre_process_timings.is_none());
+  }
+
+  struct RenameGlobal {
+    from: &'static str,
+    to: &'static str,
+  }
+
+  impl PreProcessPass for RenameGlobal {
+    fn run<'a>(&mut self, _allocator: &'a Allocator, program: &mut Program<'a>) {
+      struct Renamer<'me> {
+        from: &'me str,
+        to: &'static str,
+      }
+      impl<'a> VisitMut<'a> for Renamer<'_> {
+        fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+          if ident.name == self.from {
+            ident.name = Atom::from(self.to);
+          }
+        }
+      }
+      Renamer { from: self.from, to: self.to }.visit_program(program);
+    }
+  }
+
+  #[test]
+  fn custom_passes_are_reflected_in_scoping() {
+    let options = NormalizedBundlerOptions::default();
+    let ast = EcmaCompiler::parse("main.js", "console.log(foo)", SourceType::mjs()).unwrap();
+    let passes: Vec<Box<dyn PreProcessPass>> =
+      vec![Box::new(RenameGlobal { from: "foo", to: "bar" })];
+
+    let mut pre_process = PreProcessEcmaAst::default().with_passes(passes);
+    let ret = pre_process.build(ast, "main.js", &OxcParseType::Js, None, &options, false).unwrap();
+
+    let unresolved = ret.scoping.root_unresolved_references();
+    assert!(unresolved.contains_key("bar"));
+    assert!(!unresolved.contains_key("foo"));
+  }
+
+  #[test]
+  fn const_enum_under_isolated_modules() {
+    let code = "export const enum Direction { Up, Down }\ndeclare const enum Ambient { A }";
+    let build = |isolated_modules: bool| {
+      let options = NormalizedBundlerOptions { isolated_modules, ..Default::default() };
+      let ast = EcmaCompiler::parse("main.ts", code, SourceType::ts()).unwrap();
+      PreProcessEcmaAst::default().build(ast, "main.ts", &OxcParseType::Ts, None, &options, false)
+    };
+
+    assert!(build(false).is_ok());
+
+    let Err(errors) = build(true) else { panic!("const enum should be rejected") };
+    let errors = errors.into_vec();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind().to_string(), "CONST_ENUM_IN_ISOLATED_MODULES");
+  }
+
+  #[test]
+  fn export_assignment_mixed_with_other_exports() {
+    let build = |code: &str| {
+      let options = NormalizedBundlerOptions::default();
+      let ast = EcmaCompiler::parse("main.ts", code, SourceType::ts()).unwrap();
+      PreProcessEcmaAst::default().build(ast, "main.ts", &OxcParseType::Ts, None, &options, false)
+    };
+
+    for code in [
+      "class Foo {}\nexport = Foo;\nexport const x = 1;",
+      "export function f() {}\nexport = f;",
+      "const Foo = 1;\nexport = Foo;\nexport { Foo as Bar };",
+      "const Foo = 1;\ntype T = string;\nexport = Foo;\nexport { type T, Foo as Bar };",
+    ] {
+      let Err(errors) = build(code) else { panic!("{code} should be rejected") };
+      let errors = errors.into_vec();
+      assert_eq!(errors.len(), 1, "{code}");
+      assert!(
+        errors[0]
+          .to_diagnostic()
+          .to_string()
+          .contains("An export assignment cannot be used in a module with other exported elements"),
+        "{code}"
+      );
+    }
+
+    for code in [
+      "class Foo {}\nexport = Foo;",
+      "class Foo {}\nexport = Foo;\nexport type T = string;\nexport interface I {}",
+      "class Foo {}\nexport = Foo;\ntype T = string;\nexport { type T };",
+      "export const x = 1;",
+    ] {
+      assert!(build(code).is_ok(), "{code}");
+    }
+  }
+
+  #[test]
+  fn sloppy_mode_syntax_is_rejected_in_every_module() {
+    // Every module is parsed as an ES module, `.cjs` files too, so sloppy mode only syntax is
+    // rejected before the scanner could tell ES modules and CommonJS modules apart.
+    let build = |code: &str| {
+      let options = NormalizedBundlerOptions::default();
+      EcmaCompiler::parse("main.cjs", code, pure_esm_js_oxc_source_type()).and_then(|ast| {
+        PreProcessEcmaAst::default()
+          .build(ast, "main.cjs", &OxcParseType::Js, None, &options, false)
+          .map(|_| ())
+      })
+    };
+
+    for code in
+      ["module.exports = 0777;", "module.exports = function (a, a) {};", "with (obj) { a; }"]
+    {
+      let Err(errors) = build(code) else { panic!("{code} should be rejected") };
+      let errors = errors.into_vec();
+      assert!(!errors.is_empty(), "{code}");
+      assert!(errors.iter().all(|error| matches!(error.kind(), EventKind::ParseError)), "{code}");
+    }
+    assert!(build("module.exports = 0o777;").is_ok());
+  }
+
+  #[test]
+  fn pure_annotations_survive_the_typescript_transform() {
+    let code = "const a = /*#__PURE__*/ create<Foo>();\nconst b = /*#__PURE__*/ new Thing<Foo>();";
+    let options = NormalizedBundlerOptions::default();
+    let ast = EcmaCompiler::parse("main.ts", code, SourceType::ts()).unwrap();
+    let ret = PreProcessEcmaAst::default()
+      .build(ast, "main.ts", &OxcParseType::Ts, None, &options, false)
+      .unwrap();
+
+    let inits = ret
+      .ast
+      .program()
+      .body
+      .iter()
+      .filter_map(|stmt| match stmt {
+        Statement::VariableDeclaration(decl) => decl.declarations[0].init.as_ref(),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(inits.len(), 2);
+    assert!(matches!(inits[0], Expression::CallExpression(call) if call.pure));
+    assert!(matches!(inits[1], Expression::NewExpression(new_expr) if new_expr.pure));
+  }
+
+  #[test]
+  fn trim_unused_enum_reverse_mappings() {
+    let build = |code: &str| {
+      let optimization =
+        OptimizationOption { trim_enum_reverse_mappings: Some(true), ..Default::default() };
+      let options = NormalizedBundlerOptions {
+        optimization: normalize_optimization_option(Some(optimization), Platform::Browser),
+        ..Default::default()
+      };
+      let ast = EcmaCompiler::parse("main.ts", code, SourceType::ts()).unwrap();
+      let ret = PreProcessEcmaAst::default()
+        .build(ast, "main.ts", &OxcParseType::Ts, None, &options, false)
+        .unwrap();
+      EcmaCompiler::print_with(&ret.ast, PrintOptions::default()).code
+    };
+
+    let code = build(
+      "enum Color { Red, Green = 'green', Blue = 2 }\nconsole.log(Color.Red, Color['Blue']);",
+    );
+    assert!(code.contains(r#"Color["Red"] = 0;"#), "{code}");
+    assert!(code.contains(r#"Color["Blue"] = 2;"#), "{code}");
+    assert!(!code.contains(r#"] = "Red""#), "{code}");
+
+    for code in [
+      "enum Color { Red }\nconsole.log(Color[0]);",
+      "enum Color { Red }\nconsole.log(Color['0']);",
+      "enum Color { Red }\nconsole.log(Color[value]);",
+      "enum Color { Red }\nconsole.log(Object.keys(Color));",
+      "enum Color { Red }\nenum Color { Green = 1 }\nconsole.log(Color[1]);",
+      "export enum Color { Red }",
+      "enum Color { Red }\nexport { Color };",
+    ] {
+      let output = build(code);
+      assert!(output.contains(r#"Color[Color["Red"] = 0] = "Red";"#), "{code}\n{output}");
+    }
+  }
+
+  #[test]
+  fn compress_with_minify_options() {
+    let build = |minify: MinifyOptions| {
+      let options = NormalizedBundlerOptions {
+        minify,
+        treeshake: TreeshakeOptions::Boolean(true).into_normalized_options(),
+        ..Default::default()
+      };
+      let code = "console.log(foo);\nbar();";
+      let ast = EcmaCompiler::parse("main.js", code, SourceType::mjs()).unwrap();
+      let ret = PreProcessEcmaAst::default()
+        .build(ast, "main.js", &OxcParseType::Js, None, &options, false)
+        .unwrap();
+      EcmaCompiler::print_with(&ret.ast, PrintOptions::default()).code
+    };
+    let compress = |compress: Option<CompressOptions>| {
+      MinifyOptions::Enabled((MinifierOptions { mangle: None, compress }, false))
+    };
+
+    let code =
+      build(compress(Some(CompressOptions { drop_console: true, ..CompressOptions::smallest() })));
+    assert!(!code.contains("console.log"), "{code}");
+    assert!(code.contains("bar()"), "{code}");
+
+    // Only dead code elimination if compressing isn't enabled by `minify`
+    for minify in [MinifyOptions::Disabled, MinifyOptions::DeadCodeEliminationOnly, compress(None)]
+    {
+      let code = build(minify);
+      assert!(code.contains("console.log(foo)"), "{code}");
+    }
+  }
+
+  #[test]
+  fn keep_use_strict_directive_after_compress() {
+    let options = NormalizedBundlerOptions {
+      treeshake: TreeshakeOptions::Boolean(true).into_normalized_options(),
+      ..Default::default()
+    };
+    let ast = EcmaCompiler::parse("main.js", "'use strict';\nfoo();", SourceType::mjs()).unwrap();
+    let ret = PreProcessEcmaAst::default()
+      .build(ast, "main.js", &OxcParseType::Js, None, &options, false)
+      .unwrap();
+    assert!(ret.ast.program().has_use_strict_directive());
+  }
+
+  #[test]
+  fn preserve_parens_when_tree_shaking() {
+    let build = |code: &str, preserve_parens: bool| {
+      let options = NormalizedBundlerOptions {
+        treeshake: TreeshakeOptions::Boolean(true).into_normalized_options(),
+        experimental: ExperimentalOptions {
+          preserve_parens: Some(preserve_parens),
+          ..Default::default()
+        },
+        ..Default::default()
+      };
+      let ast = EcmaCompiler::parse("main.js", code, SourceType::mjs()).unwrap();
+      let ret = PreProcessEcmaAst::default()
+        .build(ast, "main.js", &OxcParseType::Js, None, &options, false)
+        .unwrap();
+      EcmaCompiler::print_with(&ret.ast, PrintOptions::default()).code
+    };
+
+    let code = "(0, fn)();\nconsole.log((a));\nif ('a' === 'b') { dead(); } else { alive(); }";
+    let output = build(code, true);
+    assert!(output.contains("(0, fn)()"), "{output}");
+    assert!(output.contains("console.log((a))"), "{output}");
+    assert!(!output.contains("dead"), "{output}");
+    assert!(output.contains("alive()"), "{output}");
+    assert!(!output.contains("if"), "{output}");
+    assert!(build(code, false).contains("console.log(a)"));
+
+    for code in [
+      // The test isn't a constant
+      "if (a) { dead(); }",
+      "if (foo() === 'b') { dead(); }",
+      // The hoisted binding must stay
+      "if (false) { var dead; }",
+    ] {
+      assert!(build(code, true).contains("dead"), "{code}");
+    }
+    assert!(!build("if (false) { (() => { var dead; })(); }", true).contains("dead"));
+  }
+
+  #[test]
+  fn drop_console_and_debugger() {
+    let build = |code: &str, drop_console: bool, drop_debugger: bool| {
+      let options = NormalizedBundlerOptions { drop_console, drop_debugger, ..Default::default() };
+      let ast = EcmaCompiler::parse("main.js", code, SourceType::mjs()).unwrap();
+      let ret = PreProcessEcmaAst::default()
+        .build(ast, "main.js", &OxcParseType::Js, None, &options, false)
+        .unwrap();
+      EcmaCompiler::print_with(&ret.ast, PrintOptions::default()).code
+    };
+
+    let code = "console.log(a);\nconsole.error(b);\nconsole.log.call(console, c);\nif (d) console.warn(d);\ndebugger;\nfoo();";
+    let output = build(code, true, false);
+    assert!(!output.contains("console"), "{output}");
+    assert!(output.contains("debugger"), "{output}");
+    assert!(output.contains("foo()"), "{output}");
+    let output = build(code, false, true);
+    assert!(output.contains("console.error(b)"), "{output}");
+    assert!(!output.contains("debugger"), "{output}");
+
+    for code in [
+      // The return value is used
+      "const y = console.log(x);",
+      // Not the global `console`
+      "const c = console;\nc.log(x);",
+      "function f(console) {\n\tconsole.log(x);\n}",
+    ] {
+      let output = build(code, true, true);
+      assert!(output.contains(".log(x)"), "{code}\n{output}");
+    }
+  }
+
+  #[test]
+  fn resolve_computed_defines() {
+    let build = |code: &str| {
+      let options = NormalizedBundlerOptions {
+        define_resolver: Some(DefineResolver::new(Arc::new(|name: &str| match name {
+          "__BUILD_TIME__" => Some("123".to_string()),
+          "__X__" => Some("{ a: 1 }".to_string()),
+          "__INVALID__" => Some("1 +".to_string()),
+          _ => None,
+        }))),
+        ..Default::default()
+      };
+      let define_config =
+        ReplaceGlobalDefinesConfig::new(&[("DEBUG".to_string(), "false".to_string())]).unwrap();
+      let ast = EcmaCompiler::parse("main.js", code, SourceType::mjs()).unwrap();
+      PreProcessEcmaAst::default()
+        .build(ast, "main.js", &OxcParseType::Js, Some(&define_config), &options, false)
+        .map(|ret| EcmaCompiler::print_with(&ret.ast, PrintOptions::default()).code)
+    };
+
+    let code = "console.log(__BUILD_TIME__, __X__.a, DEBUG, __OTHER__);
+function f(__X__) {
+\treturn __X__;
+}";
+    let output = build(code).unwrap();
+    assert!(output.contains("console.log(123, { a: 1 }.a, false, __OTHER__)"), "{output}");
+    // Local bindings are not replaced
+    assert!(output.contains("return __X__"), "{output}");
+
+    let errors = build("console.log(__INVALID__);").unwrap_err().into_vec();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].to_diagnostic().to_string().contains("`__INVALID__`"));
+  }
+
+  #[test]
+  fn stop_between_phases_once_cancelled() {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let options = NormalizedBundlerOptions {
+      // Cancel the build while the define phase is running.
+      define_resolver: Some(DefineResolver::new(Arc::new({
+        let cancelled = Arc::clone(&cancelled);
+        move |_: &str| {
+          cancelled.store(true, Ordering::Relaxed);
+          None
+        }
+      }))),
+      treeshake: TreeshakeOptions::Boolean(true).into_normalized_options(),
+      ..Default::default()
+    };
+    let ast = EcmaCompiler::parse("main.js", "console.log(__X__);", SourceType::mjs()).unwrap();
+
+    let mut pre_process =
+      PreProcessEcmaAst::with_timings().with_cancellation(Arc::clone(&cancelled));
+    let errors = pre_process
+      .build(ast, "main.js", &OxcParseType::Js, None, &options, false)
+      .err()
+      .expect("the build should be cancelled")
+      .into_vec();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0].kind(), EventKind::CancelledError));
+
+    let timings = pre_process.take_timings().expect("timings should be collected");
+    let phases = timings.phases.iter().map(|(phase, _)| *phase).collect::<Vec<_>>();
+    assert_eq!(phases, [PreProcessPhase::Semantic, PreProcessPhase::Define]);
+  }
+
+  #[test]
+  fn skip_compress_once_cancelled() {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    // The flag is set before the compress phase, which would otherwise drop the dead branch.
+    let options = NormalizedBundlerOptions {
+      define_resolver: Some(DefineResolver::new(Arc::new({
+        let cancelled = Arc::clone(&cancelled);
+        move |_: &str| {
+          cancelled.store(true, Ordering::Relaxed);
+          None
+        }
+      }))),
+      treeshake: TreeshakeOptions::Boolean(true).into_normalized_options(),
+      minify: MinifyOptions::Enabled((
+        MinifierOptions { mangle: None, compress: Some(CompressOptions::smallest()) },
+        false,
+      )),
+      ..Default::default()
+    };
+    let code = "const a: number = __X__;\nif (false) { console.log(a); }";
+    let ast = EcmaCompiler::parse("main.ts", code, SourceType::ts()).unwrap();
+
+    let mut pre_process =
+      PreProcessEcmaAst::with_timings().with_cancellation(Arc::clone(&cancelled));
+    let result = pre_process.build(ast, "main.ts", &OxcParseType::Ts, None, &options, false);
+    let Err(errors) = result else { panic!("the build should be cancelled") };
+    let errors = errors.into_vec();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0].kind(), EventKind::CancelledError));
+
+    let timings = pre_process.take_timings().expect("timings should be collected");
+    assert!(
+      timings.phases.iter().all(|(phase, _)| !matches!(
+        phase,
+        PreProcessPhase::Transform | PreProcessPhase::Compress | PreProcessPhase::RebuildSemantic
+      )),
+      "{:?}",
+      timings.phases
+    );
+  }
+
+  #[test]
+  fn top_level_declarations_shadowing_imports_are_rejected() {
+    let build = |code: &str| {
+      let options = NormalizedBundlerOptions::default();
+      let ast = EcmaCompiler::parse("main.js", code, SourceType::mjs()).unwrap();
+      PreProcessEcmaAst::default().build(ast, "main.js", &OxcParseType::Js, None, &options, false)
+    };
+
+    for code in [
+      "import { foo } from './m';\nfunction foo() {}",
+      "import { foo } from './m';\nlet foo = 1;",
+      "import * as foo from './m';\nclass foo {}",
+      "import foo from './m';\nvar foo;",
+    ] {
+      let Err(errors) = build(code) else { panic!("{code} should be rejected") };
+      let errors = errors.into_vec();
+      assert_eq!(errors.len(), 1, "{code}");
+      assert!(errors[0].to_diagnostic().to_string().contains("already been declared"), "{code}");
+    }
+
+    // Shadowing in a nested scope is allowed
+    assert!(build("import { foo } from './m';\nfunction bar() {\n\tfunction foo() {}\n}").is_ok());
+  }
+
+  #[test]
+  fn semantic_errors_as_warnings() {
+    let build = |semantic_errors_as_warnings: bool| {
+      let options = NormalizedBundlerOptions {
+        experimental: ExperimentalOptions {
+          semantic_errors_as_warnings: Some(semantic_errors_as_warnings),
+          ..Default::default()
+        },
+        ..Default::default()
+      };
+      let ast =
+        EcmaCompiler::parse("main.js", "let a = 1;\nlet a = 2;", SourceType::mjs()).unwrap();
+      PreProcessEcmaAst::default().build(ast, "main.js", &OxcParseType::Js, None, &options, false)
+    };
+
+    let Err(errors) = build(false) else { panic!("semantic errors should be fatal by default") };
+    assert_eq!(errors.into_vec().len(), 1);
+
+    let ret = build(true).expect("semantic errors should be downgraded to warnings");
+    assert_eq!(ret.warnings.len(), 1);
+    assert_eq!(ret.warnings[0].kind().to_string(), "PARSE_ERROR");
+    assert!(matches!(ret.warnings[0].severity(), Severity::Warning));
+  }
+
+  #[test]
+  fn transformer_warnings_are_reported() {
+    let options = NormalizedBundlerOptions::default();
+    // `export =` is only supported when compiling to CommonJS
+    let ast =
+      EcmaCompiler::parse("main.ts", "const a = 1;\nexport = a;", SourceType::ts()).unwrap();
+    let ret = PreProcessEcmaAst::default()
+      .build(ast, "main.ts", &OxcParseType::Ts, None, &options, false)
+      .expect("transformer warnings should not fail the build");
+    assert_eq!(ret.warnings.len(), 1);
+    assert!(matches!(ret.warnings[0].severity(), Severity::Warning));
+  }
+
+  #[test]
+  fn declaration_files_only_keep_side_effect_imports() {
+    let code = "import './polyfill';\nimport type { A } from './a';\nimport { B } from './b';\n\
+      export declare const c: A;\nexport interface D extends B {}\ndeclare module 'e' {}";
+    let options = NormalizedBundlerOptions::default();
+    let ast =
+      EcmaCompiler::parse("main.d.ts", code, SourceType::ts().with_typescript_definition(true))
+        .unwrap();
+    let ret = PreProcessEcmaAst::default()
+      .build(ast, "main.d.ts", &OxcParseType::Dts, None, &options, false)
+      .unwrap();
+
+    let body = &ret.ast.program().body;
+    assert_eq!(body.len(), 1);
+    let Statement::ImportDeclaration(decl) = &body[0] else {
+      panic!("the side-effect import should be kept");
+    };
+    assert_eq!(decl.source.value, "./polyfill");
   }
 }
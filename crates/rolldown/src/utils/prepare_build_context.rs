@@ -94,6 +94,40 @@ fn verify_raw_options(raw_options: &crate::BundlerOptions) -> Vec<BuildDiagnosti
   warnings
 }
 
+/// Remove identical inject entries, and error if the same global is injected from different
+/// sources.
+fn dedupe_inject(raw_injects: Vec<InjectImport>) -> BuildResult<Vec<InjectImport>> {
+  fn injected_name(inject: &InjectImport) -> &str {
+    match inject {
+      InjectImport::Named { imported, alias, .. } => alias.as_deref().unwrap_or(imported),
+      InjectImport::Namespace { alias, .. } => alias,
+    }
+  }
+
+  fn describe(inject: &InjectImport) -> String {
+    match inject {
+      InjectImport::Named { imported, from, .. } => format!("`{imported}` of \"{from}\""),
+      InjectImport::Namespace { from, .. } => format!("the namespace of \"{from}\""),
+    }
+  }
+
+  let mut injects: Vec<InjectImport> = Vec::with_capacity(raw_injects.len());
+  for raw in raw_injects {
+    match injects.iter().find(|inject| injected_name(inject) == injected_name(&raw)) {
+      Some(existing) if describe(existing) == describe(&raw) => {}
+      Some(existing) => {
+        Err(BuildDiagnostic::invalid_option(InvalidOptionType::ConflictingInject {
+          name: injected_name(&raw).to_string(),
+          first: describe(existing),
+          second: describe(&raw),
+        }))?
+      }
+      None => injects.push(raw),
+    }
+  }
+  Ok(injects)
+}
+
 #[expect(clippy::too_many_lines)] // This function is long, but it's mostly just mapping values
 pub fn prepare_build_context(
   mut raw_options: crate::BundlerOptions,
@@ -159,28 +193,23 @@ pub fn prepare_build_context(
   let globals = raw_options.globals.unwrap_or(GlobalsOutputOption::FxHashMap(FxHashMap::default()));
   let generated_code = raw_options.generated_code.unwrap_or_default();
 
+  let inject = dedupe_inject(raw_options.inject.take().unwrap_or_default())?;
   let oxc_inject_global_variables_config = InjectGlobalVariablesConfig::new(
-    raw_options
-      .inject
-      .as_ref()
-      .map(|raw_injects| {
-        raw_injects
-          .iter()
-          .map(|raw| match raw {
-            InjectImport::Named { imported, alias, from } => {
-              oxc::transformer_plugins::InjectImport::named_specifier(
-                from,
-                Some(imported),
-                alias.as_deref().unwrap_or(imported),
-              )
-            }
-            InjectImport::Namespace { alias, from } => {
-              oxc::transformer_plugins::InjectImport::namespace_specifier(from, alias)
-            }
-          })
-          .collect()
+    inject
+      .iter()
+      .map(|raw| match raw {
+        InjectImport::Named { imported, alias, from } => {
+          oxc::transformer_plugins::InjectImport::named_specifier(
+            from,
+            Some(imported),
+            alias.as_deref().unwrap_or(imported),
+          )
+        }
+        InjectImport::Namespace { alias, from } => {
+          oxc::transformer_plugins::InjectImport::namespace_specifier(from, alias)
+        }
       })
-      .unwrap_or_default(),
+      .collect(),
   );
 
   let mut experimental = raw_options.experimental.unwrap_or_default();
@@ -275,9 +304,13 @@ pub fn prepare_build_context(
     // Use placeholder for minify options at first
     minify: MinifyOptions::Disabled,
     define,
-    inject: raw_options.inject.unwrap_or_default(),
+    define_resolver: raw_options.define_resolver,
+    import_meta_env: raw_options.import_meta_env.unwrap_or_default(),
+    inject,
     oxc_inject_global_variables_config,
     extend: raw_options.extend.unwrap_or(false),
+    global_this: raw_options.global_this.unwrap_or(false),
+    defensive_semicolons: raw_options.defensive_semicolons.unwrap_or(false),
     external_live_bindings: raw_options.external_live_bindings.unwrap_or(true),
     inline_dynamic_imports,
     advanced_chunks: raw_options.advanced_chunks,
@@ -285,6 +318,8 @@ pub fn prepare_build_context(
     watch: raw_options.watch.unwrap_or_default(),
     legal_comments: raw_options.legal_comments.unwrap_or(LegalComments::Inline),
     drop_labels: FxHashSet::from_iter(raw_options.drop_labels.unwrap_or_default()),
+    drop_console: raw_options.drop_console.unwrap_or_default(),
+    drop_debugger: raw_options.drop_debugger.unwrap_or_default(),
     keep_names: raw_options.keep_names.unwrap_or_default(),
     polyfill_require: raw_options.polyfill_require.unwrap_or(true),
     defer_sync_scan_data: raw_options.defer_sync_scan_data,
@@ -311,6 +346,17 @@ pub fn prepare_build_context(
     optimization: normalize_optimization_option(raw_options.optimization, platform),
     top_level_var: raw_options.top_level_var.unwrap_or(false),
     minify_internal_exports: raw_options.minify_internal_exports.unwrap_or(false),
+    namespace_to_string_tag: raw_options.namespace_to_string_tag.unwrap_or(false),
+    require_esm_interop: raw_options.require_esm_interop.unwrap_or_default(),
+    virtual_prefixes: raw_options.virtual_prefixes.unwrap_or_default(),
+    side_effect_entries: raw_options.side_effect_entries.unwrap_or_default(),
+    isolated_modules: raw_options.isolated_modules.unwrap_or(false),
+    validate: raw_options.validate.unwrap_or(false),
+    exports_manifest: raw_options.exports_manifest,
+    integrity: raw_options.integrity.unwrap_or(false),
+    top_level_await: raw_options.top_level_await.unwrap_or(false),
+    keep_comments: raw_options.keep_comments,
+    keep_var_names: raw_options.keep_var_names,
     context: raw_options.context.unwrap_or_default(),
     tsconfig,
   };
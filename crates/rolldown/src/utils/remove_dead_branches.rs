@@ -0,0 +1,143 @@
+use oxc::allocator::{Allocator, TakeIn, Vec as ArenaVec};
+use oxc::ast::AstBuilder;
+use oxc::ast::ast::{
+  ArrowFunctionExpression, Expression, Function, Statement, VariableDeclaration,
+  VariableDeclarationKind,
+};
+use oxc::ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc::semantic::{ScopeFlags, Scoping};
+use oxc::span::SPAN;
+use oxc::syntax::operator::{BinaryOperator, UnaryOperator};
+use oxc_ecmascript::constant_evaluation::{ConstantEvaluation, ConstantValue};
+use rustc_hash::FxHashMap;
+
+use crate::ast_scanner::const_eval::ConstEvalCtx;
+
+/// Removes the dead branch of `if` statements whose test is a constant, e.g.
+/// `if ("production" !== "production") { ... }` after `define`.
+///
+/// This is a lighter alternative to the dead code elimination of the compressor for the
+/// `experimental.preserveParens` option, since the compressor also removes
+/// `ParenthesizedExpression`s. A branch that declares a `var` is kept, the binding is hoisted out
+/// of it.
+pub struct RemoveDeadBranches<'me, 'a> {
+  allocator: &'a Allocator,
+  builder: AstBuilder<'a>,
+  scoping: &'me Scoping,
+}
+
+impl<'me, 'a> RemoveDeadBranches<'me, 'a> {
+  pub fn new(allocator: &'a Allocator, scoping: &'me Scoping) -> Self {
+    Self { allocator, builder: AstBuilder::new(allocator), scoping }
+  }
+
+  /// Replace `if (true) a; else b` with `a` and `if (false) a` with an empty statement.
+  fn remove_dead_branch(&mut self, stmt: &mut Statement<'a>) {
+    while let Statement::IfStatement(if_stmt) = stmt
+      && let Some(test) = self.evaluate_test(&if_stmt.test)
+    {
+      let removed = if test { if_stmt.alternate.as_ref() } else { Some(&if_stmt.consequent) };
+      if removed.is_some_and(declares_var) {
+        return;
+      }
+      let kept = if test {
+        Some(if_stmt.consequent.take_in(self.allocator))
+      } else {
+        if_stmt.alternate.take()
+      };
+      *stmt = kept.unwrap_or_else(|| self.builder.statement_empty(SPAN));
+    }
+  }
+
+  fn evaluate_test(&self, test: &Expression<'a>) -> Option<bool> {
+    if !is_constant(test) {
+      return None;
+    }
+    let constant_map = FxHashMap::default();
+    let ctx = ConstEvalCtx {
+      ast: self.builder,
+      scope: self.scoping,
+      constant_map: &constant_map,
+      overrode_get_constant_value_from_reference_id: None,
+    };
+    match test.evaluate_value(&ctx)? {
+      ConstantValue::Boolean(value) => Some(value),
+      ConstantValue::Number(value) => Some(value != 0.0 && !value.is_nan()),
+      ConstantValue::String(value) => Some(!value.is_empty()),
+      ConstantValue::Undefined | ConstantValue::Null => Some(false),
+      ConstantValue::BigInt(_) => None,
+    }
+  }
+}
+
+impl<'a> VisitMut<'a> for RemoveDeadBranches<'_, 'a> {
+  fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+    for stmt in stmts.iter_mut() {
+      self.remove_dead_branch(stmt);
+    }
+    // Only drop the empty statements created above, not the ones written in the source.
+    stmts.retain(
+      |stmt| !matches!(stmt, Statement::EmptyStatement(empty) if empty.span.is_unspanned()),
+    );
+    walk_mut::walk_statements(self, stmts);
+  }
+
+  fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+    // Statements that aren't in a list, e.g. the body of `for (;;) if (false) a`.
+    self.remove_dead_branch(stmt);
+    walk_mut::walk_statement(self, stmt);
+  }
+}
+
+/// Literals combined with `!`, `void`, equality operators and logical operators, which don't
+/// have side effects.
+fn is_constant(expr: &Expression) -> bool {
+  match expr {
+    Expression::BooleanLiteral(_)
+    | Expression::NumericLiteral(_)
+    | Expression::StringLiteral(_)
+    | Expression::NullLiteral(_) => true,
+    Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+    Expression::ParenthesizedExpression(paren) => is_constant(&paren.expression),
+    Expression::UnaryExpression(unary) => {
+      matches!(unary.operator, UnaryOperator::LogicalNot | UnaryOperator::Void)
+        && is_constant(&unary.argument)
+    }
+    Expression::BinaryExpression(binary) => {
+      matches!(
+        binary.operator,
+        BinaryOperator::Equality
+          | BinaryOperator::Inequality
+          | BinaryOperator::StrictEquality
+          | BinaryOperator::StrictInequality
+      ) && is_constant(&binary.left)
+        && is_constant(&binary.right)
+    }
+    Expression::LogicalExpression(logical) => {
+      is_constant(&logical.left) && is_constant(&logical.right)
+    }
+    _ => false,
+  }
+}
+
+fn declares_var(stmt: &Statement) -> bool {
+  let mut finder = VarDeclarationFinder { found: false };
+  finder.visit_statement(stmt);
+  finder.found
+}
+
+struct VarDeclarationFinder {
+  found: bool,
+}
+
+impl<'a> Visit<'a> for VarDeclarationFinder {
+  fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+    self.found |= decl.kind == VariableDeclarationKind::Var;
+    walk::walk_variable_declaration(self, decl);
+  }
+
+  // A `var` in a nested function belongs to that function.
+  fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+  fn visit_arrow_function_expression(&mut self, _func: &ArrowFunctionExpression<'a>) {}
+}
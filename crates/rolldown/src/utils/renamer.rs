@@ -2,8 +2,8 @@ use oxc::semantic::ScopeId;
 use oxc::span::CompactStr;
 use oxc::syntax::keyword::{GLOBAL_OBJECTS, RESERVED_KEYWORDS};
 use rolldown_common::{
-  AstScopes, ModuleIdx, ModuleScopeSymbolIdMap, NormalModule, OutputFormat, SymbolRef, SymbolRefDb,
-  SymbolRefFlags,
+  AstScopes, GetLocalDb, ModuleIdx, ModuleScopeSymbolIdMap, NormalModule, OutputFormat, SymbolRef,
+  SymbolRefDb, SymbolRefFlags,
 };
 use rolldown_utils::rustc_hash::FxHashMapExt;
 use rolldown_utils::{
@@ -110,6 +110,53 @@ impl<'name> Renamer<'name> {
     }
   }
 
+  /// Bindings visible to a direct `eval` may be referenced by name from the evaluated code. They
+  /// keep their original names, and no other symbol of the chunk is allowed to take these names.
+  ///
+  /// This needs to run before any symbol gets a canonical name. Returns the names of top level
+  /// bindings that can't be kept because they are already reserved, e.g. by a global reference
+  /// or by another module that uses `eval`.
+  pub fn preserve_names_visible_to_eval(
+    &mut self,
+    module: &NormalModule,
+    map: &ModuleScopeSymbolIdMap<'_>,
+  ) -> Vec<CompactStr> {
+    let mut conflicting_names = vec![];
+    let symbol_db = self.symbol_db;
+    let scoping = symbol_db.local_db(module.idx).ast_scopes.scoping();
+    let root_scope_id = scoping.root_scope_id();
+    // The root scope goes first, so names of nested scopes, e.g. a parameter shadowing a top level
+    // binding, aren't taken as conflicts of the module's own top level bindings.
+    if module.scopes_with_direct_eval.contains(&root_scope_id) {
+      for (name, symbol_id) in scoping.get_bindings(root_scope_id) {
+        let symbol_ref: SymbolRef = (module.idx, *symbol_id).into();
+        // Imported bindings are named after the symbols they are linked to.
+        if symbol_ref.canonical_ref(symbol_db) != symbol_ref {
+          continue;
+        }
+        let name = CompactStr::new(name);
+        match self.used_canonical_names.entry(name.clone()) {
+          Entry::Vacant(vac) => {
+            vac.insert(0);
+            self.canonical_names.entry(symbol_ref).or_insert(name);
+          }
+          Entry::Occupied(_) => conflicting_names.push(name),
+        }
+      }
+    }
+    for &scope_id in &module.scopes_with_direct_eval {
+      if scope_id == root_scope_id {
+        continue;
+      }
+      if let Some(bindings) = map.get(&module.idx).map(|vec| &vec[scope_id]) {
+        for &(_, binding_name) in bindings {
+          self.used_canonical_names.entry(CompactStr::new(binding_name)).or_insert(0);
+        }
+      }
+    }
+    conflicting_names
+  }
+
   pub fn create_conflictless_name(&mut self, hint: &str) -> String {
     let mut conflictless_name = CompactStr::new(hint);
     loop {
@@ -155,6 +202,12 @@ impl<'name> Renamer<'name> {
         let mut count = 1;
         let mut candidate_name = Cow::Borrowed(binding_name);
         match canonical_names.entry(binding_ref) {
+          // The name is reserved by `preserve_names_visible_to_eval`.
+          Entry::Vacant(slot) if module.scopes_with_direct_eval.contains(&scope_id) => {
+            let name = CompactStr::new(binding_name);
+            used_canonical_names_for_this_scope.insert(name.clone(), 0);
+            slot.insert(name);
+          }
           Entry::Vacant(slot) => loop {
             let is_shadowed = stack.iter().any(|used_canonical_names| {
               used_canonical_names.contains_key(candidate_name.as_ref())
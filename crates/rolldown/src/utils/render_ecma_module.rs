@@ -17,6 +17,11 @@ pub fn render_ecma_module(
       sources.push(Box::new(concat_string!("//#region ", module.debug_id)));
     }
 
+    // A separate line keeps the sourcemap of the module code untouched.
+    if options.defensive_semicolons && starts_with_asi_hazard(&render_output.code) {
+      sources.push(Box::new(";"));
+    }
+
     let enable_sourcemap = options.sourcemap.is_some() && !module.is_virtual();
 
     // Because oxc codegen sourcemap is last of sourcemap chain,
@@ -53,3 +58,19 @@ pub fn render_ecma_module(
     Some(Arc::from(sources.into_boxed_slice()))
   }
 }
+
+/// Whether `code` starts with a token that would continue the previous statement if it doesn't end
+/// with a semicolon, e.g. `foo()\n(function () {})()` calls the result of `foo()`.
+fn starts_with_asi_hazard(code: &str) -> bool {
+  let mut rest = code;
+  loop {
+    rest = rest.trim_start();
+    if let Some(comment) = rest.strip_prefix("//") {
+      rest = comment.split_once('\n').map_or("", |(_, after)| after);
+    } else if let Some(comment) = rest.strip_prefix("/*") {
+      rest = comment.split_once("*/").map_or("", |(_, after)| after);
+    } else {
+      return rest.starts_with(['(', '[', '`']);
+    }
+  }
+}
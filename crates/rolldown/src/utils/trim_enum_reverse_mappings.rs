@@ -0,0 +1,247 @@
+use oxc::allocator::{Allocator, TakeIn};
+use oxc::ast::ast::{self, AssignmentTarget, Expression, IdentifierReference, Program, Statement};
+use oxc::ast_visit::{Visit, walk};
+use oxc::semantic::{ReferenceId, Scoping, SymbolId};
+use oxc::syntax::operator::LogicalOperator;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Drop the reverse mappings generated by the TypeScript transform for enums whose reverse mappings
+/// are never read, e.g.
+///
+/// ```js
+/// var Enum = /* @__PURE__ */ function(Enum) {
+///   Enum[Enum["A"] = 0] = "A"; // => Enum["A"] = 0;
+///   return Enum;
+/// }(Enum || {});
+/// ```
+///
+/// Only top level enums that are accessed with a static key (`Enum.A` or `Enum["A"]`) are trimmed.
+/// Numeric keys (`Enum[0]` or `Enum["0"]`) read reverse mappings, and any other reference of the
+/// enum, including exporting it, keeps its reverse mappings too.
+pub fn trim_enum_reverse_mappings<'a>(
+  allocator: &'a Allocator,
+  program: &mut Program<'a>,
+  scoping: &Scoping,
+) {
+  let mut collector = EnumReferenceCollector {
+    scoping,
+    enums: FxHashMap::default(),
+    non_enum_declarations: FxHashSet::default(),
+    static_references: FxHashSet::default(),
+    reverse_mapping_used: FxHashSet::default(),
+  };
+  for stmt in &program.body {
+    let Statement::VariableDeclaration(decl) = stmt else {
+      continue;
+    };
+    for declarator in &decl.declarations {
+      let Some(symbol_id) =
+        declarator.id.get_binding_identifier().and_then(|id| id.symbol_id.get())
+      else {
+        continue;
+      };
+      match declarator.init.as_ref().and_then(as_enum_iife) {
+        Some(iife) => collector.add_enum(symbol_id, &iife),
+        None => {
+          collector.non_enum_declarations.insert(symbol_id);
+        }
+      }
+    }
+  }
+  if collector.enums.is_empty() {
+    return;
+  }
+  collector.visit_program(program);
+
+  for stmt in &mut program.body {
+    let Statement::VariableDeclaration(decl) = stmt else {
+      continue;
+    };
+    for declarator in &mut decl.declarations {
+      let Some(symbol_id) =
+        declarator.id.get_binding_identifier().and_then(|id| id.symbol_id.get())
+      else {
+        continue;
+      };
+      if !collector.is_trimmable(symbol_id) {
+        continue;
+      }
+      let Some(Expression::CallExpression(call)) = &mut declarator.init else {
+        continue;
+      };
+      let Expression::FunctionExpression(func) = &mut call.callee else {
+        continue;
+      };
+      let Some(param) =
+        func.params.items[0].pattern.get_binding_identifier().map(|param| param.name)
+      else {
+        continue;
+      };
+      let Some(body) = func.body.as_mut() else {
+        continue;
+      };
+      for stmt in &mut body.statements {
+        if reverse_mapping_object(stmt, &param).is_none() {
+          continue;
+        }
+        // `Enum[Enum["A"] = 0] = "A"` => `Enum["A"] = 0`
+        if let Statement::ExpressionStatement(expr_stmt) = stmt
+          && let Expression::AssignmentExpression(assign_expr) = &mut expr_stmt.expression
+          && let AssignmentTarget::ComputedMemberExpression(target) = &mut assign_expr.left
+        {
+          expr_stmt.expression = target.expression.take_in(allocator);
+        }
+      }
+    }
+  }
+}
+
+struct EnumIife<'b, 'a> {
+  func: &'b ast::Function<'a>,
+  /// `Enum` in `Enum || {}`
+  merged_with: Option<&'b IdentifierReference<'a>>,
+}
+
+/// Match `function(Enum) { ... }(Enum || {})` or `function(Enum) { ... }({})`.
+fn as_enum_iife<'b, 'a>(init: &'b Expression<'a>) -> Option<EnumIife<'b, 'a>> {
+  let Expression::CallExpression(call) = init else {
+    return None;
+  };
+  let Expression::FunctionExpression(func) = &call.callee else {
+    return None;
+  };
+  if func.params.items.len() != 1 || func.params.rest.is_some() || call.arguments.len() != 1 {
+    return None;
+  }
+  match call.arguments[0].as_expression()? {
+    expr if is_empty_object(expr) => Some(EnumIife { func, merged_with: None }),
+    Expression::LogicalExpression(logical)
+      if logical.operator == LogicalOperator::Or && is_empty_object(&logical.right) =>
+    {
+      let Expression::Identifier(ident) = &logical.left else {
+        return None;
+      };
+      Some(EnumIife { func, merged_with: Some(ident.as_ref()) })
+    }
+    _ => None,
+  }
+}
+
+/// Match `Enum[Enum["A"] = 0] = "A"` and return the outer `Enum`.
+fn reverse_mapping_object<'b, 'a>(
+  stmt: &'b Statement<'a>,
+  param: &str,
+) -> Option<&'b IdentifierReference<'a>> {
+  let Statement::ExpressionStatement(expr_stmt) = stmt else {
+    return None;
+  };
+  let Expression::AssignmentExpression(assign_expr) = &expr_stmt.expression else {
+    return None;
+  };
+  let AssignmentTarget::ComputedMemberExpression(target) = &assign_expr.left else {
+    return None;
+  };
+  if !assign_expr.right.is_string_literal() {
+    return None;
+  }
+  let Expression::Identifier(object) = &target.object else {
+    return None;
+  };
+  let Expression::AssignmentExpression(forward) = &target.expression else {
+    return None;
+  };
+  let AssignmentTarget::ComputedMemberExpression(forward_target) = &forward.left else {
+    return None;
+  };
+  let is_param =
+    |expr: &Expression| matches!(expr, Expression::Identifier(ident) if ident.name == param);
+  (is_param(&target.object) && is_param(&forward_target.object)).then_some(object.as_ref())
+}
+
+/// Whether `Enum[key]` might read a reverse mapping, whose keys are stringified numbers like `"0"`,
+/// `"-1"` or `"NaN"`. Any string parsing as a number is considered one, to stay on the safe side.
+fn is_numeric_key(key: &str) -> bool {
+  key.parse::<f64>().is_ok()
+}
+
+fn is_empty_object(expr: &Expression) -> bool {
+  matches!(expr, Expression::ObjectExpression(obj) if obj.properties.is_empty())
+}
+
+struct EnumReferenceCollector<'me> {
+  scoping: &'me Scoping,
+  /// Enum symbols and their IIFE parameters, mapped to the enum symbol.
+  enums: FxHashMap<SymbolId, SymbolId>,
+  /// Symbols declared by a top level variable that isn't an enum IIFE.
+  non_enum_declarations: FxHashSet<SymbolId>,
+  /// References that can't read a reverse mapping.
+  static_references: FxHashSet<ReferenceId>,
+  /// Enums whose reverse mappings might be read.
+  reverse_mapping_used: FxHashSet<SymbolId>,
+}
+
+impl<'a> EnumReferenceCollector<'_> {
+  fn add_enum(&mut self, symbol_id: SymbolId, iife: &EnumIife<'_, 'a>) {
+    self.enums.insert(symbol_id, symbol_id);
+    if let Some(ident) = iife.merged_with {
+      self.static_references.extend(ident.reference_id.get());
+    }
+    let func = iife.func;
+    let Some(param) = func.params.items[0].pattern.get_binding_identifier() else {
+      return;
+    };
+    if let Some(param_symbol_id) = param.symbol_id.get() {
+      self.enums.insert(param_symbol_id, symbol_id);
+    }
+    for stmt in func.body.iter().flat_map(|body| &body.statements) {
+      let object = match stmt {
+        Statement::ReturnStatement(ret) => match &ret.argument {
+          Some(Expression::Identifier(ident)) => Some(ident.as_ref()),
+          _ => None,
+        },
+        _ => reverse_mapping_object(stmt, &param.name),
+      };
+      if let Some(object) = object {
+        self.static_references.extend(object.reference_id.get());
+      }
+    }
+  }
+
+  fn is_trimmable(&self, symbol_id: SymbolId) -> bool {
+    self.enums.get(&symbol_id) == Some(&symbol_id)
+      && !self.non_enum_declarations.contains(&symbol_id)
+      && !self.reverse_mapping_used.contains(&symbol_id)
+  }
+}
+
+impl<'a> Visit<'a> for EnumReferenceCollector<'_> {
+  fn visit_member_expression(&mut self, expr: &ast::MemberExpression<'a>) {
+    let object = match expr {
+      ast::MemberExpression::StaticMemberExpression(expr) => Some(&expr.object),
+      ast::MemberExpression::ComputedMemberExpression(expr) => match &expr.expression {
+        Expression::StringLiteral(key) if !is_numeric_key(&key.value) => Some(&expr.object),
+        _ => None,
+      },
+      _ => None,
+    };
+    if let Some(Expression::Identifier(ident)) = object {
+      self.static_references.extend(ident.reference_id.get());
+    }
+    walk::walk_member_expression(self, expr);
+  }
+
+  fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+    let Some(reference_id) = ident.reference_id.get() else {
+      return;
+    };
+    if self.static_references.contains(&reference_id) {
+      return;
+    }
+    let Some(symbol_id) = self.scoping.get_reference(reference_id).symbol_id() else {
+      return;
+    };
+    if let Some(enum_symbol_id) = self.enums.get(&symbol_id) {
+      self.reverse_mapping_used.insert(*enum_symbol_id);
+    }
+  }
+}
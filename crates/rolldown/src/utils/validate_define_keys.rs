@@ -0,0 +1,78 @@
+use rolldown_error::{BuildDiagnostic, BuildResult};
+use rolldown_utils::ecmascript::is_validate_identifier_name;
+
+/// Check the keys of `define` before they are passed to `ReplaceGlobalDefinesConfig::new`, which
+/// only reports a generic error for a malformed key.
+///
+/// A key is either an identifier like `__DEV__`, or a member expression path like
+/// `process.env.NODE_ENV` whose parts are all identifier names. The last part of a path may be
+/// `*` to match any property, e.g. `import.meta.env.*`.
+pub fn validate_define_keys(define: &[(String, String)]) -> BuildResult<()> {
+  let errors = define
+    .iter()
+    .filter_map(|(key, _)| validate_define_key(key).err())
+    .map(BuildDiagnostic::invalid_define_config)
+    .collect::<Vec<_>>();
+  if errors.is_empty() { Ok(()) } else { Err(errors.into()) }
+}
+
+fn validate_define_key(key: &str) -> Result<(), String> {
+  if !key.contains('.') {
+    return if is_validate_identifier_name(key) {
+      Ok(())
+    } else {
+      Err(format!("Invalid define key `{key}`: `{key}` is not a valid identifier."))
+    };
+  }
+
+  let parts = key.split('.').collect::<Vec<_>>();
+  let last = parts.len() - 1;
+  for (i, part) in parts.iter().enumerate() {
+    if part.is_empty() {
+      return Err(format!(
+        "Invalid define key `{key}`: a member expression path can't have an empty part."
+      ));
+    }
+    if !is_validate_identifier_name(part) && !(i == last && *part == "*") {
+      return Err(format!(
+        "Invalid define key `{key}`: `{part}` in the member expression path is not a valid identifier."
+      ));
+    }
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::validate_define_key;
+
+  #[test]
+  fn identifier_keys() {
+    assert!(validate_define_key("__DEV__").is_ok());
+    assert!(validate_define_key("$foo").is_ok());
+    assert_eq!(
+      validate_define_key("1foo").unwrap_err(),
+      "Invalid define key `1foo`: `1foo` is not a valid identifier."
+    );
+    assert!(validate_define_key("").is_err());
+    assert!(validate_define_key("foo-bar").is_err());
+  }
+
+  #[test]
+  fn member_expression_keys() {
+    assert!(validate_define_key("process.env.NODE_ENV").is_ok());
+    assert!(validate_define_key("import.meta.env.MODE").is_ok());
+    assert!(validate_define_key("import.meta.env.*").is_ok());
+    assert_eq!(
+      validate_define_key("a..b").unwrap_err(),
+      "Invalid define key `a..b`: a member expression path can't have an empty part."
+    );
+    assert!(validate_define_key("a.b.").is_err());
+    assert!(validate_define_key(".a").is_err());
+    assert_eq!(
+      validate_define_key("process.env.1X").unwrap_err(),
+      "Invalid define key `process.env.1X`: `1X` in the member expression path is not a valid identifier."
+    );
+    assert!(validate_define_key("a.*.b").is_err());
+  }
+}
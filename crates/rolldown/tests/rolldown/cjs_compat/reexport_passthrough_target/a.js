@@ -0,0 +1 @@
+exports.foo = { foo: 'picked' }
@@ -0,0 +1,2 @@
+require('./side.js')
+module.exports = require('./other.js')
@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import { value } from './facade.js'
+import { foo } from './pick.js'
+
+assert.equal(value, 'other')
+assert.equal(foo, 'picked')
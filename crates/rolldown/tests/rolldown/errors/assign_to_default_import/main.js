@@ -0,0 +1,7 @@
+import Foo from './foo'
+
+let local = 1
+local = 2
+
+Foo = 1
+console.log(Foo, local)
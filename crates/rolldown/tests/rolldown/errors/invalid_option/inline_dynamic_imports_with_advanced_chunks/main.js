@@ -0,0 +1,4 @@
+import lib from './lib.js'
+
+import('./dynamic.js').then(console.log)
+console.log(lib)
@@ -0,0 +1 @@
+import('./lib.js').then(console.log)
@@ -0,0 +1,2 @@
+export * from './main'
+export const a = 1
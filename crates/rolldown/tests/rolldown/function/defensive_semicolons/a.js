@@ -0,0 +1,3 @@
+(function () {
+  log.push('a');
+})();
@@ -0,0 +1 @@
+[1, 2].forEach((n) => log.push(n));
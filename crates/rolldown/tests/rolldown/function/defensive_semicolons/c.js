@@ -0,0 +1 @@
+log.push('c');
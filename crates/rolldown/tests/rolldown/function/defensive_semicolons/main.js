@@ -0,0 +1,5 @@
+import './a.js';
+import './b.js';
+import './c.js';
+
+if (log.join() !== 'a,1,2,c') throw new Error(log.join());
@@ -0,0 +1,9 @@
+const require = (await import('node:module')).createRequire(import.meta.url);
+const assert = require('node:assert');
+const main = require('./dist/main.js');
+const other = require('./dist/other.js');
+
+// Both entries await the shared chunk using top-level await before assigning their exports
+await Promise.all([main.__tla, other.__tla]);
+assert.strictEqual(main.doubled, 42);
+assert.strictEqual(other.tripled, 63);
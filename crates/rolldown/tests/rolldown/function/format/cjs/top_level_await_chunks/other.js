@@ -0,0 +1,3 @@
+import { value } from './tla.js'
+
+export const tripled = value * 3
@@ -0,0 +1 @@
+export const value = 1;
\ No newline at end of file
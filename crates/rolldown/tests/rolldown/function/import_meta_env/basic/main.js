@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+
+assert.strictEqual(import.meta.env.MODE, 'production')
+assert.strictEqual(import.meta.env.BASE_URL, '/')
+assert.strictEqual(import.meta.env.UNKNOWN, undefined)
+
+const key = 'MODE'
+assert.strictEqual(import.meta.env[key], 'production')
+assert.deepStrictEqual(import.meta.env, { MODE: 'production', BASE_URL: '/' })
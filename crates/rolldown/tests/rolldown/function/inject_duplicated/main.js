@@ -0,0 +1,3 @@
+import assert from 'node:assert'
+
+assert.strictEqual(Buffer, 'buffer-shim')
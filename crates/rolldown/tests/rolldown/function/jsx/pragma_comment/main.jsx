@@ -0,0 +1,4 @@
+/* @jsx h */
+/* @jsxFrag Fragment */
+import { h, Fragment } from './preact'
+console.log(<div/>, <>fragment</>)
@@ -0,0 +1,2 @@
+export function h() {}
+export function Fragment() {}
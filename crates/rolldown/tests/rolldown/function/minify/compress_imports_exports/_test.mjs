@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+import { createRequire } from 'node:module'
+const require = createRequire(import.meta.url)
+
+// Modules compressed before scanning keep the bindings they import and export.
+const mod = globalThis.__configName === 'cjs' ? require('./dist/main.js') : await import('./dist/main.js')
+assert.strictEqual(mod.message, 'hello rolldown')
+assert.strictEqual(mod.count, 1)
+assert.strictEqual(mod.version, '1.0')
@@ -0,0 +1,10 @@
+import { DEBUG, greet } from './shared.js'
+import * as shared from './shared.js'
+
+if (DEBUG) {
+  console.log('debug')
+}
+
+export const message = greet('rolldown')
+export const count = shared.counter()
+export { version } from './shared.js'
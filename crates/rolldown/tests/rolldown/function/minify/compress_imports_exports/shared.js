@@ -0,0 +1,14 @@
+export const DEBUG = false
+
+let calls = 0
+
+export function greet(name) {
+  return 'hello ' + name
+}
+
+export function counter() {
+  calls += 1
+  return calls
+}
+
+export const version = '1.' + 0
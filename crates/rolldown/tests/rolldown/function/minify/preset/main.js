@@ -0,0 +1,3 @@
+export function measure(longParameterName) {
+  return longParameterName.length + longParameterName.length
+}
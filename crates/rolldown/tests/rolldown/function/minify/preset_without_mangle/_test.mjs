@@ -0,0 +1,11 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import path from 'node:path'
+import { measure } from './dist/main.js'
+
+const content = fs.readFileSync(path.resolve(import.meta.dirname, './dist/main.js'), 'utf-8')
+assert.strictEqual(measure('abc'), 6)
+// not mangled
+assert(content.includes('longParameterName'))
+// whitespace removed
+assert(!content.includes('\n  '))
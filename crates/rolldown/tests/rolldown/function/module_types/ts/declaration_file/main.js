@@ -0,0 +1,2 @@
+import './types.d.ts'
+console.log('main')
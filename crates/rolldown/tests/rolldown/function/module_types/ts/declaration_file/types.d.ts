@@ -0,0 +1,3 @@
+import './side-effect.js'
+export declare const a: number;
+export interface B {}
@@ -0,0 +1,8 @@
+import assert from 'node:assert'
+import * as ns from './foo.js'
+import * as empty from './empty.js'
+
+assert.strictEqual(Object.prototype.toString.call(ns), '[object Module]')
+assert.strictEqual(Object.prototype.toString.call(empty), '[object Module]')
+assert.strictEqual(ns.foo, 'foo')
+assert.deepStrictEqual(Object.keys(ns), ['foo'])
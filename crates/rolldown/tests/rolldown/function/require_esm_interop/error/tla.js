@@ -0,0 +1 @@
+export default await Promise.resolve('tla')
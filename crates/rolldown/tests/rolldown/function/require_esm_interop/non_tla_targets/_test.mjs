@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { cjs, esm } from './dist/main.js'
+
+assert.strictEqual(esm.value, 'esm')
+assert.strictEqual(cjs.value, 'cjs')
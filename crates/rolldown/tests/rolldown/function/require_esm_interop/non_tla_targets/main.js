@@ -0,0 +1,5 @@
+// Neither a synchronous ES module nor a CommonJS module is affected by `requireEsmInterop`.
+const esm = require('./esm')
+const cjs = require('./cjs')
+
+export { cjs, esm }
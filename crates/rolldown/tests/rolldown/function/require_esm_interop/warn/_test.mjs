@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { tla } from './dist/main.js'
+
+// The synchronous `require()` observes the exports before `tla.js` finishes initializing.
+assert.strictEqual(tla.default, undefined)
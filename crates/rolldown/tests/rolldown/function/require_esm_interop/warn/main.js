@@ -0,0 +1,3 @@
+const tla = require('./tla')
+
+export { tla }
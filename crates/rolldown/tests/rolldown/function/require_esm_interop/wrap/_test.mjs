@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { tla } from './dist/main.js'
+
+assert.ok(tla instanceof Promise)
+assert.strictEqual((await tla).default, 'tla')
@@ -0,0 +1,10 @@
+import assert from 'node:assert';
+import fn, { Cls } from './reexport.js';
+
+// Take the names derived for the anonymous default exports
+const lib_default = 'main';
+const cls_default = 'main';
+
+assert.strictEqual(fn(), 'lib');
+assert.strictEqual(new Cls().value, 'cls');
+assert.strictEqual(lib_default, cls_default);
@@ -0,0 +1,2 @@
+export { default } from './lib.js';
+export { default as Cls } from './cls.js';
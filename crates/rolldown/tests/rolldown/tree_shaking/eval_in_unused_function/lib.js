@@ -0,0 +1,5 @@
+export const used = 'used'
+export const unrelated = 'unrelated'
+export function runCode(code) {
+  return eval(code)
+}
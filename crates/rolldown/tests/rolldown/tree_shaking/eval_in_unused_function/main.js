@@ -0,0 +1,2 @@
+import { used } from './lib.js'
+console.log(used)
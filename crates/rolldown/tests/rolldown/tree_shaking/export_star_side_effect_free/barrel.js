@@ -0,0 +1,3 @@
+export const foo = 'local';
+export * from './pure';
+export * from './impure';
@@ -0,0 +1,2 @@
+console.log('impure');
+export const baz = 'baz';
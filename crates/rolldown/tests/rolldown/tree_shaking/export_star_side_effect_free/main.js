@@ -0,0 +1,2 @@
+import { foo } from './barrel';
+console.log(foo);
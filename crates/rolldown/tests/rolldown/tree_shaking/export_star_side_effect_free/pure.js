@@ -0,0 +1,2 @@
+export const foo = 'pure';
+export const bar = 'bar';
@@ -0,0 +1,3 @@
+const unused = <div>unused</div>
+
+export const used = <span>used</span>
@@ -0,0 +1,3 @@
+export function fn() {
+  console.log('fn');
+}
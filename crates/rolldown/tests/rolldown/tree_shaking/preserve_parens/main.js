@@ -0,0 +1,6 @@
+import { fn } from './foo';
+(0, fn)();
+console.log((fn));
+if (false) {
+  console.log('dead');
+}
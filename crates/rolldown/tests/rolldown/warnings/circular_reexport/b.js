@@ -0,0 +1,2 @@
+export { a as aFromBarrel } from './barrel.js'
+export const b = 'b'
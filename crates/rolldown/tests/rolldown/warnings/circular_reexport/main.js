@@ -0,0 +1,3 @@
+import { a, b } from './barrel.js'
+
+console.log(a, b)
@@ -0,0 +1,3 @@
+if (typeof window === 'undefined') {
+  module.exports = { value: 1 };
+}
@@ -0,0 +1,6 @@
+import './cjs.js';
+
+if (typeof window === 'undefined') {
+  module.exports = {};
+}
+export const value = 1;
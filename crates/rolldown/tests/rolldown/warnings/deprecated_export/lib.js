@@ -0,0 +1,8 @@
+/** @deprecated Use `bar` instead. */
+export function foo() {
+  return 'foo'
+}
+
+export function bar() {
+  return 'bar'
+}
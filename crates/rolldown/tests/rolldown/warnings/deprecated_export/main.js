@@ -0,0 +1,3 @@
+import { foo, bar } from './lib.js'
+
+console.log(foo(), bar())
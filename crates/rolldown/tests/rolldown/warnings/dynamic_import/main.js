@@ -0,0 +1,7 @@
+export function loadDefault() {
+  return import('./en.js')
+}
+
+export function load(lang) {
+  return import('./' + lang + '.js')
+}
@@ -0,0 +1 @@
+const unused = 1
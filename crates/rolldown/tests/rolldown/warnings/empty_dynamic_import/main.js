@@ -0,0 +1,4 @@
+import('./empty.js').then(console.log)
+import('./real.js').then(console.log)
+import('./named-barrel.js').then(console.log)
+import('./star-barrel.js').then(console.log)
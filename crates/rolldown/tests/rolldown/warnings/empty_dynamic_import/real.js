@@ -0,0 +1 @@
+export const real = 1
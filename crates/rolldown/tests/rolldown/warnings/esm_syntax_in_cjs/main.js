@@ -0,0 +1,3 @@
+import { x } from "./foo.cjs"
+
+console.log(x)
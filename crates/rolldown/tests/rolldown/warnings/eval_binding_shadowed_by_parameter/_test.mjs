@@ -0,0 +1,5 @@
+import assert from 'node:assert';
+import { f, value } from './dist/main.js';
+
+assert.strictEqual(value, 1);
+assert.strictEqual(f(2), 2);
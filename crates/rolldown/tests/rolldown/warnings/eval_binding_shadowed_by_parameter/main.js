@@ -0,0 +1,5 @@
+export const value = 1
+
+export function f(value) {
+  return eval('value')
+}
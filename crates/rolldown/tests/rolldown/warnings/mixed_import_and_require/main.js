@@ -0,0 +1,4 @@
+import m from './m.js'
+import n from './n.js'
+
+export const value = [m, require('./m'), n]
@@ -0,0 +1,5 @@
+import { foo } from './unimplemented.js'
+
+if (!foo) {
+  throw new Error('foo is missing')
+}
@@ -0,0 +1,3 @@
+export const foo = 1
+
+throw new Error('not implemented')
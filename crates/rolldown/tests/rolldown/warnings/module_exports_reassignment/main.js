@@ -0,0 +1,4 @@
+import lost from './lost.js'
+import ok from './ok.js'
+
+export const value = [lost, ok]
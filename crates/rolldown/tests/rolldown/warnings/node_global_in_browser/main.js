@@ -0,0 +1,2 @@
+console.log(Buffer.from('rolldown'));
+console.log(typeof process !== 'undefined');
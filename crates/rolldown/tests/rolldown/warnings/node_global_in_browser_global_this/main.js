@@ -0,0 +1,2 @@
+console.log(globalThis.process.env.NODE_ENV);
+console.log(typeof window.process !== 'undefined');
@@ -0,0 +1,4 @@
+export const a = require('./cjs')
+
+const foo = 'foo'
+export const b = foo
@@ -0,0 +1,2 @@
+import { getA } from 'pure-lib';
+getA();
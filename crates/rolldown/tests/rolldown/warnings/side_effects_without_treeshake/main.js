@@ -0,0 +1,3 @@
+import { a } from 'pure-lib'
+
+console.log(a)
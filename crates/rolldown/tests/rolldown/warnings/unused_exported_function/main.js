@@ -0,0 +1,11 @@
+export function format(value) {
+  return normalize(value).trim()
+}
+
+export function normalize(value) {
+  return String(value)
+}
+
+function unused() {}
+
+export { unused }
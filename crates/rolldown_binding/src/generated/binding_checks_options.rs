@@ -17,6 +17,25 @@ pub struct BindingChecksOptions {
   pub empty_import_meta: Option<bool>,
   pub configuration_field_conflict: Option<bool>,
   pub prefer_builtin_feature: Option<bool>,
+  pub node_global_in_browser: Option<bool>,
+  pub require_in_esm_export: Option<bool>,
+  pub side_effects_in_pure_package: Option<bool>,
+  pub conditional_module_exports_in_esm: Option<bool>,
+  pub esm_syntax_in_common_js: Option<bool>,
+  pub deprecated_export: Option<bool>,
+  pub entry_without_exports: Option<bool>,
+  pub unused_exported_function: Option<bool>,
+  pub empty_dynamic_import: Option<bool>,
+  pub circular_import_at_init: Option<bool>,
+  pub dynamic_require: Option<bool>,
+  pub side_effects_without_treeshake: Option<bool>,
+  pub dynamic_import: Option<bool>,
+  pub unused_function_parameter: Option<bool>,
+  pub mixed_import_and_require: Option<bool>,
+  pub module_always_throws: Option<bool>,
+  pub circular_reexport: Option<bool>,
+  pub module_exports_reassignment: Option<bool>,
+  pub eval_binding_renamed: Option<bool>,
 }
 impl From<BindingChecksOptions> for rolldown_common::ChecksOptions {
   fn from(value: BindingChecksOptions) -> Self {
@@ -34,6 +53,25 @@ impl From<BindingChecksOptions> for rolldown_common::ChecksOptions {
       empty_import_meta: value.empty_import_meta,
       configuration_field_conflict: value.configuration_field_conflict,
       prefer_builtin_feature: value.prefer_builtin_feature,
+      node_global_in_browser: value.node_global_in_browser,
+      require_in_esm_export: value.require_in_esm_export,
+      side_effects_in_pure_package: value.side_effects_in_pure_package,
+      conditional_module_exports_in_esm: value.conditional_module_exports_in_esm,
+      esm_syntax_in_common_js: value.esm_syntax_in_common_js,
+      deprecated_export: value.deprecated_export,
+      entry_without_exports: value.entry_without_exports,
+      unused_exported_function: value.unused_exported_function,
+      empty_dynamic_import: value.empty_dynamic_import,
+      circular_import_at_init: value.circular_import_at_init,
+      dynamic_require: value.dynamic_require,
+      side_effects_without_treeshake: value.side_effects_without_treeshake,
+      dynamic_import: value.dynamic_import,
+      unused_function_parameter: value.unused_function_parameter,
+      mixed_import_and_require: value.mixed_import_and_require,
+      module_always_throws: value.module_always_throws,
+      circular_reexport: value.circular_reexport,
+      module_exports_reassignment: value.module_exports_reassignment,
+      eval_binding_renamed: value.eval_binding_renamed,
     }
   }
 }
@@ -7,6 +7,8 @@ pub struct BindingExperimentalOptions {
   pub disable_live_bindings: Option<bool>,
   pub vite_mode: Option<bool>,
   pub resolve_new_url_to_asset: Option<bool>,
+  pub new_url_ignore_comment: Option<String>,
+  pub resolve_require_resolve_to_asset: Option<bool>,
   pub hmr: Option<BindingExperimentalHmrOptions>,
   pub attach_debug_info: Option<BindingAttachDebugInfo>,
   pub chunk_modules_order: Option<BindingChunkModuleOrderBy>,
@@ -15,6 +17,8 @@ pub struct BindingExperimentalOptions {
   pub incremental_build: Option<bool>,
   #[napi(ts_type = "boolean | 'boundary'")]
   pub transform_hires_sourcemap: Option<Either<bool, String>>,
+  pub semantic_errors_as_warnings: Option<bool>,
+  pub preserve_parens: Option<bool>,
 }
 
 impl TryFrom<BindingExperimentalOptions> for rolldown_common::ExperimentalOptions {
@@ -26,6 +30,8 @@ impl TryFrom<BindingExperimentalOptions> for rolldown_common::ExperimentalOption
       disable_live_bindings: value.disable_live_bindings,
       vite_mode: value.vite_mode,
       resolve_new_url_to_asset: value.resolve_new_url_to_asset,
+      new_url_ignore_comment: value.new_url_ignore_comment,
+      resolve_require_resolve_to_asset: value.resolve_require_resolve_to_asset,
       incremental_build: value.incremental_build,
       hmr: value.hmr.map(Into::into),
       attach_debug_info: value.attach_debug_info.map(Into::into),
@@ -52,6 +58,8 @@ impl TryFrom<BindingExperimentalOptions> for rolldown_common::ExperimentalOption
       } else {
         None
       },
+      semantic_errors_as_warnings: value.semantic_errors_as_warnings,
+      preserve_parens: value.preserve_parens,
     })
   }
 }
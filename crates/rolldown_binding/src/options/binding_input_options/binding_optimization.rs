@@ -14,6 +14,8 @@ pub struct BindingOptimization {
   #[napi(ts_type = "boolean | BindingInlineConstConfig")]
   pub inline_const: Option<Either<bool, BindingInlineConstConfig>>,
   pub pife_for_module_wrappers: Option<bool>,
+  pub trim_enum_reverse_mappings: Option<bool>,
+  pub max_member_expr_chain_length: Option<u32>,
 }
 
 impl TryFrom<BindingOptimization> for rolldown_common::OptimizationOption {
@@ -45,6 +47,11 @@ impl TryFrom<BindingOptimization> for rolldown_common::OptimizationOption {
       None => None,
     };
 
-    Ok(Self { inline_const, pife_for_module_wrappers: value.pife_for_module_wrappers })
+    Ok(Self {
+      inline_const,
+      pife_for_module_wrappers: value.pife_for_module_wrappers,
+      trim_enum_reverse_mappings: value.trim_enum_reverse_mappings,
+      max_member_expr_chain_length: value.max_member_expr_chain_length,
+    })
   }
 }
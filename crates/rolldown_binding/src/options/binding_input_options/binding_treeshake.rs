@@ -44,6 +44,10 @@ pub struct BindingTreeshake {
   pub annotations: Option<bool>,
   #[napi(ts_type = "ReadonlyArray<string>")]
   pub manual_pure_functions: Option<FxHashSet<String>>,
+  #[napi(ts_type = "ReadonlyArray<string>")]
+  pub pure_globals: Option<FxHashSet<String>>,
+  #[napi(ts_type = "ReadonlyArray<string>")]
+  pub pure_annotations: Option<FxHashSet<String>>,
   pub unknown_global_side_effects: Option<bool>,
   pub commonjs: Option<bool>,
   pub property_read_side_effects: Option<BindingPropertyReadSideEffects>,
@@ -105,6 +109,8 @@ impl TryFrom<BindingTreeshake> for rolldown::TreeshakeOptions {
       module_side_effects,
       annotations: value.annotations,
       manual_pure_functions: value.manual_pure_functions,
+      pure_globals: value.pure_globals,
+      pure_annotations: value.pure_annotations,
       unknown_global_side_effects: value.unknown_global_side_effects,
       commonjs: value.commonjs,
       property_read_side_effects,
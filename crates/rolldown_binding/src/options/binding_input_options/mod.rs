@@ -88,7 +88,10 @@ pub struct BindingInputOptions<'env> {
 
   pub module_types: Option<HashMap<String, String, FxBuildHasher>>,
   pub define: Option<Vec<(/* Target to be replaced */ String, /* Replacement */ String)>>,
+  pub import_meta_env: Option<Vec<(/* Key */ String, /* Value */ String)>>,
   pub drop_labels: Option<Vec<String>>,
+  pub drop_console: Option<bool>,
+  pub drop_debugger: Option<bool>,
   #[napi(ts_type = "Array<BindingInjectImportNamed | BindingInjectImportNamespace>")]
   pub inject: Option<Vec<BindingInjectImport>>,
   pub experimental: Option<binding_experimental_options::BindingExperimentalOptions>,
@@ -112,4 +115,9 @@ pub struct BindingInputOptions<'env> {
   pub optimization: Option<BindingOptimization>,
   pub context: Option<String>,
   pub tsconfig: Option<String>,
+  #[napi(ts_type = "'error' | 'warn' | 'wrap'")]
+  pub require_esm_interop: Option<String>,
+  pub virtual_prefixes: Option<Vec<String>>,
+  pub side_effect_entries: Option<Vec<String>>,
+  pub isolated_modules: Option<bool>,
 }
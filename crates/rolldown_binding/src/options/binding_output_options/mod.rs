@@ -18,6 +18,7 @@ use crate::types::{
   binding_rendered_chunk::BindingRenderedChunk,
   binding_string_or_regex::BindingStringOrRegex,
   js_callback::{JsCallback, MaybeAsyncJsCallback},
+  js_regex::JsRegExp,
 };
 
 pub type AddonOutputOption = MaybeAsyncJsCallback<FnArgs<(BindingRenderedChunk,)>, Option<String>>;
@@ -69,6 +70,8 @@ pub struct BindingOutputOptions<'env> {
   #[napi(ts_type = "'default' | 'named' | 'none' | 'auto'")]
   pub exports: Option<String>,
   pub extend: Option<bool>,
+  pub global_this: Option<bool>,
+  pub defensive_semicolons: Option<bool>,
   pub external_live_bindings: Option<bool>,
   #[debug(skip)]
   #[napi(ts_type = "(chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>")]
@@ -117,7 +120,7 @@ pub struct BindingOutputOptions<'env> {
   // sourcemapFile: string | undefined;
   // strict: boolean;
   // systemNullSetters: boolean;
-  // validate: boolean;
+  pub validate: Option<bool>,
 
   // --- Enhanced options
   #[debug(skip)]
@@ -126,10 +129,18 @@ pub struct BindingOutputOptions<'env> {
   pub advanced_chunks: Option<BindingAdvancedChunksOptions>,
   #[napi(ts_type = "'none' | 'inline'")]
   pub legal_comments: Option<String>,
+  #[napi(ts_type = "RegExp")]
+  pub keep_comments: Option<JsRegExp>,
+  #[napi(ts_type = "RegExp")]
+  pub keep_var_names: Option<JsRegExp>,
   pub polyfill_require: Option<bool>,
   pub preserve_modules: Option<bool>,
   pub virtual_dirname: Option<String>,
   pub preserve_modules_root: Option<String>,
   pub top_level_var: Option<bool>,
   pub minify_internal_exports: Option<bool>,
+  pub namespace_to_string_tag: Option<bool>,
+  pub exports_manifest: Option<String>,
+  pub integrity: Option<bool>,
+  pub top_level_await: Option<bool>,
 }
@@ -14,12 +14,13 @@ pub struct BindingPluginContextResolveOptions {
   /// - `import-statement`: `import { foo } from './lib.js';`
   /// - `dynamic-import`: `import('./lib.js')`
   /// - `require-call`: `require('./lib.js')`
+  /// - `require-resolve`: `require.resolve('./lib.js')`
   /// - `import-rule`: `@import 'bg-color.css'`
   /// - `url-token`: `url('./icon.png')`
   /// - `new-url`: `new URL('./worker.js', import.meta.url)`
   /// - `hot-accept`: `import.meta.hot.accept('./lib.js', () => {})`
   #[napi(
-    ts_type = "'import-statement' | 'dynamic-import' | 'require-call' | 'import-rule' | 'url-token' | 'new-url' | 'hot-accept'"
+    ts_type = "'import-statement' | 'dynamic-import' | 'require-call' | 'require-resolve' | 'import-rule' | 'url-token' | 'new-url' | 'hot-accept'"
   )]
   pub import_kind: Option<String>,
   pub is_entry: Option<bool>,
@@ -3,6 +3,7 @@ use std::{collections::HashMap, sync::Arc};
 use arcstr::ArcStr;
 use napi_derive::napi;
 use rolldown_sourcemap::SourceMap;
+use rolldown_utils::integrity::sha384_integrity;
 use rustc_hash::FxBuildHasher;
 
 use super::{
@@ -94,6 +95,11 @@ impl BindingOutputChunk {
   pub fn name(&self) -> String {
     self.inner.name.to_string()
   }
+
+  #[napi(getter)]
+  pub fn integrity(&self) -> Option<String> {
+    self.inner.integrity.clone()
+  }
 }
 
 #[napi(object)]
@@ -122,6 +128,8 @@ pub fn update_output_chunk(
   js_chunk: JsOutputChunk,
 ) -> anyhow::Result<()> {
   let old_chunk = (**chunk).clone();
+  // Keep the integrity hash in sync with code changed by `generateBundle` hooks
+  let integrity = old_chunk.integrity.as_ref().map(|_| sha384_integrity(js_chunk.code.as_bytes()));
   *chunk = Arc::new(rolldown_common::OutputChunk {
     code: js_chunk.code,
     map: js_chunk.map.map(TryInto::try_into).transpose()?,
@@ -129,6 +137,7 @@ pub fn update_output_chunk(
     dynamic_imports: js_chunk.dynamic_imports.into_iter().map(Into::into).collect(),
     is_entry: js_chunk.is_entry, // used by nuxt
     filename: js_chunk.filename.into(),
+    integrity,
     ..old_chunk
   });
   Ok(())
@@ -17,13 +17,14 @@ use napi::bindgen_prelude::{Either, Either3, FnArgs};
 use rolldown::{
   AddonOutputOption, AdvancedChunksOptions, AssetFilenamesOutputOption, BundlerOptions,
   ChunkFilenamesOutputOption, DeferSyncScanDataOption, HashCharacters, IsExternal, MatchGroup,
-  MatchGroupName, ModuleType, OptimizationOption, OutputExports, OutputFormat, Platform,
-  RawMinifyOptions, SanitizeFilename,
+  MatchGroupName, MinifyPresetOptions, ModuleType, OptimizationOption, OutputExports, OutputFormat,
+  Platform, RawMinifyOptions, SanitizeFilename,
 };
 use rolldown_common::GeneratedCodeOptions;
 use rolldown_common::{DeferSyncScanData, bundler_options};
 use rolldown_plugin::__inner::SharedPluginable;
 use rolldown_utils::indexmap::FxIndexMap;
+use rolldown_utils::js_regex::HybridRegex;
 use rolldown_utils::rustc_hash::FxHashMapExt;
 use rustc_hash::FxHashMap;
 use std::path::PathBuf;
@@ -356,19 +357,44 @@ pub fn normalize_binding_options(
             Err(napi::Error::new(napi::Status::InvalidArg, "Invalid minify option"))
           }
         }
-        napi::bindgen_prelude::Either3::C(opts) => Ok(RawMinifyOptions::Object((
-          oxc::minifier::MinifierOptions::try_from(&opts)
-            .map_err(|_| napi::Error::new(napi::Status::InvalidArg, "Invalid minify option"))?,
-          match &opts.codegen {
+        napi::bindgen_prelude::Either3::C(opts) => {
+          let remove_whitespace = match &opts.codegen {
             None => true,
             Some(Either::A(bool)) => *bool,
             Some(Either::B(codegen_opts)) => codegen_opts.remove_whitespace.unwrap_or(true),
-          },
-        ))),
+          };
+          // Without detailed `compress` or `mangle` options, fall back to the `minify: true` preset
+          // so that the sub-options still respect `target`, `keepNames` and the output format.
+          let compress = match &opts.compress {
+            None => Some(None),
+            Some(Either::A(bool)) => Some(Some(*bool)),
+            Some(Either::B(_)) => None,
+          };
+          let mangle = match &opts.mangle {
+            None => Some(None),
+            Some(Either::A(bool)) => Some(Some(*bool)),
+            Some(Either::B(_)) => None,
+          };
+          if let (Some(compress), Some(mangle)) = (compress, mangle) {
+            return Ok(RawMinifyOptions::Preset(MinifyPresetOptions {
+              compress,
+              mangle,
+              remove_whitespace: Some(remove_whitespace),
+            }));
+          }
+          Ok(RawMinifyOptions::Object((
+            oxc::minifier::MinifierOptions::try_from(&opts)
+              .map_err(|_| napi::Error::new(napi::Status::InvalidArg, "Invalid minify option"))?,
+            remove_whitespace,
+          )))
+        }
       })
       .transpose()?,
     extend: output_options.extend,
+    global_this: output_options.global_this,
+    defensive_semicolons: output_options.defensive_semicolons,
     define: input_options.define.map(FxIndexMap::from_iter),
+    import_meta_env: input_options.import_meta_env.map(FxIndexMap::from_iter),
     inject: input_options
       .inject
       .map(|inner| inner.into_iter().map(normalize_binding_inject_import).collect()),
@@ -438,7 +464,31 @@ pub fn normalize_binding_options(
         )),
       })
       .transpose()?,
+    keep_comments: output_options
+      .keep_comments
+      .map(|inner| {
+        HybridRegex::try_from(inner).map_err(|err| {
+          napi::Error::new(
+            napi::Status::GenericFailure,
+            format!("Invalid value for `keepComments` option: {err}"),
+          )
+        })
+      })
+      .transpose()?,
+    keep_var_names: output_options
+      .keep_var_names
+      .map(|inner| {
+        HybridRegex::try_from(inner).map_err(|err| {
+          napi::Error::new(
+            napi::Status::GenericFailure,
+            format!("Invalid value for `keepVarNames` option: {err}"),
+          )
+        })
+      })
+      .transpose()?,
     drop_labels: input_options.drop_labels,
+    drop_console: input_options.drop_console,
+    drop_debugger: input_options.drop_debugger,
     keep_names: input_options.keep_names,
     polyfill_require: output_options.polyfill_require,
     defer_sync_scan_data: get_defer_sync_scan_data,
@@ -460,8 +510,29 @@ pub fn normalize_binding_options(
     optimization: input_options.optimization.map(OptimizationOption::try_from).transpose()?,
     top_level_var: output_options.top_level_var,
     minify_internal_exports: output_options.minify_internal_exports,
+    namespace_to_string_tag: output_options.namespace_to_string_tag,
+    validate: output_options.validate,
+    exports_manifest: output_options.exports_manifest,
+    integrity: output_options.integrity,
+    top_level_await: output_options.top_level_await,
     context: input_options.context,
     tsconfig: input_options.tsconfig,
+    require_esm_interop: input_options
+      .require_esm_interop
+      .map(|inner| match inner.as_str() {
+        "error" => Ok(rolldown::RequireEsmInterop::Error),
+        "warn" => Ok(rolldown::RequireEsmInterop::Warn),
+        "wrap" => Ok(rolldown::RequireEsmInterop::Wrap),
+        _ => Err(napi::Error::new(
+          napi::Status::GenericFailure,
+          format!("Invalid value for `requireEsmInterop` option: {inner}"),
+        )),
+      })
+      .transpose()?,
+    virtual_prefixes: input_options.virtual_prefixes,
+    side_effect_entries: input_options.side_effect_entries,
+    isolated_modules: input_options.isolated_modules,
+    define_resolver: None,
   };
 
   #[cfg(not(target_family = "wasm"))]
@@ -2,7 +2,7 @@ use crate::{ConstExportMeta, ImportAttribute, RUNTIME_HELPER_NAMES, StmtInfoIdx}
 use arcstr::ArcStr;
 use bitflags::bitflags;
 use oxc::{
-  semantic::SymbolId,
+  semantic::{ScopeId, SymbolId},
   span::{CompactStr, Span},
 };
 use oxc_index::IndexVec;
@@ -103,6 +103,8 @@ pub struct EcmaView {
   pub mutations: Vec<ArcSourceMutation>,
   /// `Span` of `new URL('path', import.meta.url)` -> `ImportRecordIdx`
   pub new_url_references: FxHashMap<Span, ImportRecordIdx>,
+  /// `Span` of `require.resolve('path')` -> `ImportRecordIdx`
+  pub require_resolve_references: FxHashMap<Span, ImportRecordIdx>,
   pub this_expr_replace_map: FxHashMap<Span, ThisExprReplaceKind>,
   pub depended_runtime_helper: Box<[Vec<StmtInfoIdx>; RUNTIME_HELPER_NAMES.len()]>,
 
@@ -110,6 +112,45 @@ pub struct EcmaView {
   pub hmr_info: HmrInfo,
   pub constant_export_map: FxHashMap<SymbolId, ConstExportMeta>,
   pub import_attribute_map: FxHashMap<ImportRecordIdx, ImportAttribute>,
+  /// Spans of `import(...)` expressions whose source isn't a static module request, e.g.
+  /// `import(variable)`. They don't create import records, plugins could transform them manually.
+  pub dynamic_import_exprs: Vec<Span>,
+  /// Spans of `import.meta.env` references to be replaced according to the `importMetaEnv` option.
+  /// `Some(key)` for `import.meta.env.KEY` with a configured `KEY`, `None` for other references of
+  /// `import.meta.env`, which are replaced with an object literal of all values.
+  pub import_meta_env_references: FxHashMap<Span, Option<CompactStr>>,
+  /// Spans of `import.meta` destructured by a variable declaration, e.g. `const { url } = import.meta`,
+  /// mapped to the destructured keys. They are replaced with an object literal of the polyfilled
+  /// properties for non-esm output.
+  pub destructured_import_meta: FxHashMap<Span, Vec<CompactStr>>,
+  /// Local symbols of exports annotated with a `@deprecated` JSDoc, mapped to the text following
+  /// the tag.
+  pub deprecated_exports: FxHashMap<SymbolId, ArcStr>,
+  /// Named import symbols referenced while the module is being initialized, i.e. not inside a
+  /// function, mapped to the span of the first such reference.
+  pub imports_referenced_at_init: FxHashMap<SymbolId, Span>,
+  /// The url of the trailing `//# sourceMappingURL=` comment, which is either the location of the
+  /// input source map or an inline `data:` url. Used to chain through the input source map.
+  pub source_mapping_url: Option<ArcStr>,
+  /// Scopes that contain a direct `eval`, directly or in a nested scope. The evaluated code may
+  /// reference their bindings by name, so these bindings must keep their original names.
+  pub scopes_with_direct_eval: FxHashSet<ScopeId>,
+  /// Names and definition spans of the functions, classes and variables declared in the module
+  /// scope, in source order. Imported bindings and redeclarations of a `var` aren't included.
+  pub top_level_symbols: Vec<(CompactStr, Span)>,
+  /// Spans of the arguments of `require(...)` calls that can't be resolved at build time, e.g.
+  /// `require(name)` or `require('./locale/' + lang)`.
+  pub dynamic_requires: Vec<Span>,
+  /// Spans of the top level statements of the original source, in source order. The statement of
+  /// `stmt_spans[i]` is `stmt_infos[i + 1]`, since the first `StmtInfo` is the namespace statement.
+  pub stmt_spans: Vec<Span>,
+}
+
+impl EcmaView {
+  /// The top level statements that are side-effect-free, see `StmtInfos::side_effect_free_stmts`.
+  pub fn side_effect_free_stmts(&self) -> Vec<(StmtInfoIdx, String)> {
+    self.stmt_infos.side_effect_free_stmts(&self.stmt_spans)
+  }
 }
 
 bitflags! {
@@ -127,6 +168,11 @@ bitflags! {
         const UnknownExportsRead = 1 << 7;
         /// Top-level return statement (only valid in CommonJS)
         const TopLevelReturn = 1 << 8;
+        /// `Object.freeze(exports)` or `Object.seal(module.exports)`, the `exports.<prop>`
+        /// assignments before it are the complete export set.
+        const ExportsFrozen = 1 << 9;
+        /// `import.meta.url` is read, directly or by destructuring `import.meta`.
+        const ImportMetaUrl = 1 << 10;
         const ModuleOrExports = Self::ModuleRef.bits() | Self::ExportsRef.bits();
     }
 }
@@ -25,6 +25,25 @@ pub struct ChecksOptions {
   pub empty_import_meta: Option<bool>,
   pub configuration_field_conflict: Option<bool>,
   pub prefer_builtin_feature: Option<bool>,
+  pub node_global_in_browser: Option<bool>,
+  pub require_in_esm_export: Option<bool>,
+  pub side_effects_in_pure_package: Option<bool>,
+  pub conditional_module_exports_in_esm: Option<bool>,
+  pub esm_syntax_in_common_js: Option<bool>,
+  pub deprecated_export: Option<bool>,
+  pub entry_without_exports: Option<bool>,
+  pub unused_exported_function: Option<bool>,
+  pub empty_dynamic_import: Option<bool>,
+  pub circular_import_at_init: Option<bool>,
+  pub dynamic_require: Option<bool>,
+  pub side_effects_without_treeshake: Option<bool>,
+  pub dynamic_import: Option<bool>,
+  pub unused_function_parameter: Option<bool>,
+  pub mixed_import_and_require: Option<bool>,
+  pub module_always_throws: Option<bool>,
+  pub circular_reexport: Option<bool>,
+  pub module_exports_reassignment: Option<bool>,
+  pub eval_binding_renamed: Option<bool>,
 }
 impl From<ChecksOptions> for rolldown_error::EventKindSwitcher {
   fn from(value: ChecksOptions) -> Self {
@@ -75,6 +94,80 @@ impl From<ChecksOptions> for rolldown_error::EventKindSwitcher {
       rolldown_error::EventKindSwitcher::PreferBuiltinFeature,
       value.prefer_builtin_feature.unwrap_or(true),
     );
+    flag.set(
+      rolldown_error::EventKindSwitcher::NodeGlobalInBrowser,
+      value.node_global_in_browser.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::RequireInEsmExport,
+      value.require_in_esm_export.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::SideEffectsInPurePackage,
+      value.side_effects_in_pure_package.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::ConditionalModuleExportsInEsm,
+      value.conditional_module_exports_in_esm.unwrap_or(true),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::EsmSyntaxInCommonJs,
+      value.esm_syntax_in_common_js.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::DeprecatedExport,
+      value.deprecated_export.unwrap_or(true),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::EntryWithoutExports,
+      value.entry_without_exports.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::UnusedExportedFunction,
+      value.unused_exported_function.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::EmptyDynamicImport,
+      value.empty_dynamic_import.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::CircularImportAtInit,
+      value.circular_import_at_init.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::DynamicRequire,
+      value.dynamic_require.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::SideEffectsWithoutTreeshake,
+      value.side_effects_without_treeshake.unwrap_or(false),
+    );
+    flag
+      .set(rolldown_error::EventKindSwitcher::DynamicImport, value.dynamic_import.unwrap_or(false));
+    flag.set(
+      rolldown_error::EventKindSwitcher::UnusedFunctionParameter,
+      value.unused_function_parameter.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::MixedImportAndRequire,
+      value.mixed_import_and_require.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::ModuleAlwaysThrows,
+      value.module_always_throws.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::CircularReexport,
+      value.circular_reexport.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::ModuleExportsReassignment,
+      value.module_exports_reassignment.unwrap_or(false),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::EvalBindingRenamed,
+      value.eval_binding_renamed.unwrap_or(true),
+    );
     flag
   }
 }
@@ -1,8 +1,10 @@
 use rolldown_utils::indexmap::FxIndexMap;
+use rolldown_utils::js_regex::HybridRegex;
 use rustc_hash::FxHashMap;
 use std::{fmt::Debug, path::PathBuf};
 use types::advanced_chunks_options::AdvancedChunksOptions;
 use types::debug_options::DebugOptions;
+use types::define_resolver::DefineResolver;
 use types::generated_code_options::GeneratedCodeOptions;
 use types::inject_import::InjectImport;
 use types::invalidate_js_side_cache::InvalidateJsSideCache;
@@ -15,6 +17,7 @@ use types::optimization::OptimizationOption;
 use types::output_option::{
   AssetFilenamesOutputOption, GlobalsOutputOption, PreserveEntrySignatures,
 };
+use types::require_esm_interop::RequireEsmInterop;
 use types::sanitize_filename::SanitizeFilename;
 use types::watch_option::WatchOption;
 
@@ -114,6 +117,8 @@ pub struct BundlerOptions {
   pub sourcemap: Option<SourceMapType>,
   pub es_module: Option<EsModuleFlag>,
   pub drop_labels: Option<Vec<String>>,
+  pub drop_console: Option<bool>,
+  pub drop_debugger: Option<bool>,
   pub hash_characters: Option<HashCharacters>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
@@ -175,7 +180,28 @@ pub struct BundlerOptions {
     schemars(with = "Option<FxHashMap<String, String>>")
   )]
   pub define: Option<FxIndexMap<String, String>>,
+  /// Consulted for global identifiers that aren't replaced by `define`.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, skip_deserializing),
+    schemars(skip)
+  )]
+  pub define_resolver: Option<DefineResolver>,
+  /// Replace `import.meta.env.KEY` with the string value of `KEY`, and other `import.meta.env`
+  /// references with an object literal of all values.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    schemars(with = "Option<FxHashMap<String, String>>")
+  )]
+  pub import_meta_env: Option<FxIndexMap<String, String>>,
   pub extend: Option<bool>,
+  /// Assign the `name` of IIFE output to `globalThis`, or `window` for the `browser` platform,
+  /// instead of `this`.
+  pub global_this: Option<bool>,
+  /// Insert a `;` before a module that starts with `(`, `[` or `` ` `` and before the wrapper of
+  /// `iife` and `umd` chunks, so they can't continue the statement before them when the chunk is
+  /// concatenated with other code.
+  pub defensive_semicolons: Option<bool>,
   pub profiler_names: Option<bool>,
   pub keep_names: Option<bool>,
   pub inject: Option<Vec<InjectImport>>,
@@ -220,6 +246,43 @@ pub struct BundlerOptions {
   pub optimization: Option<OptimizationOption>,
   pub top_level_var: Option<bool>,
   pub minify_internal_exports: Option<bool>,
+  /// Whether to add `Symbol.toStringTag` with the value `'Module'` to synthesized namespace
+  /// objects, so that `Object.prototype.toString.call(ns)` returns `[object Module]`.
+  pub namespace_to_string_tag: Option<bool>,
+  pub require_esm_interop: Option<RequireEsmInterop>,
+  /// Module requests starting with one of these prefixes, e.g. `virtual:`, refer to virtual modules
+  /// that must be provided by a plugin.
+  pub virtual_prefixes: Option<Vec<String>>,
+  /// Names of entries that only run side effects, e.g. a CLI, so they aren't reported by the
+  /// `entryWithoutExports` check.
+  pub side_effect_entries: Option<Vec<String>>,
+  /// Reject TypeScript constructs that can't be transpiled one file at a time, like
+  /// `isolatedModules` in `tsconfig.json` does.
+  pub isolated_modules: Option<bool>,
+  /// Whether to re-parse each generated chunk and fail the build if it isn't valid JavaScript.
+  pub validate: Option<bool>,
+  /// File name of an emitted JSON asset that maps the file name of each chunk to its exports, from
+  /// the original export names to the names in the output.
+  pub exports_manifest: Option<String>,
+  /// Whether to compute a `sha384` subresource integrity hash of the code of each chunk.
+  pub integrity: Option<bool>,
+  /// Allow top-level await with the `cjs` format by wrapping the chunks that need it in an async
+  /// IIFE.
+  pub top_level_await: Option<bool>,
+  /// Comments matching this pattern are kept in the output, in addition to legal comments.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_keep_comments"),
+    schemars(with = "Option<String>")
+  )]
+  pub keep_comments: Option<HybridRegex>,
+  /// Variables whose original names match this pattern aren't renamed by the minifier.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_keep_var_names"),
+    schemars(with = "Option<String>")
+  )]
+  pub keep_var_names: Option<HybridRegex>,
   pub context: Option<String>,
   pub tsconfig: Option<String>,
 }
@@ -284,6 +347,30 @@ where
   Ok(deserialized.map(From::from))
 }
 
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_keep_comments<'de, D>(deserializer: D) -> Result<Option<HybridRegex>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<String>::deserialize(deserializer)?;
+  deserialized
+    .map(|inner| HybridRegex::new(&inner))
+    .transpose()
+    .map_err(|e| serde::de::Error::custom(format!("failed to deserialize {e:?} to HybridRegex")))
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_keep_var_names<'de, D>(deserializer: D) -> Result<Option<HybridRegex>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<String>::deserialize(deserializer)?;
+  deserialized
+    .map(|inner| HybridRegex::new(&inner))
+    .transpose()
+    .map_err(|e| serde::de::Error::custom(format!("failed to deserialize {e:?} to HybridRegex")))
+}
+
 #[cfg(feature = "deserialize_bundler_options")]
 fn deserialize_minify<'de, D>(deserializer: D) -> Result<Option<RawMinifyOptions>, D::Error>
 where
@@ -296,6 +383,7 @@ where
     Some(SimpleMinifyOptions::String(value)) if value == "dceOnly" => {
       Ok(Some(RawMinifyOptions::DeadCodeEliminationOnly))
     }
+    Some(SimpleMinifyOptions::Preset(preset)) => Ok(Some(RawMinifyOptions::Preset(preset))),
     None => Ok(None),
     _ => unreachable!("Unexpected value for minify {:?}", deserialized),
   }
@@ -316,6 +404,8 @@ where
         module_side_effects: types::treeshake::ModuleSideEffects::Boolean(true),
         annotations: Some(true),
         manual_pure_functions: None,
+        pure_globals: None,
+        pure_annotations: None,
         unknown_global_side_effects: None,
         commonjs: Some(true),
         property_read_side_effects: None,
@@ -344,15 +434,19 @@ where
           _ => Err(serde::de::Error::custom("commonjs should be a `true` or `false`")),
         },
       )?;
-      let unknown_global_side_effects = obj.get("unknown_global_side_effects").map_or_else(
-        || Ok(Some(true)),
-        |v| match v {
-          Value::Bool(b) => Ok(Some(*b)),
-          _ => Err(serde::de::Error::custom(
-            "unknown_global_side_effects should be a `true` or `false`",
-          )),
-        },
-      )?;
+      // `unknown_global_side_effects` is a deprecated alias kept for existing configs.
+      let unknown_global_side_effects = obj
+        .get("unknownGlobalSideEffects")
+        .or_else(|| obj.get("unknown_global_side_effects"))
+        .map_or_else(
+          || Ok(Some(true)),
+          |v| match v {
+            Value::Bool(b) => Ok(Some(*b)),
+            _ => Err(serde::de::Error::custom(
+              "unknownGlobalSideEffects should be a `true` or `false`",
+            )),
+          },
+        )?;
       let manual_pure_functions = obj.get("manualPureFunctions").map_or_else(
         || Ok(FxHashSet::default()),
         |v| match v {
@@ -366,6 +460,37 @@ where
           _ => Err(serde::de::Error::custom("manualPureFunctions should be a `Vec<String>`")),
         },
       )?;
+      let pure_globals = obj.get("pureGlobals").map_or_else(
+        || Ok(None),
+        |v| match v {
+          Value::Array(v) => v
+            .iter()
+            .map(|item| {
+              item
+                .as_str()
+                .map(ToString::to_string)
+                .ok_or_else(|| serde::de::Error::custom("pureGlobals should be a `Vec<String>`"))
+            })
+            .collect::<Result<FxHashSet<_>, _>>()
+            .map(Some),
+          _ => Err(serde::de::Error::custom("pureGlobals should be a `Vec<String>`")),
+        },
+      )?;
+      let pure_annotations = obj.get("pureAnnotations").map_or_else(
+        || Ok(None),
+        |v| match v {
+          Value::Array(v) => v
+            .iter()
+            .map(|item| {
+              item.as_str().map(ToString::to_string).ok_or_else(|| {
+                serde::de::Error::custom("pureAnnotations should be a `Vec<String>`")
+              })
+            })
+            .collect::<Result<FxHashSet<_>, _>>()
+            .map(Some),
+          _ => Err(serde::de::Error::custom("pureAnnotations should be a `Vec<String>`")),
+        },
+      )?;
       // Use string to make deserialization logic easier
       let property_read_side_effects = obj.get("propertyReadSideEffects").map_or_else(
         || Ok(None),
@@ -399,6 +524,8 @@ where
         module_side_effects,
         annotations,
         manual_pure_functions: Some(manual_pure_functions),
+        pure_globals,
+        pure_annotations,
         unknown_global_side_effects,
         commonjs,
         property_read_side_effects,
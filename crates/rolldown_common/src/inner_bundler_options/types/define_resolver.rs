@@ -0,0 +1,21 @@
+use derive_more::Debug;
+use std::sync::Arc;
+
+type DefineResolverFn = dyn Fn(&str) -> Option<String> + Send + Sync;
+
+/// Computes the replacement of a global identifier that isn't in the static `define` config.
+///
+/// The returned string is parsed as an expression, returning `None` leaves the identifier as is.
+#[derive(Clone, Debug)]
+#[debug("DefineResolver::Fn(...)")]
+pub struct DefineResolver(Arc<DefineResolverFn>);
+
+impl DefineResolver {
+  pub fn new(f: Arc<DefineResolverFn>) -> Self {
+    Self(f)
+  }
+
+  pub fn call(&self, name: &str) -> Option<String> {
+    self.0(name)
+  }
+}
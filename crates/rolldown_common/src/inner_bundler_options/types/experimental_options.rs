@@ -44,6 +44,11 @@ pub struct ExperimentalOptions {
   pub disable_live_bindings: Option<bool>,
   pub vite_mode: Option<bool>,
   pub resolve_new_url_to_asset: Option<bool>,
+  /// A `new URL(...)` with a leading comment containing this text isn't emitted as an asset.
+  /// Defaults to `@url-ignore`.
+  pub new_url_ignore_comment: Option<String>,
+  /// Emit the target of `require.resolve('./path')` as an asset and rewrite the path to it.
+  pub resolve_require_resolve_to_asset: Option<bool>,
   pub incremental_build: Option<bool>,
   pub hmr: Option<HmrOptions>,
   pub attach_debug_info: Option<AttachDebugInfo>,
@@ -51,6 +56,13 @@ pub struct ExperimentalOptions {
   pub chunk_modules_order: Option<ChunkModulesOrderBy>,
   pub on_demand_wrapping: Option<bool>,
   pub transform_hires_sourcemap: Option<SourcemapHires>,
+  /// Report semantic errors of modules, e.g. a redeclared lexical binding, as warnings instead of
+  /// failing the build. The output of such modules might be broken.
+  pub semantic_errors_as_warnings: Option<bool>,
+  /// Keep the parentheses of the source when tree shaking without minification. The dead code
+  /// elimination of the compressor is replaced by a lighter pass that only removes `if` branches
+  /// with a constant test.
+  pub preserve_parens: Option<bool>,
 }
 
 impl ExperimentalOptions {
@@ -70,6 +82,22 @@ impl ExperimentalOptions {
     self.resolve_new_url_to_asset.unwrap_or(false)
   }
 
+  pub fn new_url_ignore_comment(&self) -> &str {
+    self.new_url_ignore_comment.as_deref().unwrap_or("@url-ignore")
+  }
+
+  pub fn is_resolve_require_resolve_to_asset_enabled(&self) -> bool {
+    self.resolve_require_resolve_to_asset.unwrap_or(false)
+  }
+
+  pub fn is_semantic_errors_as_warnings_enabled(&self) -> bool {
+    self.semantic_errors_as_warnings.unwrap_or(false)
+  }
+
+  pub fn is_preserve_parens_enabled(&self) -> bool {
+    self.preserve_parens.unwrap_or(false)
+  }
+
   #[inline]
   pub fn is_incremental_build_enabled(&self) -> bool {
     self.incremental_build.unwrap_or(false)
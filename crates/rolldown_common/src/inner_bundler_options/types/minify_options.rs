@@ -12,9 +12,24 @@ use serde::Deserialize;
 pub enum RawMinifyOptions {
   Bool(bool),
   DeadCodeEliminationOnly,
+  /// `minify: true` with some of the sub-options overridden.
+  Preset(MinifyPresetOptions),
   Object((oxc::minifier::MinifierOptions, bool)),
 }
 
+/// Sub-options of the `minify: true` preset. Sub-options left as `None` are enabled.
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MinifyPresetOptions {
+  pub compress: Option<bool>,
+  pub mangle: Option<bool>,
+  pub remove_whitespace: Option<bool>,
+}
+
 impl RawMinifyOptions {
   /// Returns `true` if the minify options is [`Enabled`].
   ///
@@ -28,34 +43,36 @@ impl RawMinifyOptions {
 impl RawMinifyOptions {
   pub fn normalize(self, options: &NormalizedBundlerOptions) -> MinifyOptions {
     match self {
-      RawMinifyOptions::Bool(value) => {
-        if value {
-          let keep_names = options.keep_names;
-          let mangle = MangleOptions {
-            // IIFE need to preserve top level names
-            top_level: !matches!(options.format, OutputFormat::Iife),
-            keep_names: MangleOptionsKeepNames { function: keep_names, class: keep_names },
-            debug: false,
-          };
-
-          let compress = CompressOptions {
-            target: options.transform_options.target.clone(),
-            keep_names: CompressOptionsKeepNames { function: keep_names, class: keep_names },
-            treeshake: TreeShakeOptions::from(&options.treeshake),
-            ..CompressOptions::smallest()
-          };
-          MinifyOptions::Enabled((
-            oxc::minifier::MinifierOptions { mangle: Some(mangle), compress: Some(compress) },
-            true,
-          ))
-        } else {
-          MinifyOptions::Disabled
-        }
-      }
+      RawMinifyOptions::Bool(true) => MinifyPresetOptions::default().normalize(options),
+      RawMinifyOptions::Bool(false) => MinifyOptions::Disabled,
+      RawMinifyOptions::Preset(preset) => preset.normalize(options),
       RawMinifyOptions::DeadCodeEliminationOnly => MinifyOptions::DeadCodeEliminationOnly,
       RawMinifyOptions::Object(value) => MinifyOptions::Enabled(value),
     }
-    //
+  }
+}
+
+impl MinifyPresetOptions {
+  pub fn normalize(self, options: &NormalizedBundlerOptions) -> MinifyOptions {
+    let keep_names = options.keep_names;
+    let mangle = self.mangle.unwrap_or(true).then(|| MangleOptions {
+      // IIFE need to preserve top level names
+      top_level: !matches!(options.format, OutputFormat::Iife),
+      keep_names: MangleOptionsKeepNames { function: keep_names, class: keep_names },
+      debug: false,
+    });
+
+    let compress = self.compress.unwrap_or(true).then(|| CompressOptions {
+      target: options.transform_options.target.clone(),
+      keep_names: CompressOptionsKeepNames { function: keep_names, class: keep_names },
+      treeshake: TreeShakeOptions::from(&options.treeshake),
+      ..CompressOptions::smallest()
+    });
+
+    MinifyOptions::Enabled((
+      oxc::minifier::MinifierOptions { mangle, compress },
+      self.remove_whitespace.unwrap_or(true),
+    ))
   }
 }
 
@@ -89,7 +106,7 @@ impl MinifyOptions {
   }
 }
 
-/// A simple minify option that can be either a boolean or a string, used for rolldown rust testing.
+/// A simple minify option that can be either a boolean, a string or the preset sub-options, used for rolldown rust testing.
 #[cfg(feature = "deserialize_bundler_options")]
 #[cfg_attr(
   feature = "deserialize_bundler_options",
@@ -100,4 +117,5 @@ impl MinifyOptions {
 pub enum SimpleMinifyOptions {
   Boolean(bool),
   String(String),
+  Preset(MinifyPresetOptions),
 }
@@ -4,6 +4,7 @@ pub mod chunk_import_map;
 pub mod chunk_modules_order;
 pub mod debug_options;
 pub mod defer_sync_scan_data_option;
+pub mod define_resolver;
 pub mod es_module_flag;
 pub mod experimental_options;
 pub mod filename_template;
@@ -26,6 +27,7 @@ pub mod output_exports;
 pub mod output_format;
 pub mod output_option;
 pub mod platform;
+pub mod require_esm_interop;
 pub mod resolve_options;
 pub mod sanitize_filename;
 pub mod source_map_type;
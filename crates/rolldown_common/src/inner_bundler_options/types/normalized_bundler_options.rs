@@ -8,6 +8,8 @@ use std::sync::Arc;
 use arcstr::ArcStr;
 use oxc::transformer_plugins::InjectGlobalVariablesConfig;
 use rolldown_error::EventKindSwitcher;
+use rolldown_utils::indexmap::FxIndexMap;
+use rolldown_utils::js_regex::HybridRegex;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::advanced_chunks_options::AdvancedChunksOptions;
@@ -18,6 +20,7 @@ use super::minify_options::MinifyOptions;
 use super::output_option::{
   AssetFilenamesOutputOption, ChunkFilenamesOutputOption, PreserveEntrySignatures,
 };
+use super::require_esm_interop::RequireEsmInterop;
 use super::sanitize_filename::SanitizeFilename;
 use super::treeshake::NormalizedTreeshakeOptions;
 use super::watch_option::WatchOption;
@@ -28,8 +31,8 @@ use super::{
 };
 use crate::inner_bundler_options::types::optimization::NormalizedOptimizationConfig;
 use crate::{
-  DeferSyncScanDataOption, EmittedAsset, EsModuleFlag, FilenameTemplate, GlobalsOutputOption,
-  HashCharacters, InjectImport, InputItem, InvalidateJsSideCache, LogLevel,
+  DeferSyncScanDataOption, DefineResolver, EmittedAsset, EsModuleFlag, FilenameTemplate,
+  GlobalsOutputOption, HashCharacters, InjectImport, InputItem, InvalidateJsSideCache, LogLevel,
   MakeAbsoluteExternalsRelative, ModuleType, OnLog, RollupPreRenderedAsset, TransformOptions,
 };
 
@@ -77,7 +80,13 @@ pub struct NormalizedBundlerOptions {
   pub experimental: ExperimentalOptions,
   pub minify: MinifyOptions,
   pub extend: bool,
+  /// Assign the `name` of IIFE output to `globalThis`, or `window` for the `browser` platform.
+  pub global_this: bool,
+  /// Insert a `;` before a module that starts with `(`, `[` or `` ` ``.
+  pub defensive_semicolons: bool,
   pub define: Vec<(/* Target to be replaced */ String, /* Replacement */ String)>,
+  pub define_resolver: Option<DefineResolver>,
+  pub import_meta_env: FxIndexMap<String, String>,
   pub keep_names: bool,
   pub inject: Vec<InjectImport>,
   pub oxc_inject_global_variables_config: InjectGlobalVariablesConfig,
@@ -89,6 +98,10 @@ pub struct NormalizedBundlerOptions {
   pub watch: WatchOption,
   pub legal_comments: LegalComments,
   pub drop_labels: FxHashSet<String>,
+  /// Remove `console.*(...)` expression statements.
+  pub drop_console: bool,
+  /// Remove `debugger` statements.
+  pub drop_debugger: bool,
   pub polyfill_require: bool,
   pub defer_sync_scan_data: Option<DeferSyncScanDataOption>,
   pub transform_options: Box<TransformOptions>,
@@ -104,6 +117,17 @@ pub struct NormalizedBundlerOptions {
   pub optimization: NormalizedOptimizationConfig,
   pub top_level_var: bool,
   pub minify_internal_exports: bool,
+  pub namespace_to_string_tag: bool,
+  pub require_esm_interop: RequireEsmInterop,
+  pub virtual_prefixes: Vec<String>,
+  pub side_effect_entries: Vec<String>,
+  pub isolated_modules: bool,
+  pub validate: bool,
+  pub exports_manifest: Option<String>,
+  pub integrity: bool,
+  pub top_level_await: bool,
+  pub keep_comments: Option<HybridRegex>,
+  pub keep_var_names: Option<HybridRegex>,
   pub context: String,
   pub tsconfig: Option<PathBuf>,
 }
@@ -148,7 +172,11 @@ impl Default for NormalizedBundlerOptions {
       experimental: Default::default(),
       minify: MinifyOptions::Disabled,
       extend: Default::default(),
+      global_this: Default::default(),
+      defensive_semicolons: Default::default(),
       define: Default::default(),
+      define_resolver: Default::default(),
+      import_meta_env: Default::default(),
       keep_names: Default::default(),
       inject: Default::default(),
       oxc_inject_global_variables_config: InjectGlobalVariablesConfig::new(vec![]),
@@ -160,6 +188,8 @@ impl Default for NormalizedBundlerOptions {
       watch: Default::default(),
       legal_comments: LegalComments::None,
       drop_labels: Default::default(),
+      drop_console: Default::default(),
+      drop_debugger: Default::default(),
       polyfill_require: Default::default(),
       defer_sync_scan_data: Default::default(),
       transform_options: Default::default(),
@@ -175,6 +205,17 @@ impl Default for NormalizedBundlerOptions {
       optimization: NormalizedOptimizationConfig::default(),
       top_level_var: false,
       minify_internal_exports: Default::default(),
+      namespace_to_string_tag: false,
+      require_esm_interop: RequireEsmInterop::default(),
+      virtual_prefixes: Vec::new(),
+      side_effect_entries: Vec::new(),
+      isolated_modules: false,
+      validate: false,
+      exports_manifest: None,
+      integrity: false,
+      top_level_await: false,
+      keep_comments: None,
+      keep_var_names: None,
       context: Default::default(),
       tsconfig: Default::default(),
     }
@@ -196,6 +237,16 @@ impl NormalizedBundlerOptions {
     self.experimental.hmr.is_some()
   }
 
+  /// Whether modules may use top-level await with the current output format
+  #[inline]
+  pub fn supports_top_level_await(&self) -> bool {
+    match self.format {
+      OutputFormat::Esm => true,
+      OutputFormat::Cjs => self.top_level_await,
+      OutputFormat::Iife | OutputFormat::Umd => false,
+    }
+  }
+
   /// make sure the `polyfill_require` is only valid for `esm` format with `node` platform
   #[inline]
   pub fn polyfill_require_for_esm_format_with_node_platform(&self) -> bool {
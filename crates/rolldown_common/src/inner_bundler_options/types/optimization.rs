@@ -95,8 +95,18 @@ pub struct OptimizationOption {
   /// This improves the initial execution performance.
   /// See <https://v8.dev/blog/preparser#pife> for more details about the optimization.
   pub pife_for_module_wrappers: Option<bool>,
+  /// Drop the reverse mappings (`Enum[Enum["A"] = 0] = "A"`) of TypeScript numeric enums that are
+  /// never accessed by a computed key, e.g. `Enum[0]` or `Enum[value]`.
+  /// Enums that are exported or otherwise escape the module are always kept as they are.
+  pub trim_enum_reverse_mappings: Option<bool>,
+  /// The longest static property chain on an import, e.g. `ns.a.b`, that is tracked as a single
+  /// member expression access so unused exports can be tree shaken. The import is referenced as a
+  /// whole for longer chains. Defaults to `64`.
+  pub max_member_expr_chain_length: Option<u32>,
 }
 
+pub const DEFAULT_MAX_MEMBER_EXPR_CHAIN_LENGTH: usize = 64;
+
 pub fn normalize_optimization_option(
   option: Option<OptimizationOption>,
   platform: Platform,
@@ -119,6 +129,10 @@ pub fn normalize_optimization_option(
     pife_for_module_wrappers: option
       .pife_for_module_wrappers
       .unwrap_or(!matches!(platform, Platform::Neutral)),
+    trim_enum_reverse_mappings: option.trim_enum_reverse_mappings.unwrap_or(false),
+    max_member_expr_chain_length: option
+      .max_member_expr_chain_length
+      .map_or(DEFAULT_MAX_MEMBER_EXPR_CHAIN_LENGTH, |len| len as usize),
   }
 }
 
@@ -144,10 +158,23 @@ where
   }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct NormalizedOptimizationConfig {
   pub inline_const: Option<NormalizedInlineConstConfig>,
   pub pife_for_module_wrappers: bool,
+  pub trim_enum_reverse_mappings: bool,
+  pub max_member_expr_chain_length: usize,
+}
+
+impl Default for NormalizedOptimizationConfig {
+  fn default() -> Self {
+    Self {
+      inline_const: None,
+      pife_for_module_wrappers: false,
+      trim_enum_reverse_mappings: false,
+      max_member_expr_chain_length: DEFAULT_MAX_MEMBER_EXPR_CHAIN_LENGTH,
+    }
+  }
 }
 
 #[derive(Debug, Clone, Default, Copy)]
@@ -176,4 +203,14 @@ impl NormalizedOptimizationConfig {
   pub fn is_pife_for_module_wrappers_enabled(&self) -> bool {
     self.pife_for_module_wrappers
   }
+
+  #[inline]
+  pub fn is_trim_enum_reverse_mappings_enabled(&self) -> bool {
+    self.trim_enum_reverse_mappings
+  }
+
+  #[inline]
+  pub fn max_member_expr_chain_length(&self) -> usize {
+    self.max_member_expr_chain_length
+  }
 }
@@ -0,0 +1,22 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// How to handle `require()` calls that target an ES module using top-level await, which can't
+/// be loaded synchronously.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "kebab-case", deny_unknown_fields)
+)]
+pub enum RequireEsmInterop {
+  /// Fail the build, like Node.js does with `ERR_REQUIRE_ASYNC_MODULE`
+  #[default]
+  Error,
+  /// Emit a warning and keep the synchronous `require()`, which may observe uninitialized exports
+  Warn,
+  /// Rewrite the `require()` to return a promise that resolves to the module's exports
+  Wrap,
+}
@@ -94,6 +94,14 @@ impl NormalizedTreeshakeOptions {
   pub fn manual_pure_functions(&self) -> Option<&FxHashSet<String>> {
     self.as_ref().and_then(|item| item.manual_pure_functions.as_ref())
   }
+
+  pub fn pure_globals(&self) -> Option<&FxHashSet<String>> {
+    self.as_ref().and_then(|item| item.pure_globals.as_ref())
+  }
+
+  pub fn pure_annotations(&self) -> Option<&FxHashSet<String>> {
+    self.as_ref().and_then(|item| item.pure_annotations.as_ref())
+  }
 }
 
 impl Default for TreeshakeOptions {
@@ -201,7 +209,15 @@ pub struct InnerOptions {
   )]
   pub module_side_effects: ModuleSideEffects,
   pub annotations: Option<bool>,
+  /// Functions whose calls are side effect free. A name like `styled` covers `styled.div()` and
+  /// `styled()()`, a member path like `console.log` covers that member and the ones below it.
   pub manual_pure_functions: Option<FxHashSet<String>>,
+  /// Global member paths whose reads are side effect free, e.g. `document` or `document.cookie`.
+  /// An entry also covers the members below it.
+  pub pure_globals: Option<FxHashSet<String>>,
+  /// Extra comments that mark a call or `new` expression as pure, in addition to `#__PURE__` and
+  /// `@__PURE__`, e.g. `#PURE#` for `/*#PURE#*/ foo()`. Compared with the trimmed comment text.
+  pub pure_annotations: Option<FxHashSet<String>>,
   pub unknown_global_side_effects: Option<bool>,
   pub commonjs: Option<bool>,
   pub property_read_side_effects: Option<PropertyReadSideEffects>,
@@ -214,6 +230,8 @@ impl Default for InnerOptions {
       module_side_effects: ModuleSideEffects::Boolean(true),
       annotations: Some(true),
       manual_pure_functions: None,
+      pure_globals: None,
+      pure_annotations: None,
       unknown_global_side_effects: None,
       commonjs: None,
       property_read_side_effects: None,
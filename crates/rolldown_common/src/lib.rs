@@ -34,6 +34,7 @@ pub mod bundler_options {
       chunk_modules_order::ChunkModulesOrderBy,
       debug_options::DebugOptions,
       defer_sync_scan_data_option::DeferSyncScanDataOption,
+      define_resolver::DefineResolver,
       es_module_flag::EsModuleFlag,
       experimental_options::{ExperimentalOptions, SourcemapHires},
       filename_template::FilenameTemplate,
@@ -47,12 +48,13 @@ pub mod bundler_options {
       legal_comments::LegalComments,
       log_level::LogLevel,
       make_absolute_externals_relative::MakeAbsoluteExternalsRelative,
-      minify_options::{MinifyOptions, RawMinifyOptions},
+      minify_options::{MinifyOptions, MinifyPresetOptions, RawMinifyOptions},
       module_type::ModuleType,
       normalized_bundler_options::{NormalizedBundlerOptions, SharedNormalizedBundlerOptions},
       on_log::{Log, LogWithoutPlugin, OnLog},
       optimization::{
-        InlineConstConfig, InlineConstMode, InlineConstOption, OptimizationOption,
+        DEFAULT_MAX_MEMBER_EXPR_CHAIN_LENGTH, InlineConstConfig, InlineConstMode,
+        InlineConstOption, NormalizedOptimizationConfig, OptimizationOption,
         normalize_optimization_option,
       },
       output_exports::OutputExports,
@@ -62,6 +64,7 @@ pub mod bundler_options {
         GlobalsOutputOption, PreserveEntrySignatures,
       },
       platform::Platform,
+      require_esm_interop::RequireEsmInterop,
       resolve_options::ResolveOptions,
       sanitize_filename::SanitizeFilename,
       source_map_type::SourceMapType,
@@ -207,6 +207,8 @@ impl NormalModule {
             sourcemap: enable_sourcemap,
             filename: self.id.to_string(),
             print_legal_comments,
+            // Normal comments that don't match `keepComments` are already dropped by the finalizer.
+            print_normal_comments: options.keep_comments.is_some(),
             initial_indent,
           },
         );
@@ -30,6 +30,12 @@ bitflags! {
     /// If set, inline const optimization is enabled.
     /// Usage: `self.options.optimization.is_inline_const_enabled()`
     const InlineConstEnabled = 1 << 9;
+    /// If set, modules may use top-level await with the output format.
+    /// Usage: `self.options.supports_top_level_await()`
+    const SupportsTopLevelAwait = 1 << 10;
+    /// If set, `require.resolve('path')` calls should be resolved to assets.
+    /// Usage: `self.options.experimental.is_resolve_require_resolve_to_asset_enabled()`
+    const ResolveRequireResolveToAssetEnabled = 1 << 11;
   }
 }
 
@@ -64,7 +70,12 @@ impl FlatOptions {
       Self::ResolveNewUrlToAssetEnabled,
       options.experimental.is_resolve_new_url_to_asset_enabled(),
     );
+    flags.set(
+      Self::ResolveRequireResolveToAssetEnabled,
+      options.experimental.is_resolve_require_resolve_to_asset_enabled(),
+    );
     flags.set(Self::InlineConstEnabled, options.optimization.is_inline_const_enabled());
+    flags.set(Self::SupportsTopLevelAwait, options.supports_top_level_await());
     flags
   }
 
@@ -113,8 +124,18 @@ impl FlatOptions {
     self.contains(Self::ResolveNewUrlToAssetEnabled)
   }
 
+  #[inline]
+  pub fn resolve_require_resolve_to_asset_enabled(self) -> bool {
+    self.contains(Self::ResolveRequireResolveToAssetEnabled)
+  }
+
   #[inline]
   pub fn inline_const_enabled(self) -> bool {
     self.contains(Self::InlineConstEnabled)
   }
+
+  #[inline]
+  pub fn supports_top_level_await(self) -> bool {
+    self.contains(Self::SupportsTopLevelAwait)
+  }
 }
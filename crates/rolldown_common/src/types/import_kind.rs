@@ -8,6 +8,8 @@ pub enum ImportKind {
   DynamicImport,
   /// `require('foo')`
   Require,
+  /// `require.resolve('foo')`
+  RequireResolve,
   AtImport,
   /// css url import, e.g. `url(foo.png)`
   UrlImport,
@@ -20,7 +22,15 @@ pub enum ImportKind {
 impl ImportKind {
   #[inline]
   pub fn is_static(&self) -> bool {
-    matches!(self, Self::Import | Self::Require | Self::AtImport | Self::UrlImport | Self::NewUrl)
+    matches!(
+      self,
+      Self::Import
+        | Self::Require
+        | Self::RequireResolve
+        | Self::AtImport
+        | Self::UrlImport
+        | Self::NewUrl
+    )
   }
   #[inline]
   pub fn is_dynamic(&self) -> bool {
@@ -36,6 +46,7 @@ impl TryFrom<&str> for ImportKind {
       "import-statement" => Ok(Self::Import),
       "dynamic-import" => Ok(Self::DynamicImport),
       "require-call" => Ok(Self::Require),
+      "require-resolve" => Ok(Self::RequireResolve),
       "import-rule" => Ok(Self::AtImport),
       "url-import" => Ok(Self::UrlImport),
       _ => Err(format!("Invalid import kind: {value:?}")),
@@ -50,6 +61,7 @@ impl Display for ImportKind {
       Self::Import => write!(f, "import-statement"),
       Self::DynamicImport => write!(f, "dynamic-import"),
       Self::Require => write!(f, "require-call"),
+      Self::RequireResolve => write!(f, "require-resolve"),
       // TODO(hyf0): check if this literal is the same as esbuild's
       Self::AtImport => write!(f, "import-rule"),
       ImportKind::UrlImport => write!(f, "url-token"),
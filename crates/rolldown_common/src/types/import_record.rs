@@ -16,6 +16,9 @@ pub struct ImportRecordStateInit {
   pub span: Span,
   /// The importee of this import record is asserted to be this specific module type.
   pub asserted_module_type: Option<ModuleType>,
+  /// Spans of the sources of later import declarations merged into this record, e.g. the second
+  /// `'./m'` in `import { a } from './m'; import { b } from './m'`.
+  pub merged_spans: Vec<Span>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -25,7 +28,7 @@ pub struct ImportRecordStateResolved {
 
 bitflags::bitflags! {
   #[derive(Debug, Clone, Copy)]
-  pub struct ImportRecordMeta: u16 {
+  pub struct ImportRecordMeta: u32 {
     /// If it is `import {} from '...'` or `import '...'`
     const IsPlainImport = 1;
     /// the import is inserted during ast transformation, can't get source slice from the original source file
@@ -56,6 +59,19 @@ bitflags::bitflags! {
     /// If a record is a re-export-all from an external module, and that re-export-all chain continues uninterrupted to the entry point,
     /// we can reuse the original re-export-all declaration instead of generating complex interoperability code.
     const EntryLevelExternal = 1 << 11;
+    /// The module request is a bare specifier, e.g. `react` or `lodash/fp`, see `rolldown_utils::ecmascript::is_bare_specifier`
+    const IsBareSpecifier = 1 << 12;
+    /// The `require('mod')` call is the whole right hand side of `module.exports = require('mod')`,
+    /// which makes the module a passthrough of `mod`
+    const IsCjsReexportTarget = 1 << 13;
+    /// The `import('mod')` is immediately awaited and assigned in a top level variable declaration,
+    /// e.g. `const m = await import('mod')`. It behaves like a static import and is a candidate for
+    /// hoisting.
+    const IsHoistableDynamicImport = 1 << 14;
+    /// The module request starts with one of `virtualPrefixes`, so it can only be resolved by a plugin
+    const IsVirtualModuleRequest = 1 << 15;
+    /// The module request ends with `.node`, a native addon that can't be bundled and must be external
+    const IsNativeAddon = 1 << 16;
 
     const TopLevelPureDynamicImport = Self::IsTopLevel.bits() | Self::PureDynamicImport.bits();
   }
@@ -110,7 +126,11 @@ impl RawImportRecord {
       kind,
       namespace_ref,
       meta: ImportRecordMeta::empty(),
-      state: ImportRecordStateInit { span, asserted_module_type: assert_module_type },
+      state: ImportRecordStateInit {
+        span,
+        asserted_module_type: assert_module_type,
+        merged_spans: vec![],
+      },
       related_stmt_info_idx,
     }
   }
@@ -1,7 +1,9 @@
 use std::ops::{Deref, DerefMut};
 
-use crate::{Module, ModuleIdx};
+use crate::{ImportRecordMeta, Module, ModuleIdx};
+use oxc::span::CompactStr;
 use oxc_index::IndexVec;
+use rolldown_utils::indexmap::FxIndexSet;
 
 pub type IndexModules = IndexVec<ModuleIdx, Module>;
 
@@ -10,6 +12,20 @@ pub struct ModuleTable {
   pub modules: IndexModules,
 }
 
+impl ModuleTable {
+  /// Deduplicated bare specifiers (e.g. `react`, `lodash/fp`) requested by all modules, in
+  /// the order they are first seen. Useful for generating a `dependencies` list.
+  pub fn bare_specifiers(&self) -> FxIndexSet<CompactStr> {
+    self
+      .modules
+      .iter()
+      .flat_map(|module| module.import_records().iter())
+      .filter(|rec| rec.meta.contains(ImportRecordMeta::IsBareSpecifier))
+      .map(|rec| rec.module_request.clone())
+      .collect()
+  }
+}
+
 impl Deref for ModuleTable {
   type Target = IndexModules;
 
@@ -23,3 +39,62 @@ impl DerefMut for ModuleTable {
     &mut self.modules
   }
 }
+
+#[test]
+fn test_bare_specifiers() {
+  use crate::{
+    ExternalModule, ImportKind, RawImportRecord, SymbolRef, side_effects::DeterminedSideEffects,
+  };
+  use oxc::{semantic::SymbolId, span::Span};
+  use rolldown_utils::ecmascript::is_bare_specifier;
+
+  // Mimic the scanner, which flags bare specifiers on the import records of every module
+  let module_with_requests = |idx: usize, requests: &[&str]| {
+    let idx = ModuleIdx::new(idx);
+    let namespace_ref = SymbolRef::from((idx, SymbolId::new(0)));
+    let mut module = ExternalModule::new(
+      idx,
+      "external".into(),
+      "external".into(),
+      "external".into(),
+      DeterminedSideEffects::NoTreeshake,
+      namespace_ref,
+      false,
+    );
+    module.import_records = requests
+      .iter()
+      .map(|request| {
+        let meta = if is_bare_specifier(request) {
+          ImportRecordMeta::IsBareSpecifier
+        } else {
+          ImportRecordMeta::empty()
+        };
+        RawImportRecord::new(
+          (*request).into(),
+          ImportKind::Import,
+          namespace_ref,
+          Span::default(),
+          None,
+          None,
+        )
+        .with_meta(meta)
+        .into_resolved(idx)
+      })
+      .collect();
+    Module::External(Box::new(module))
+  };
+
+  let table = ModuleTable {
+    modules: [
+      module_with_requests(0, &["react", "./local"]),
+      module_with_requests(1, &["lodash/fp", "react"]),
+    ]
+    .into_iter()
+    .collect(),
+  };
+
+  assert_eq!(
+    table.bare_specifiers().into_iter().collect::<Vec<_>>(),
+    vec![CompactStr::new("react"), CompactStr::new("lodash/fp")]
+  );
+}
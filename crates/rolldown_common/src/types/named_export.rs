@@ -14,4 +14,10 @@ pub struct LocalExport {
   /// exports.foo = 1;
   /// ```
   pub came_from_commonjs: bool,
+  /// `true` if every assignment of the commonjs export is in a conditional control flow, so the
+  /// export may be absent at runtime
+  /// ```js
+  /// if (cond) exports.foo = 1;
+  /// ```
+  pub is_conditional: bool,
 }
@@ -28,6 +28,8 @@ pub struct OutputChunk {
   pub map: Option<SourceMap>,
   pub sourcemap_filename: Option<String>,
   pub preliminary_filename: String,
+  /// The `sha384` subresource integrity hash of `code`, computed when `output.integrity` is enabled.
+  pub integrity: Option<String>,
 }
 
 #[derive(Debug, Clone)]
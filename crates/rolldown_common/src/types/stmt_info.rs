@@ -1,4 +1,5 @@
 use bitflags::bitflags;
+use oxc::span::Span;
 use oxc_index::IndexVec;
 use rustc_hash::FxHashMap;
 
@@ -89,6 +90,21 @@ impl StmtInfos {
     self.infos.iter_enumerated().skip(1)
   }
 
+  /// The top level statements that are side-effect-free, as the index of their `StmtInfo` and a
+  /// label. The label is the source of the statement in debug builds and its span, e.g. `12..34`,
+  /// in release builds.
+  ///
+  /// `stmt_spans[i]` is the span of the statement of `infos[i + 1]`. Statements generated after
+  /// scanning are appended after the original ones and don't have a span, so they are skipped.
+  pub fn side_effect_free_stmts(&self, stmt_spans: &[Span]) -> Vec<(StmtInfoIdx, String)> {
+    self
+      .iter_enumerated_without_namespace_stmt()
+      .zip(stmt_spans)
+      .filter(|((_, stmt_info), _)| !stmt_info.side_effect.has_side_effect())
+      .map(|((idx, stmt_info), span)| (idx, stmt_label(stmt_info, *span)))
+      .collect()
+  }
+
   pub fn symbol_ref_to_referenced_stmt_idx(&self) -> &FxHashMap<SymbolRef, Vec<StmtInfoIdx>> {
     &self.symbol_ref_to_referenced_stmt_idx
   }
@@ -126,6 +142,10 @@ bitflags! {
         const HasDummyRecord = 1 << 4;
         /// see `has_dynamic_exports` in https://github.com/rolldown/rolldown/blob/8bc7dca5a09047b6b494e3fa7b6b7564aa465372/crates/rolldown/src/types/linking_metadata.rs?plain=1#L49
         const ReExportDynamicExports = 1 << 5;
+        /// The statement contains a direct `eval(...)` call, which could access any top level
+        /// binding of the module once the statement is executed. Indirect calls like `(0, eval)(...)`
+        /// only see the global scope and don't set this flag.
+        const HasEval = 1 << 6;
         const KeepNamesType = StmtInfoMeta::FnDecl.bits() | StmtInfoMeta::ClassDecl.bits() | StmtInfoMeta::FnExpr.bits() | StmtInfoMeta::ClassExpr.bits();
     }
 }
@@ -189,6 +209,16 @@ impl StmtInfo {
   }
 }
 
+#[cfg(debug_assertions)]
+fn stmt_label(stmt_info: &StmtInfo, _span: Span) -> String {
+  stmt_info.unwrap_debug_label().to_string()
+}
+
+#[cfg(not(debug_assertions))]
+fn stmt_label(_stmt_info: &StmtInfo, span: Span) -> String {
+  format!("{}..{}", span.start, span.end)
+}
+
 #[derive(Debug)]
 pub struct DebugStmtInfoForTreeShaking {
   pub is_included: bool,
@@ -23,12 +23,13 @@ pub struct ModuleImport {
   /// - `import-statement`: `import { foo } from './lib.js';`
   /// - `dynamic-import`: `import('./lib.js')`
   /// - `require-call`: `require('./lib.js')`
+  /// - `require-resolve`: `require.resolve('./lib.js')`
   /// - `import-rule`: `@import 'bg-color.css'`
   /// - `url-token`: `url('./icon.png')`
   /// - `new-url`: `new URL('./worker.js', import.meta.url)`
   /// - `hot-accept`: `import.meta.hot.accept('./lib.js', () => {})`
   #[ts(
-    type = "'import-statement' | 'dynamic-import' | 'require-call' | 'import-rule' | 'url-token' | 'new-url' | 'hot-accept'"
+    type = "'import-statement' | 'dynamic-import' | 'require-call' | 'require-resolve' | 'import-rule' | 'url-token' | 'new-url' | 'hot-accept'"
   )]
   pub kind: String,
   /// `./lib.js` in `import { foo } from './lib.js';`
@@ -3,14 +3,16 @@ use std::path::PathBuf;
 use arcstr::ArcStr;
 use oxc::{
   allocator::Allocator,
-  ast::AstBuilder,
+  ast::{AstBuilder, CommentContent},
   codegen::{Codegen, CodegenOptions, CodegenReturn, CommentOptions, LegalComment},
   minifier::{Minifier, MinifierOptions},
   parser::{ParseOptions, Parser},
+  semantic::Scoping,
   span::{SPAN, SourceType},
 };
 use oxc_sourcemap::SourceMap;
 use rolldown_error::{BuildDiagnostic, BuildResult, Severity};
+use rustc_hash::FxHashSet;
 
 use crate::ecma_ast::{
   EcmaAst,
@@ -85,7 +87,7 @@ impl EcmaCompiler {
     Codegen::new()
       .with_options(CodegenOptions {
         comments: CommentOptions {
-          normal: false,
+          normal: options.print_normal_comments,
           legal,
           // These option will be configurable when we begin to support `ignore-annotations`
           // https://esbuild.github.io/api/#ignore-annotations
@@ -109,14 +111,26 @@ impl EcmaCompiler {
     compress: bool,
     minify_options: MinifierOptions,
     codegen_options: CodegenOptions,
+    keep_comment: Option<&dyn Fn(&str) -> bool>,
+    keep_var_name: Option<&dyn Fn(&str) -> bool>,
   ) -> (String, Option<SourceMap>) {
     let mut program = Parser::new(allocator, source_text, source_type).parse().program;
+    if let Some(keep_comment) = keep_comment {
+      // Only normal comments are filtered here, the others are controlled by `codegen_options`.
+      program.comments.retain(|comment| {
+        !matches!(comment.content, CommentContent::None)
+          || keep_comment(comment.span.source_text(source_text))
+      });
+    }
     let minifier = Minifier::new(minify_options);
-    let ret = if compress {
+    let mut ret = if compress {
       minifier.minify(allocator, &mut program)
     } else {
       minifier.dce(allocator, &mut program)
     };
+    if let (Some(keep_var_name), Some(scoping)) = (keep_var_name, ret.scoping.as_mut()) {
+      restore_var_names(scoping, source_text, keep_var_name);
+    }
     let ret = Codegen::new()
       .with_options(CodegenOptions {
         source_map_path: enable_sourcemap.then(|| PathBuf::from(filename)),
@@ -128,6 +142,32 @@ impl EcmaCompiler {
   }
 }
 
+/// Give the symbols whose original names match `keep_var_name` their names back after mangling.
+/// The original name is read from the declaration in `source_text`, symbols created by the
+/// minifier don't have one. A name that is taken by another symbol after mangling is left alone.
+fn restore_var_names(
+  scoping: &mut Scoping,
+  source_text: &str,
+  keep_var_name: &dyn Fn(&str) -> bool,
+) {
+  let mut kept = vec![];
+  let mut used_names = FxHashSet::default();
+  for symbol_id in scoping.symbol_ids() {
+    let span = scoping.symbol_span(symbol_id);
+    let original_name = if span.is_unspanned() { "" } else { span.source_text(source_text) };
+    if !original_name.is_empty() && keep_var_name(original_name) {
+      kept.push((symbol_id, original_name));
+    } else {
+      used_names.insert(scoping.symbol_name(symbol_id).to_string());
+    }
+  }
+  for (symbol_id, original_name) in kept {
+    if !used_names.contains(original_name) {
+      scoping.set_symbol_name(symbol_id, original_name);
+    }
+  }
+}
+
 #[test]
 fn basic_test() {
   let ast = EcmaCompiler::parse("", "const a = 1;".to_string(), SourceType::default()).unwrap();
@@ -138,7 +178,29 @@ fn basic_test() {
 
 pub struct PrintOptions {
   pub print_legal_comments: bool,
+  pub print_normal_comments: bool,
   pub filename: String,
   pub sourcemap: bool,
   pub initial_indent: u32,
 }
+
+#[test]
+fn keep_var_names_when_mangling() {
+  let allocator = Allocator::default();
+  let source =
+    "export function foo(__DEBUG_value, other) {\n  console.log(__DEBUG_value, other);\n}\n";
+  let (code, _) = EcmaCompiler::dce_or_minify(
+    &allocator,
+    source,
+    SourceType::mjs(),
+    false,
+    "main.js",
+    true,
+    MinifierOptions::default(),
+    CodegenOptions::default(),
+    None,
+    Some(&|name: &str| name.starts_with("__DEBUG_")),
+  );
+  assert!(code.contains("console.log(__DEBUG_value,"), "{code}");
+  assert!(!code.contains("other"), "{code}");
+}
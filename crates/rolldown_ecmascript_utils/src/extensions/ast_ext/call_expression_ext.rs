@@ -3,16 +3,30 @@ use rolldown_common::AstScopes;
 
 pub trait CallExpressionExt<'ast> {
   fn is_global_require_call(&self, scope: &AstScopes) -> bool;
+  /// `require.resolve(...)` with a global `require`
+  fn is_global_require_resolve_call(&self, scope: &AstScopes) -> bool;
 }
 
 impl<'ast> CallExpressionExt<'ast> for ast::CallExpression<'ast> {
   fn is_global_require_call(&self, scope: &AstScopes) -> bool {
     match &self.callee {
-      ast::Expression::Identifier(ident) if ident.name == "require" => {
-        // `require(...)` inserted by bundler does not have a reference id
-        ident.reference_id.get().is_none_or(|ref_id| scope.is_unresolved(ref_id))
+      ast::Expression::Identifier(ident) => is_global_require(ident, scope),
+      _ => false,
+    }
+  }
+
+  fn is_global_require_resolve_call(&self, scope: &AstScopes) -> bool {
+    match &self.callee {
+      ast::Expression::StaticMemberExpression(member) if member.property.name == "resolve" => {
+        matches!(&member.object, ast::Expression::Identifier(ident) if is_global_require(ident, scope))
       }
       _ => false,
     }
   }
 }
+
+fn is_global_require(ident: &ast::IdentifierReference, scope: &AstScopes) -> bool {
+  // `require(...)` inserted by bundler does not have a reference id
+  ident.name == "require"
+    && ident.reference_id.get().is_none_or(|ref_id| scope.is_unresolved(ref_id))
+}
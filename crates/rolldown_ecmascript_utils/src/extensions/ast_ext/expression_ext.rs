@@ -1,4 +1,6 @@
-use oxc::{ast::ast, span::Atom};
+use std::borrow::Cow;
+
+use oxc::{ast::ast, span::Atom, syntax::operator::BinaryOperator};
 
 pub trait ExpressionExt<'ast> {
   fn as_call_expression(&self) -> Option<&ast::CallExpression<'ast>>;
@@ -11,6 +13,7 @@ pub trait ExpressionExt<'ast> {
   fn as_binary_expression(&self) -> Option<&ast::BinaryExpression<'ast>>;
   fn as_static_member_expr_mut(&mut self) -> Option<&mut ast::StaticMemberExpression<'ast>>;
   fn as_static_module_request(&self) -> Option<Atom<'ast>>;
+  fn as_constant_module_request(&self) -> Option<Cow<'ast, str>>;
 
   fn is_import_meta(&self) -> bool;
   fn is_import_meta_url(&self) -> bool;
@@ -57,6 +60,22 @@ impl<'ast> ExpressionExt<'ast> for ast::Expression<'ast> {
     }
   }
 
+  /// Like [`ExpressionExt::as_static_module_request`], but also folds concatenations of static
+  /// strings, e.g. `'./a' + '.js'`.
+  fn as_constant_module_request(&self) -> Option<Cow<'ast, str>> {
+    match self {
+      ast::Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+        let left = expr.left.as_constant_module_request()?;
+        let right = expr.right.as_constant_module_request()?;
+        Some(Cow::Owned(format!("{left}{right}")))
+      }
+      ast::Expression::ParenthesizedExpression(expr) => {
+        expr.expression.as_constant_module_request()
+      }
+      _ => self.as_static_module_request().map(|request| Cow::Borrowed(request.as_str())),
+    }
+  }
+
   fn as_binary_expression(&self) -> Option<&ast::BinaryExpression<'ast>> {
     let ast::Expression::BinaryExpression(expr) = self else {
       return None;
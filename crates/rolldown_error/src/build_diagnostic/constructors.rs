@@ -15,7 +15,9 @@ use super::Severity;
 use super::events::DiagnosableArcstr;
 use super::events::assign_to_import::AssignToImport;
 use super::events::bundler_initialize_error::BundlerInitializeError;
+use super::events::cancelled::Cancelled;
 use super::events::configuration_field_conflict::ConfigurationFieldConflict;
+use super::events::export_assignment_with_exports::ExportAssignmentWithExports;
 use super::events::export_undefined_variable::ExportUndefinedVariable;
 use super::events::filename_conflict::FilenameConflict;
 use super::events::illegal_identifier_as_name::IllegalIdentifierAsName;
@@ -26,24 +28,48 @@ use super::events::json_parse::JsonParse;
 use super::events::missing_global_name::MissingGlobalName;
 use super::events::missing_name_option_for_iife_export::MissingNameOptionForIifeExport;
 use super::events::missing_name_option_for_umd_export::MissingNameOptionForUmdExport;
+use super::events::node_global_in_browser::NodeGlobalInBrowser;
 use super::events::plugin_error::{CausedPlugin, PluginError};
 use super::events::prefer_builtin_feature::PreferBuiltinFeature;
+use super::events::require_in_esm_export::RequireInEsmExport;
 use super::events::resolve_error::DiagnosableResolveError;
+use super::events::side_effects_in_pure_package::SideEffectsInPurePackage;
+use super::events::side_effects_without_treeshake::SideEffectsWithoutTreeshake;
+use super::events::sloppy_mode_syntax_in_esm::SloppyModeSyntaxInEsm;
 use super::events::unhandleable_error::UnhandleableError;
 use super::events::unloadable_dependency::{UnloadableDependency, UnloadableDependencyContext};
 use super::events::unsupported_feature::UnsupportedFeature;
 use super::events::{
   ambiguous_external_namespace::{AmbiguousExternalNamespace, AmbiguousExternalNamespaceModule},
   circular_dependency::CircularDependency,
+  circular_import_at_init::CircularImportAtInit,
+  circular_reexport::CircularReexport,
   commonjs_variable_in_esm::{CjsExportSpan, CommonJsVariableInEsm},
+  conditional_module_exports_in_esm::ConditionalModuleExportsInEsm,
+  const_enum_in_isolated_modules::ConstEnumInIsolatedModules,
+  deprecated_export::DeprecatedExport,
+  dynamic_import::DynamicImport,
+  dynamic_require::DynamicRequire,
+  empty_dynamic_import::EmptyDynamicImport,
+  entry_without_exports::EntryWithoutExports,
+  esm_syntax_in_commonjs::EsmSyntaxInCommonJs,
   eval::Eval,
+  eval_binding_renamed::EvalBindingRenamed,
   external_entry::ExternalEntry,
   forbid_const_assign::ForbidConstAssign,
   invalid_export_option::InvalidExportOption,
   missing_export::MissingExport,
   mixed_export::MixedExport,
+  mixed_import_and_require::MixedImportAndRequire,
+  module_always_throws::ModuleAlwaysThrows,
+  module_exports_reassignment::ModuleExportsReassignment,
+  nested_export::NestedExport,
   parse_error::ParseError,
+  require_async_module::RequireAsyncModule,
+  self_reexport::SelfReexport,
   unresolved_entry::UnresolvedEntry,
+  unused_exported_function::UnusedExportedFunction,
+  unused_function_parameter::UnusedFunctionParameter,
 };
 
 impl BuildDiagnostic {
@@ -106,6 +132,10 @@ impl BuildDiagnostic {
     Self::new_inner(CircularDependency { paths })
   }
 
+  pub fn circular_reexport(paths: Vec<String>) -> Self {
+    Self::new_inner(CircularReexport { paths })
+  }
+
   pub fn missing_export(
     importer: String,
     stable_importer: String,
@@ -135,6 +165,26 @@ impl BuildDiagnostic {
     Self::new_inner(MixedExport { module_id, module_name, entry_module, export_keys })
   }
 
+  pub fn entry_without_exports(module_id: String, stable_id: String, format: &'static str) -> Self {
+    Self::new_inner(EntryWithoutExports { module_id, stable_id, format })
+  }
+
+  pub fn require_async_module(
+    importer: String,
+    stable_importer: String,
+    stable_importee: String,
+    importer_source: ArcStr,
+    span: Span,
+  ) -> Self {
+    Self::new_inner(RequireAsyncModule {
+      importer,
+      stable_importer,
+      stable_importee,
+      importer_source,
+      span,
+    })
+  }
+
   pub fn missing_global_name(module_id: String, module_name: ArcStr, guessed_name: ArcStr) -> Self {
     Self::new_inner(MissingGlobalName { module_id, module_name, guessed_name })
   }
@@ -178,6 +228,99 @@ impl BuildDiagnostic {
     })
   }
 
+  pub fn conditional_module_exports_in_esm(
+    filename: String,
+    source: ArcStr,
+    esm_export_span: Span,
+    module_exports_span: Span,
+  ) -> Self {
+    Self::new_inner(ConditionalModuleExportsInEsm {
+      filename,
+      source,
+      esm_export_span,
+      module_exports_span,
+    })
+  }
+
+  pub fn deprecated_export(
+    importer: String,
+    stable_importer: String,
+    stable_importee: String,
+    importer_source: ArcStr,
+    imported_specifier: String,
+    imported_specifier_span: Span,
+    deprecation: ArcStr,
+  ) -> Self {
+    Self::new_inner(DeprecatedExport {
+      importer,
+      stable_importer,
+      stable_importee,
+      importer_source,
+      imported_specifier,
+      imported_specifier_span,
+      deprecation,
+    })
+  }
+
+  pub fn circular_import_at_init(
+    importer: String,
+    stable_importer: String,
+    stable_importee: String,
+    importer_source: ArcStr,
+    imported_specifier: String,
+    reference_span: Span,
+  ) -> Self {
+    Self::new_inner(CircularImportAtInit {
+      importer,
+      stable_importer,
+      stable_importee,
+      importer_source,
+      imported_specifier,
+      reference_span,
+    })
+  }
+
+  pub fn esm_syntax_in_commonjs(
+    filename: ArcStr,
+    source: ArcStr,
+    span: Span,
+    syntax: &'static str,
+    extension: &'static str,
+  ) -> Self {
+    Self::new_inner(EsmSyntaxInCommonJs { filename, source, span, syntax, extension })
+  }
+
+  pub fn nested_export(filename: ArcStr, source: ArcStr, span: Span) -> Self {
+    Self::new_inner(NestedExport { filename, source, span })
+  }
+
+  pub fn self_reexport(filename: ArcStr, source: ArcStr, span: Span, specifier: String) -> Self {
+    Self::new_inner(SelfReexport { filename, source, span, specifier })
+  }
+
+  pub fn const_enum_in_isolated_modules(
+    filename: ArcStr,
+    source: ArcStr,
+    name: ArcStr,
+    span: Span,
+  ) -> Self {
+    Self::new_inner(ConstEnumInIsolatedModules { filename, source, name, span })
+  }
+
+  pub fn export_assignment_with_exports(
+    filename: ArcStr,
+    source: ArcStr,
+    export_assignment_span: Span,
+    export_span: Span,
+  ) -> Self {
+    Self::new_inner(ExportAssignmentWithExports {
+      filename,
+      source,
+      export_assignment_span,
+      export_span,
+    })
+  }
+
   pub fn import_is_undefined(
     filename: ArcStr,
     source: ArcStr,
@@ -268,6 +411,23 @@ impl BuildDiagnostic {
     Self::new_inner(Eval { filename, span, source })
   }
 
+  pub fn eval_binding_renamed(filename: String, name: String) -> Self {
+    Self::new_inner(EvalBindingRenamed { filename, name })
+  }
+
+  pub fn dynamic_require(
+    filename: String,
+    source: ArcStr,
+    span: Span,
+    is_prefixed_concatenation: bool,
+  ) -> Self {
+    Self::new_inner(DynamicRequire { filename, span, source, is_prefixed_concatenation })
+  }
+
+  pub fn dynamic_import(filename: String, source: ArcStr, span: Span) -> Self {
+    Self::new_inner(DynamicImport { filename, span, source })
+  }
+
   pub fn configuration_field_conflict(
     a_config_name: &str,
     a_field_name: &str,
@@ -287,8 +447,9 @@ impl BuildDiagnostic {
     source: ArcStr,
     span: Span,
     name: ArcStr,
+    note: Option<String>,
   ) -> Self {
-    Self::new_inner(ExportUndefinedVariable { filename, source, span, name })
+    Self::new_inner(ExportUndefinedVariable { filename, source, span, name, note })
   }
 
   pub fn assign_to_import(filename: ArcStr, source: ArcStr, span: Span, name: ArcStr) -> Self {
@@ -325,6 +486,103 @@ impl BuildDiagnostic {
     Self::new_inner(BundlerInitializeError { message, hint })
   }
 
+  pub fn cancelled() -> Self {
+    Self::new_inner(Cancelled)
+  }
+
+  pub fn node_global_in_browser(
+    filename: String,
+    source: ArcStr,
+    span: Span,
+    name: ArcStr,
+  ) -> Self {
+    Self::new_inner(NodeGlobalInBrowser { filename, source, span, name })
+  }
+
+  pub fn empty_dynamic_import(
+    importer: String,
+    stable_importer: String,
+    stable_importee: String,
+    importer_source: ArcStr,
+    span: Span,
+  ) -> Self {
+    Self::new_inner(EmptyDynamicImport {
+      importer,
+      stable_importer,
+      stable_importee,
+      importer_source,
+      span,
+    })
+  }
+
+  pub fn unused_exported_function(
+    filename: String,
+    source: ArcStr,
+    span: Span,
+    name: ArcStr,
+  ) -> Self {
+    Self::new_inner(UnusedExportedFunction { filename, source, span, name })
+  }
+
+  pub fn unused_function_parameter(
+    filename: String,
+    source: ArcStr,
+    function_name: ArcStr,
+    params: Vec<(ArcStr, Span)>,
+  ) -> Self {
+    Self::new_inner(UnusedFunctionParameter { filename, source, function_name, params })
+  }
+
+  pub fn mixed_import_and_require(
+    filename: String,
+    source: ArcStr,
+    specifier: ArcStr,
+    import_span: Span,
+    require_span: Span,
+  ) -> Self {
+    Self::new_inner(MixedImportAndRequire {
+      filename,
+      source,
+      specifier,
+      import_span,
+      require_span,
+    })
+  }
+
+  pub fn module_always_throws(filename: String, source: ArcStr, span: Span) -> Self {
+    Self::new_inner(ModuleAlwaysThrows { filename, source, span })
+  }
+
+  pub fn module_exports_reassignment(
+    filename: String,
+    source: ArcStr,
+    export_span: Span,
+    reassignment_span: Span,
+  ) -> Self {
+    Self::new_inner(ModuleExportsReassignment { filename, source, export_span, reassignment_span })
+  }
+
+  pub fn require_in_esm_export(filename: String, source: ArcStr, span: Span, name: ArcStr) -> Self {
+    Self::new_inner(RequireInEsmExport { filename, source, span, name })
+  }
+
+  pub fn side_effects_in_pure_package(filename: String, source: ArcStr, span: Span) -> Self {
+    Self::new_inner(SideEffectsInPurePackage { filename, source, span })
+  }
+
+  pub fn side_effects_without_treeshake(package_json_path: String) -> Self {
+    Self::new_inner(SideEffectsWithoutTreeshake { package_json_path })
+  }
+
+  pub fn sloppy_mode_syntax_in_esm(
+    filename: ArcStr,
+    source: ArcStr,
+    span: Span,
+    syntax: &'static str,
+  ) -> Self {
+    Self::new_inner(SloppyModeSyntaxInEsm { filename, source, span, syntax })
+  }
+
   pub fn plugin_error(caused_plugin: CausedPlugin, err: anyhow::Error) -> Self {
     Self::new_inner(PluginError { plugin: caused_plugin, error: err })
   }
@@ -0,0 +1,16 @@
+use crate::types::diagnostic_options::DiagnosticOptions;
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl BuildEvent for Cancelled {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::CancelledError
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    "The build was cancelled before it completed".to_string()
+  }
+}
@@ -0,0 +1,56 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{types::diagnostic_options::DiagnosticOptions, types::event_kind::EventKind};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct CircularImportAtInit {
+  pub importer: String,
+  pub stable_importer: String,
+  pub stable_importee: String,
+  pub importer_source: ArcStr,
+  pub imported_specifier: String,
+  pub reference_span: Span,
+}
+
+impl BuildEvent for CircularImportAtInit {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    EventKind::CircularImportAtInit
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.importer.clone())
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      r#""{}" imported from "{}" is used by "{}" during initialization, but "{}" hasn't been executed yet due to a circular import."#,
+      self.imported_specifier, &self.stable_importee, &self.stable_importer, &self.stable_importee
+    )
+  }
+
+  fn on_diagnostic(
+    &self,
+    diagnostic: &mut crate::build_diagnostic::diagnostic::Diagnostic,
+    _opts: &DiagnosticOptions,
+  ) {
+    let file_id = diagnostic.add_file(&self.stable_importer, &self.importer_source);
+
+    diagnostic.title = format!(
+      r#""{}" from "{}" is used before it's initialized due to a circular import."#,
+      self.imported_specifier, &self.stable_importee
+    );
+
+    diagnostic.add_label(
+      &file_id,
+      self.reference_span.start..self.reference_span.end,
+      String::from("Used here during module initialization"),
+    );
+
+    diagnostic.add_note(String::from(
+      "The binding might be `undefined` or throw a `ReferenceError` at this point. Move the usage into a function, or break the import cycle.",
+    ));
+  }
+}
@@ -0,0 +1,34 @@
+use super::BuildEvent;
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+  types::event_kind::EventKind,
+};
+
+#[derive(Debug)]
+pub struct CircularReexport {
+  /// Modules on the loop, the first one is repeated at the end.
+  pub paths: Vec<String>,
+}
+
+impl CircularReexport {
+  fn stable_paths(&self, opts: &DiagnosticOptions) -> Vec<String> {
+    self.paths.iter().map(|p| opts.stabilize_path(p)).collect::<Vec<_>>()
+  }
+}
+
+impl BuildEvent for CircularReexport {
+  fn kind(&self) -> EventKind {
+    EventKind::CircularReexport
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!("Circular re-export: {}.", self.stable_paths(opts).join(" -> "))
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, _opts: &DiagnosticOptions) {
+    diagnostic.add_help(
+      "Exports forwarded around the loop may be ambiguous or undefined. This is often a barrel file re-exporting a module that imports from the barrel."
+        .to_string(),
+    );
+  }
+}
@@ -0,0 +1,48 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct ConditionalModuleExportsInEsm {
+  pub filename: String,
+  pub source: ArcStr,
+  pub esm_export_span: Span,
+  pub module_exports_span: Span,
+}
+
+impl BuildEvent for ConditionalModuleExportsInEsm {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::ConditionalModuleExportsInEsm
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    "`module.exports` is assigned conditionally in an ECMAScript module and will not replace the exports of the module".to_string()
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.module_exports_span.start..self.module_exports_span.end,
+      "`module.exports` is assigned under a condition here".to_string(),
+    );
+
+    diagnostic.add_label(
+      &file_id,
+      self.esm_export_span.start..self.esm_export_span.end,
+      "This file is considered to be an ECMAScript module because of the `export` keyword here:"
+        .to_string(),
+    );
+  }
+}
@@ -0,0 +1,51 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct ConstEnumInIsolatedModules {
+  pub filename: ArcStr,
+  pub source: ArcStr,
+  pub name: ArcStr,
+  /// Span of the enum name.
+  pub span: Span,
+}
+
+impl BuildEvent for ConstEnumInIsolatedModules {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::ConstEnumInIsolatedModulesError
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.to_string())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Cannot declare const enum `{}` in {:?} when `isolatedModules` is enabled",
+      self.name,
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    diagnostic.title =
+      String::from("Const enums are not supported when `isolatedModules` is enabled");
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      String::from("This enum is declared with `const`"),
+    );
+    diagnostic.add_note(String::from(
+      "Other modules can't inline the members of a const enum when each file is transpiled on its own. Use a regular `enum` instead.",
+    ));
+  }
+}
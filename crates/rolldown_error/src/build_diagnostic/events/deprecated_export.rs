@@ -0,0 +1,61 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{types::diagnostic_options::DiagnosticOptions, types::event_kind::EventKind};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct DeprecatedExport {
+  pub importer: String,
+  pub stable_importer: String,
+  pub stable_importee: String,
+  pub importer_source: ArcStr,
+  pub imported_specifier: String,
+  pub imported_specifier_span: Span,
+  /// Text following the `@deprecated` tag, might be empty.
+  pub deprecation: ArcStr,
+}
+
+impl BuildEvent for DeprecatedExport {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    EventKind::DeprecatedExport
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.importer.clone())
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    let mut message = format!(
+      r#""{}" is deprecated by "{}", imported by "{}"."#,
+      self.imported_specifier, &self.stable_importee, &self.stable_importer
+    );
+    if !self.deprecation.is_empty() {
+      message.push(' ');
+      message.push_str(&self.deprecation);
+    }
+    message
+  }
+
+  fn on_diagnostic(
+    &self,
+    diagnostic: &mut crate::build_diagnostic::diagnostic::Diagnostic,
+    _opts: &DiagnosticOptions,
+  ) {
+    let file_id = diagnostic.add_file(&self.stable_importer, &self.importer_source);
+
+    diagnostic.title =
+      format!(r#""{}" is deprecated by "{}"."#, self.imported_specifier, &self.stable_importee);
+
+    if !self.deprecation.is_empty() {
+      diagnostic.add_note(self.deprecation.to_string());
+    }
+
+    diagnostic.add_label(
+      &file_id,
+      self.imported_specifier_span.start..self.imported_specifier_span.end,
+      String::from("Deprecated export"),
+    );
+  }
+}
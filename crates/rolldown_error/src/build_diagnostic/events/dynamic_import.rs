@@ -0,0 +1,56 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct DynamicImport {
+  /// Span of the specifier of the `import(...)` expression.
+  pub span: Span,
+  pub source: ArcStr,
+  pub filename: String,
+}
+
+impl DynamicImport {
+  fn specifier(&self) -> &str {
+    &self.source[self.span.start as usize..self.span.end as usize]
+  }
+}
+
+impl BuildEvent for DynamicImport {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::DynamicImport
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "The specifier `{}` of `import()` in '{}' can't be resolved at build time, so the imported module isn't bundled and the call is kept as is.",
+      self.specifier(),
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.title = format!(
+      "The specifier `{}` of `import()` can't be resolved at build time.",
+      self.specifier()
+    );
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      String::from("Only string literals and concatenations of them are supported."),
+    );
+  }
+}
@@ -0,0 +1,60 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct DynamicRequire {
+  /// Span of the argument of the `require(...)` call.
+  pub span: Span,
+  pub source: ArcStr,
+  pub filename: String,
+  /// The argument is a string literal concatenated with a dynamic value, e.g.
+  /// `require('./locale/' + lang)`.
+  pub is_prefixed_concatenation: bool,
+}
+
+impl BuildEvent for DynamicRequire {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::DynamicRequire
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    if self.is_prefixed_concatenation {
+      format!(
+        "The argument of `require()` in '{}' only has a static prefix, so none of the modules it might require is bundled and the call is kept as is. If it requires one of the modules in a directory, require them with static paths instead.",
+        opts.stabilize_path(&self.filename)
+      )
+    } else {
+      format!(
+        "The argument of `require()` in '{}' can't be resolved at build time, so the required module isn't bundled and the call is kept as is.",
+        opts.stabilize_path(&self.filename)
+      )
+    }
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.title = String::from("The argument of `require()` can't be resolved at build time.");
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      if self.is_prefixed_concatenation {
+        String::from("Only the prefix of this path is known at build time.")
+      } else {
+        String::from("Only string literals and concatenations of them are supported.")
+      },
+    );
+  }
+}
@@ -0,0 +1,49 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use super::BuildEvent;
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+  types::event_kind::EventKind,
+};
+
+#[derive(Debug)]
+pub struct EmptyDynamicImport {
+  pub importer: String,
+  pub stable_importer: String,
+  pub stable_importee: String,
+  pub importer_source: ArcStr,
+  pub span: Span,
+}
+
+impl BuildEvent for EmptyDynamicImport {
+  fn kind(&self) -> EventKind {
+    EventKind::EmptyDynamicImport
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.importer.clone())
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      r#""{}" is dynamically imported by "{}", but it's empty after tree shaking."#,
+      self.stable_importee, self.stable_importer
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, _opts: &DiagnosticOptions) {
+    let file_id = diagnostic.add_file(&self.stable_importer, &self.importer_source);
+
+    diagnostic.title = format!(
+      r#""{}" is dynamically imported, but it's empty after tree shaking."#,
+      self.stable_importee
+    );
+
+    diagnostic.add_label(&file_id, self.span.start..self.span.end, String::from("Imported here"));
+
+    diagnostic.add_note(String::from(
+      "The `import()` only resolves to an empty namespace object, so it can probably be removed.",
+    ));
+  }
+}
@@ -0,0 +1,26 @@
+use super::BuildEvent;
+use crate::{types::diagnostic_options::DiagnosticOptions, types::event_kind::EventKind};
+
+#[derive(Debug)]
+pub struct EntryWithoutExports {
+  pub module_id: String,
+  pub stable_id: String,
+  pub format: &'static str,
+}
+
+impl BuildEvent for EntryWithoutExports {
+  fn kind(&self) -> EventKind {
+    EventKind::EntryWithoutExports
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.module_id.clone())
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      r#"Entry module "{}" has no exports, which is unusual for a library build (format: "{}"). If the entry only runs side effects (e.g. a CLI), add its name to `sideEffectEntries` to disable this warning."#,
+      self.stable_id, self.format
+    )
+  }
+}
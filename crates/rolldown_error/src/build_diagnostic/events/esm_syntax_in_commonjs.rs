@@ -0,0 +1,50 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct EsmSyntaxInCommonJs {
+  pub filename: ArcStr,
+  pub source: ArcStr,
+  pub span: Span,
+  /// Either `import` or `export`.
+  pub syntax: &'static str,
+  /// The file extension that forced the module to be CommonJS, e.g. `.cjs`.
+  pub extension: &'static str,
+}
+
+impl BuildEvent for EsmSyntaxInCommonJs {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::EsmSyntaxInCommonJs
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.to_string())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "`{}` syntax is used in {:?}, which is treated as a CommonJS module by its `{}` extension",
+      self.syntax,
+      opts.stabilize_path(&self.filename),
+      self.extension
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    diagnostic.title = self.message(opts);
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      format!("`{}` is only expected in ECMAScript modules", self.syntax),
+    );
+  }
+}
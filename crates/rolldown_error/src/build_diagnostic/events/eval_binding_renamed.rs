@@ -0,0 +1,27 @@
+use crate::types::diagnostic_options::DiagnosticOptions;
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct EvalBindingRenamed {
+  pub filename: String,
+  pub name: String,
+}
+
+impl BuildEvent for EvalBindingRenamed {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::EvalBindingRenamed
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "`{}` in '{}' is visible to a direct `eval`, but has to be renamed because the name is already used in the chunk. The evaluated code can't reference it by name.",
+      self.name,
+      opts.stabilize_path(&self.filename)
+    )
+  }
+}
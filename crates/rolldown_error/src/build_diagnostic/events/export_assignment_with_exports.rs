@@ -0,0 +1,56 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct ExportAssignmentWithExports {
+  pub filename: ArcStr,
+  pub source: ArcStr,
+  /// Span of the `export = ...` statement.
+  pub export_assignment_span: Span,
+  /// Span of the first other value export.
+  pub export_span: Span,
+}
+
+impl BuildEvent for ExportAssignmentWithExports {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::ExportAssignmentWithExportsError
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.to_string())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "An export assignment cannot be used in a module with other exported elements in {:?}",
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    diagnostic.title =
+      String::from("An export assignment cannot be used in a module with other exported elements");
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.export_assignment_span.start..self.export_assignment_span.end,
+      String::from("`export =` replaces the exports of the module here"),
+    );
+    diagnostic.add_label(
+      &file_id,
+      self.export_span.start..self.export_span.end,
+      String::from("Other export here"),
+    );
+    diagnostic.add_note(String::from(
+      "`export =` is compiled to `module.exports =`, which discards the other exports. Export them as properties of the assigned value instead.",
+    ));
+  }
+}
@@ -13,6 +13,7 @@ pub struct ExportUndefinedVariable {
   pub source: ArcStr,
   pub span: Span,
   pub name: ArcStr,
+  pub note: Option<String>,
 }
 
 impl BuildEvent for ExportUndefinedVariable {
@@ -34,5 +35,9 @@ impl BuildEvent for ExportUndefinedVariable {
     let file_id = diagnostic.add_file(filename, self.source.clone());
 
     diagnostic.add_label(&file_id, self.span.start..self.span.end, String::new());
+
+    if let Some(note) = &self.note {
+      diagnostic.add_note(note.clone());
+    }
   }
 }
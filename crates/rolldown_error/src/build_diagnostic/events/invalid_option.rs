@@ -6,10 +6,12 @@ pub enum InvalidOptionType {
   UnsupportedInlineDynamicFormat(String),
   UnsupportedCodeSplittingFormat(String),
   InvalidOutputFile,
+  InlineDynamicImportsWithMultipleChunks,
   InvalidOutputDirOption,
   NoEntryPoint,
   AdvancedChunksWithoutGroups(Vec<String>),
   InvalidContext(String),
+  ConflictingInject { name: String, first: String, second: String },
 }
 
 #[derive(Debug)]
@@ -31,6 +33,7 @@ impl BuildEvent for InvalidOption {
           format!("Invalid value \"{format}\" for option \"output.format\" - UMD and IIFE are not supported for code-splitting builds.")
         }
         InvalidOptionType::InvalidOutputFile => "Invalid value for option \"output.file\" - When building multiple chunks, the \"output.dir\" option must be used, not \"output.file\". You may set `output.inlineDynamicImports` to `true` when using dynamic imports.".to_string(),
+        InvalidOptionType::InlineDynamicImportsWithMultipleChunks => "Invalid value for option \"output.inlineDynamicImports\" - the output can't be split into multiple chunks when \"output.inlineDynamicImports\" is true. Chunks are still split by multiple inputs, emitted chunks and `advancedChunks`.".to_string(),
         InvalidOptionType::InvalidOutputDirOption => "Invalid value for option \"output.dir\" - you must set either \"output.file\" for a single-file build or \"output.dir\" when generating multiple chunks.".to_string(),
         InvalidOptionType::NoEntryPoint =>"You must supply `options.input` to rolldown, you should at least provide one entrypoint via `options.input` or `this.emitFile({type: 'chunk', ...})` (https://rollupjs.org/plugin-development/#this-emitfile)".to_string(),
         InvalidOptionType::AdvancedChunksWithoutGroups(options) => {
@@ -40,6 +43,9 @@ impl BuildEvent for InvalidOption {
         InvalidOptionType::InvalidContext(options) => {
             format!("\"{options}\" is an illegitimate identifier for option \"context\". You may use a legitimate context identifier instead.")
         }
+        InvalidOptionType::ConflictingInject { name, first, second } => {
+            format!("Conflicting values for option \"inject\" - \"{name}\" is injected from both {first} and {second}. You may remove one of them.")
+        }
     }
   }
 }
@@ -0,0 +1,59 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct MixedImportAndRequire {
+  pub filename: String,
+  pub source: ArcStr,
+  pub specifier: ArcStr,
+  /// Span of the first `import` or `export ... from` statement.
+  pub import_span: Span,
+  /// Span of the first `require()` call.
+  pub require_span: Span,
+}
+
+impl BuildEvent for MixedImportAndRequire {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::MixedImportAndRequire
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "'{}' is both imported and required in '{}'.",
+      self.specifier,
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.title = format!("'{}' is both imported and required.", self.specifier);
+
+    diagnostic.add_label(
+      &file_id,
+      self.import_span.start..self.import_span.end,
+      "Imported here".to_string(),
+    );
+    diagnostic.add_label(
+      &file_id,
+      self.require_span.start..self.require_span.end,
+      "Required here".to_string(),
+    );
+    diagnostic.add_help(
+      "The `import` and the `require()` may get different values depending on the interop between ES modules and CommonJS. Use one of them consistently."
+        .to_string(),
+    );
+  }
+}
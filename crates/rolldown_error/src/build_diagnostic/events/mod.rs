@@ -14,11 +14,24 @@ pub mod napi_error;
 pub mod ambiguous_external_namespace;
 pub mod assign_to_import;
 pub mod bundler_initialize_error;
+pub mod cancelled;
 pub mod circular_dependency;
+pub mod circular_import_at_init;
+pub mod circular_reexport;
 pub mod commonjs_variable_in_esm;
+pub mod conditional_module_exports_in_esm;
 pub mod configuration_field_conflict;
+pub mod const_enum_in_isolated_modules;
+pub mod deprecated_export;
+pub mod dynamic_import;
+pub mod dynamic_require;
+pub mod empty_dynamic_import;
 pub mod empty_import_meta;
+pub mod entry_without_exports;
+pub mod esm_syntax_in_commonjs;
 pub mod eval;
+pub mod eval_binding_renamed;
+pub mod export_assignment_with_exports;
 pub mod export_undefined_variable;
 pub mod external_entry;
 pub mod filename_conflict;
@@ -34,14 +47,27 @@ pub mod missing_global_name;
 pub mod missing_name_option_for_iife_export;
 pub mod missing_name_option_for_umd_export;
 pub mod mixed_export;
+pub mod mixed_import_and_require;
+pub mod module_always_throws;
+pub mod module_exports_reassignment;
+pub mod nested_export;
+pub mod node_global_in_browser;
 pub mod parse_error;
 pub mod plugin_error;
 pub mod prefer_builtin_feature;
+pub mod require_async_module;
+pub mod require_in_esm_export;
 pub mod resolve_error;
+pub mod self_reexport;
+pub mod side_effects_in_pure_package;
+pub mod side_effects_without_treeshake;
+pub mod sloppy_mode_syntax_in_esm;
 pub mod unhandleable_error;
 pub mod unloadable_dependency;
 pub mod unresolved_entry;
 pub mod unsupported_feature;
+pub mod unused_exported_function;
+pub mod unused_function_parameter;
 
 pub trait BuildEvent: Debug + Sync + Send {
   fn kind(&self) -> EventKind;
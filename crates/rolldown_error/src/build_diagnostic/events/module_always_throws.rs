@@ -0,0 +1,40 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct ModuleAlwaysThrows {
+  pub filename: String,
+  pub source: ArcStr,
+  /// Span of the top-level `throw` statement.
+  pub span: Span,
+}
+
+impl BuildEvent for ModuleAlwaysThrows {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::ModuleAlwaysThrows
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!("Module '{}' always throws when it is evaluated.", opts.stabilize_path(&self.filename))
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.title = "Module always throws when it is evaluated.".to_string();
+
+    diagnostic.add_label(&file_id, self.span.start..self.span.end, "Thrown here".to_string());
+    diagnostic.add_help("Any module that imports it will fail to load.".to_string());
+  }
+}
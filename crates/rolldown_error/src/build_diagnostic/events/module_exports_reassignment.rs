@@ -0,0 +1,58 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct ModuleExportsReassignment {
+  pub filename: String,
+  pub source: ArcStr,
+  /// Span of the first `exports.x` or `module.exports.x` being assigned.
+  pub export_span: Span,
+  /// Span of the `module.exports` being reassigned after it.
+  pub reassignment_span: Span,
+}
+
+impl BuildEvent for ModuleExportsReassignment {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::ModuleExportsReassignment
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "`module.exports` is reassigned after properties are assigned to `exports` in '{}'.",
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.title =
+      "`module.exports` is reassigned after properties are assigned to `exports`.".to_string();
+
+    diagnostic.add_label(
+      &file_id,
+      self.export_span.start..self.export_span.end,
+      "This export is lost".to_string(),
+    );
+    diagnostic.add_label(
+      &file_id,
+      self.reassignment_span.start..self.reassignment_span.end,
+      "because `module.exports` is replaced here".to_string(),
+    );
+    diagnostic.add_help(
+      "Assign to `module.exports` first and add the properties to it afterwards, or only use one of the two patterns."
+        .to_string(),
+    );
+  }
+}
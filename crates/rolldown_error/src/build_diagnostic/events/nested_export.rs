@@ -0,0 +1,45 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct NestedExport {
+  pub filename: ArcStr,
+  pub source: ArcStr,
+  /// Span of the `export` keyword.
+  pub span: Span,
+}
+
+impl BuildEvent for NestedExport {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::NestedExportError
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.to_string())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Cannot use `export` inside a block or function in {:?}. Exports are only allowed at the top level of a module",
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    diagnostic.title = String::from("Exports are only allowed at the top level of a module");
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      String::from("This `export` is nested inside a block or function"),
+    );
+  }
+}
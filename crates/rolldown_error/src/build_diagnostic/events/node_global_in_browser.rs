@@ -0,0 +1,53 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct NodeGlobalInBrowser {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+  pub name: ArcStr,
+}
+
+impl BuildEvent for NodeGlobalInBrowser {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::NodeGlobalInBrowser
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "`{}` is a Node.js global and is not available when `platform` is set to `browser`, but it is referenced in '{}'.",
+      self.name,
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.title = format!(
+      "`{}` is a Node.js global and is not available when `platform` is set to `browser`.",
+      self.name
+    );
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      format!(
+        "`{}` is referenced here. Consider using `define` or `inject` to provide it.",
+        self.name
+      ),
+    );
+  }
+}
@@ -0,0 +1,49 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use super::BuildEvent;
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+  types::event_kind::EventKind,
+};
+
+#[derive(Debug)]
+pub struct RequireAsyncModule {
+  pub importer: String,
+  pub stable_importer: String,
+  pub stable_importee: String,
+  pub importer_source: ArcStr,
+  pub span: Span,
+}
+
+impl BuildEvent for RequireAsyncModule {
+  fn kind(&self) -> EventKind {
+    EventKind::RequireAsyncModuleError
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.importer.clone())
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      r#"Can't `require()` "{}" in "{}" because it or one of its dependencies uses top-level await."#,
+      self.stable_importee, self.stable_importer
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, _opts: &DiagnosticOptions) {
+    let file_id = diagnostic.add_file(&self.stable_importer, &self.importer_source);
+
+    diagnostic.title = format!(
+      r#"Can't `require()` "{}" because it or one of its dependencies uses top-level await."#,
+      self.stable_importee
+    );
+
+    diagnostic.add_label(&file_id, self.span.start..self.span.end, String::from("Required here"));
+
+    diagnostic.add_note(String::from(
+      "Use `import()` instead, or set `requireEsmInterop` to `'wrap'` to make this `require()` return a promise of the module's exports.",
+    ));
+  }
+}
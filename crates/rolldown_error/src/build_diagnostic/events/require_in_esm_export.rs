@@ -0,0 +1,48 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct RequireInEsmExport {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+  pub name: ArcStr,
+}
+
+impl BuildEvent for RequireInEsmExport {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::RequireInEsmExport
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "The ESM export `{}` in '{}' is initialized with a CommonJS `require` call, its value depends on the CommonJS interop of the required module.",
+      self.name,
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.title =
+      format!("The ESM export `{}` is initialized with a CommonJS `require` call.", self.name);
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      String::from("Consider using `import` or `export ... from` instead."),
+    );
+  }
+}
@@ -0,0 +1,46 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct SelfReexport {
+  pub filename: ArcStr,
+  pub source: ArcStr,
+  /// Span of the module request of the `export * from` declaration.
+  pub span: Span,
+  pub specifier: String,
+}
+
+impl BuildEvent for SelfReexport {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::SelfReexportError
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.to_string())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Module {:?} re-exports itself with `export * from '{}'`.",
+      opts.stabilize_path(&self.filename),
+      self.specifier
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      String::from("This resolves to the module itself"),
+    );
+  }
+}
@@ -0,0 +1,46 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct SideEffectsInPurePackage {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+}
+
+impl BuildEvent for SideEffectsInPurePackage {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::SideEffectsInPurePackage
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "'{}' is declared as `sideEffects: false` in package.json, but contains a top-level statement with side effects.",
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.title =
+      "Module is declared as `sideEffects: false` in package.json, but contains a top-level statement with side effects.".to_string();
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "This statement has side effects and may be removed by tree shaking.".to_string(),
+    );
+  }
+}
@@ -0,0 +1,25 @@
+use crate::types::diagnostic_options::DiagnosticOptions;
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct SideEffectsWithoutTreeshake {
+  pub package_json_path: String,
+}
+
+impl BuildEvent for SideEffectsWithoutTreeshake {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::SideEffectsWithoutTreeshake
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.package_json_path.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "`{}` declares `\"sideEffects\": false`, but it has no effect since `treeshake` is disabled.",
+      opts.stabilize_path(&self.package_json_path)
+    )
+  }
+}
@@ -0,0 +1,47 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct SloppyModeSyntaxInEsm {
+  pub filename: ArcStr,
+  pub source: ArcStr,
+  pub span: Span,
+  /// The construct that is only valid in sloppy mode, e.g. `arguments.callee`.
+  pub syntax: &'static str,
+}
+
+impl BuildEvent for SloppyModeSyntaxInEsm {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::SloppyModeSyntaxInEsmError
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.to_string())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Cannot use `{}` in {:?}, which is an ECMAScript module and therefore always in strict mode",
+      self.syntax,
+      opts.stabilize_path(&self.filename),
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    diagnostic.title = self.message(opts);
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      format!("`{}` is only allowed in sloppy mode", self.syntax),
+    );
+  }
+}
@@ -0,0 +1,51 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct UnusedExportedFunction {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+  pub name: ArcStr,
+}
+
+impl BuildEvent for UnusedExportedFunction {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::UnusedExportedFunction
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Exported function `{}` is never referenced within '{}'.",
+      self.name,
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.title = format!("Exported function `{}` is never referenced internally.", self.name);
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "Only referenced by its export".to_string(),
+    );
+    diagnostic.add_note(
+      "It may still be used by consumers of the public API. Remove it if they don't rely on it either."
+        .to_string(),
+    );
+  }
+}
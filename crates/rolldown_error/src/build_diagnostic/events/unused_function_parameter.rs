@@ -0,0 +1,61 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct UnusedFunctionParameter {
+  pub filename: String,
+  pub source: ArcStr,
+  pub function_name: ArcStr,
+  /// Names and spans of the trailing parameters that are never referenced, in source order.
+  pub params: Vec<(ArcStr, Span)>,
+}
+
+impl UnusedFunctionParameter {
+  fn param_list(&self) -> String {
+    self.params.iter().map(|(name, _)| format!("`{name}`")).collect::<Vec<_>>().join(", ")
+  }
+}
+
+impl BuildEvent for UnusedFunctionParameter {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::UnusedFunctionParameter
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Trailing parameters {} of exported function `{}` in '{}' are never referenced.",
+      self.param_list(),
+      self.function_name,
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.title = format!(
+      "Trailing parameters {} of exported function `{}` are never referenced.",
+      self.param_list(),
+      self.function_name
+    );
+
+    for (_, span) in &self.params {
+      diagnostic.add_label(&file_id, span.start..span.end, "Never referenced".to_string());
+    }
+    diagnostic.add_note(
+      "Callers may still pass arguments for them. Remove them if the public API allows it."
+        .to_string(),
+    );
+  }
+}
@@ -4,7 +4,7 @@
 use bitflags::bitflags;
 bitflags! {
   #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-  pub struct EventKindSwitcher: u32 {
+  pub struct EventKindSwitcher: u64 {
     const AmbiguousExternalNamespaceError = 1 << 0;
     const CircularDependency = 1 << 1;
     const Eval = 1 << 2;
@@ -37,5 +37,31 @@ bitflags! {
     const PreferBuiltinFeature = 1 << 29;
     const BundlerInitializeError = 1 << 30;
     const PluginError = 1 << 31;
+    const NodeGlobalInBrowser = 1 << 32;
+    const RequireInEsmExport = 1 << 33;
+    const SideEffectsInPurePackage = 1 << 34;
+    const ConditionalModuleExportsInEsm = 1 << 35;
+    const EsmSyntaxInCommonJs = 1 << 36;
+    const DeprecatedExport = 1 << 37;
+    const EntryWithoutExports = 1 << 38;
+    const UnusedExportedFunction = 1 << 39;
+    const RequireAsyncModuleError = 1 << 40;
+    const NestedExportError = 1 << 41;
+    const EmptyDynamicImport = 1 << 42;
+    const CircularImportAtInit = 1 << 43;
+    const ConstEnumInIsolatedModulesError = 1 << 44;
+    const DynamicRequire = 1 << 45;
+    const SideEffectsWithoutTreeshake = 1 << 46;
+    const SloppyModeSyntaxInEsmError = 1 << 47;
+    const DynamicImport = 1 << 48;
+    const UnusedFunctionParameter = 1 << 49;
+    const MixedImportAndRequire = 1 << 50;
+    const ModuleAlwaysThrows = 1 << 51;
+    const CircularReexport = 1 << 52;
+    const SelfReexportError = 1 << 53;
+    const CancelledError = 1 << 54;
+    const ModuleExportsReassignment = 1 << 55;
+    const ExportAssignmentWithExportsError = 1 << 56;
+    const EvalBindingRenamed = 1 << 57;
   }
 }
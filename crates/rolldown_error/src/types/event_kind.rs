@@ -44,6 +44,51 @@ pub enum EventKind {
   PreferBuiltinFeature = 29,
   BundlerInitializeError = 30,
   PluginError = 31,
+  /// Whether to emit warning when Node.js globals like `process` or `Buffer` are referenced when `platform` is `browser`
+  NodeGlobalInBrowser = 32,
+  /// Whether to emit warning when an ESM export is initialized with a CommonJS `require()` call
+  RequireInEsmExport = 33,
+  /// Whether to emit warning when a module declared as `sideEffects: false` in package.json contains top-level statements with side effects
+  SideEffectsInPurePackage = 34,
+  /// Whether to emit warning when `module.exports` is conditionally assigned in an ES module
+  ConditionalModuleExportsInEsm = 35,
+  /// Whether to emit warning when ESM `import` or `export` syntax is used in a `.cjs` or `.cts` module
+  EsmSyntaxInCommonJs = 36,
+  /// Whether to emit warning when importing an export annotated with `@deprecated` JSDoc
+  DeprecatedExport = 37,
+  /// Whether to emit warning when an entry module of an `esm` or `cjs` build has no exports
+  EntryWithoutExports = 38,
+  /// Whether to emit warning when an exported function declaration is never referenced within its module
+  UnusedExportedFunction = 39,
+  RequireAsyncModuleError = 40,
+  NestedExportError = 41,
+  /// Whether to emit warning when a dynamic import targets a module that is empty after tree shaking
+  EmptyDynamicImport = 42,
+  /// Whether to emit warning when an imported binding is used during module initialization before the circular import providing it has been executed
+  CircularImportAtInit = 43,
+  ConstEnumInIsolatedModulesError = 44,
+  /// Whether to emit warning when the argument of `require()` can't be resolved at build time
+  DynamicRequire = 45,
+  /// Whether to emit warning when a package declares `"sideEffects": false` while `treeshake` is disabled
+  SideEffectsWithoutTreeshake = 46,
+  SloppyModeSyntaxInEsmError = 47,
+  /// Whether to emit warning when the specifier of `import()` can't be resolved at build time
+  DynamicImport = 48,
+  /// Whether to emit warning when trailing parameters of an exported function are never referenced
+  UnusedFunctionParameter = 49,
+  /// Whether to emit warning when the same module is both imported and required in one module
+  MixedImportAndRequire = 50,
+  /// Whether to emit warning when the top-level code of a module unconditionally throws
+  ModuleAlwaysThrows = 51,
+  /// Whether to emit warning when modules re-export from each other in a loop
+  CircularReexport = 52,
+  SelfReexportError = 53,
+  CancelledError = 54,
+  /// Whether to emit warning when `module.exports` is reassigned after properties have been assigned to `exports`
+  ModuleExportsReassignment = 55,
+  ExportAssignmentWithExportsError = 56,
+  /// Whether to emit warning when a binding visible to a direct `eval` has to be renamed because its name is already used in the chunk
+  EvalBindingRenamed = 57,
 }
 
 impl Display for EventKind {
@@ -89,6 +134,32 @@ impl Display for EventKind {
       EventKind::PreferBuiltinFeature => write!(f, "PREFER_BUILTIN_FEATURE"),
       EventKind::BundlerInitializeError => write!(f, "BUNDLER_INITIALIZE_ERROR"),
       EventKind::PluginError => write!(f, "PLUGIN_ERROR"),
+      EventKind::NodeGlobalInBrowser => write!(f, "NODE_GLOBAL_IN_BROWSER"),
+      EventKind::RequireInEsmExport => write!(f, "REQUIRE_IN_ESM_EXPORT"),
+      EventKind::SideEffectsInPurePackage => write!(f, "SIDE_EFFECTS_IN_PURE_PACKAGE"),
+      EventKind::ConditionalModuleExportsInEsm => write!(f, "CONDITIONAL_MODULE_EXPORTS_IN_ESM"),
+      EventKind::EsmSyntaxInCommonJs => write!(f, "ESM_SYNTAX_IN_COMMONJS"),
+      EventKind::DeprecatedExport => write!(f, "DEPRECATED_EXPORT"),
+      EventKind::EntryWithoutExports => write!(f, "ENTRY_WITHOUT_EXPORTS"),
+      EventKind::UnusedExportedFunction => write!(f, "UNUSED_EXPORTED_FUNCTION"),
+      EventKind::RequireAsyncModuleError => write!(f, "REQUIRE_ASYNC_MODULE"),
+      EventKind::NestedExportError => write!(f, "NESTED_EXPORT"),
+      EventKind::EmptyDynamicImport => write!(f, "EMPTY_DYNAMIC_IMPORT"),
+      EventKind::CircularImportAtInit => write!(f, "CIRCULAR_IMPORT_AT_INIT"),
+      EventKind::ConstEnumInIsolatedModulesError => write!(f, "CONST_ENUM_IN_ISOLATED_MODULES"),
+      EventKind::DynamicRequire => write!(f, "DYNAMIC_REQUIRE"),
+      EventKind::SideEffectsWithoutTreeshake => write!(f, "SIDE_EFFECTS_WITHOUT_TREESHAKE"),
+      EventKind::SloppyModeSyntaxInEsmError => write!(f, "SLOPPY_MODE_SYNTAX_IN_ESM"),
+      EventKind::DynamicImport => write!(f, "DYNAMIC_IMPORT"),
+      EventKind::UnusedFunctionParameter => write!(f, "UNUSED_FUNCTION_PARAMETER"),
+      EventKind::MixedImportAndRequire => write!(f, "MIXED_IMPORT_AND_REQUIRE"),
+      EventKind::ModuleAlwaysThrows => write!(f, "MODULE_ALWAYS_THROWS"),
+      EventKind::CircularReexport => write!(f, "CIRCULAR_REEXPORT"),
+      EventKind::SelfReexportError => write!(f, "SELF_REEXPORT"),
+      EventKind::CancelledError => write!(f, "CANCELLED"),
+      EventKind::ModuleExportsReassignment => write!(f, "MODULE_EXPORTS_REASSIGNMENT"),
+      EventKind::ExportAssignmentWithExportsError => write!(f, "EXPORT_ASSIGNMENT_WITH_EXPORTS"),
+      EventKind::EvalBindingRenamed => write!(f, "EVAL_BINDING_RENAMED"),
     }
   }
 }
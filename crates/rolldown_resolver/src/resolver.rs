@@ -217,7 +217,7 @@ impl<F: FileSystem> Resolver<F> {
         &self.import_resolver
       }
       ImportKind::NewUrl => &self.new_url_resolver,
-      ImportKind::Require => &self.require_resolver,
+      ImportKind::Require | ImportKind::RequireResolve => &self.require_resolver,
       ImportKind::AtImport | ImportKind::UrlImport => &self.css_resolver,
     };
 
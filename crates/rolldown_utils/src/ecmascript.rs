@@ -131,3 +131,28 @@ pub fn is_path_like_specifier(specifier: &str) -> bool {
     || Path::new(specifier).is_absolute()
     || specifier.starts_with('/') // Though starting with `/` is not a absolute path in Windows, we still consider it as a path-like specifier.
 }
+
+/// Check if the specifier is a bare specifier that refers to a package. E.g. `react`, `lodash/fp`, `@scope/pkg`
+///
+/// Specifiers with a protocol (`node:fs`, `virtual:foo`, `https://...`), subpath imports (`#foo`) and
+/// virtual modules (`\0foo`) are not considered as bare specifiers.
+pub fn is_bare_specifier(specifier: &str) -> bool {
+  !specifier.is_empty()
+    && !is_path_like_specifier(specifier)
+    && !specifier.starts_with(['#', '\0'])
+    && !specifier.contains(':')
+}
+
+#[test]
+fn test_is_bare_specifier() {
+  assert!(is_bare_specifier("react"));
+  assert!(is_bare_specifier("lodash/fp"));
+  assert!(is_bare_specifier("@scope/pkg"));
+  assert!(!is_bare_specifier("./local"));
+  assert!(!is_bare_specifier("../local"));
+  assert!(!is_bare_specifier("/abs/path"));
+  assert!(!is_bare_specifier("node:fs"));
+  assert!(!is_bare_specifier("https://example.com/mod.js"));
+  assert!(!is_bare_specifier("#internal"));
+  assert!(!is_bare_specifier("\0virtual"));
+}
@@ -683,6 +683,13 @@ static MATH_SECOND_PROP: phf::Set<&str> = phf::phf_set![
   "max", "min", "pow", "random", "round", "sign", "sin", "sinh", "sqrt", "tan", "tanh", "trunc",
 ];
 
+/// `Math.random` is excluded, since it changes the internal state of the random number generator
+static MATH_SIDE_EFFECT_FREE_METHODS: phf::Set<&str> = phf::phf_set![
+  "abs", "acos", "acosh", "asin", "asinh", "atan", "atan2", "atanh", "cbrt", "ceil", "clz32",
+  "cos", "cosh", "exp", "expm1", "floor", "fround", "hypot", "imul", "log", "log10", "log1p",
+  "log2", "max", "min", "pow", "round", "sign", "sin", "sinh", "sqrt", "tan", "tanh", "trunc",
+];
+
 /// Console method references are assumed to have no side effects
 /// https://developer.mozilla.org/en-US/docs/Web/API/console
 /// `console`
@@ -798,6 +805,22 @@ pub fn is_global_ident_ref(ident: &str) -> bool {
   GLOBAL_IDENT.contains(ident)
 }
 
+/// Globals that only exist in Node.js and are not available in browsers.
+static NODE_SPECIFIC_GLOBAL_IDENT: phf::Set<&str> = phf::phf_set![
+  "__dirname",
+  "__filename",
+  "Buffer",
+  "clearImmediate",
+  "global",
+  "process",
+  "setImmediate",
+];
+
+#[inline]
+pub fn is_node_specific_global_ident_ref(ident: &str) -> bool {
+  NODE_SPECIFIC_GLOBAL_IDENT.contains(ident)
+}
+
 pub fn is_side_effect_free_member_expr_of_len_two(member_expr: &[Atom]) -> bool {
   match member_expr {
     [first, second] => {
@@ -826,3 +849,20 @@ pub fn is_side_effect_free_member_expr_of_len_three(member_expr: &[Atom]) -> boo
     _ => false,
   }
 }
+
+/// Calling these global functions is side effect free as long as the arguments are side effect free
+pub fn is_side_effect_free_global_call(member_expr: &[Atom]) -> bool {
+  match member_expr {
+    [first, second] => {
+      let second = second.as_str();
+      match first.as_str() {
+        "Math" => MATH_SIDE_EFFECT_FREE_METHODS.contains(second),
+        "Number" => matches!(second, "isFinite" | "isInteger" | "isNaN" | "isSafeInteger"),
+        "Array" => second == "isArray",
+        "String" => matches!(second, "fromCharCode" | "fromCodePoint"),
+        _ => false,
+      }
+    }
+    _ => false,
+  }
+}
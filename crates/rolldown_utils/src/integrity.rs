@@ -0,0 +1,20 @@
+use sha2::{Digest, Sha384};
+
+use crate::base64::to_standard_base64;
+
+/// Returns the [subresource integrity](https://www.w3.org/TR/SRI/) metadata of `input`, e.g.
+/// `sha384-<base64 digest>`, to be used in the `integrity` attribute of `<script>` tags.
+pub fn sha384_integrity(input: &[u8]) -> String {
+  format!("sha384-{}", to_standard_base64(Sha384::digest(input)))
+}
+
+#[test]
+fn test_sha384_integrity() {
+  // Example from https://developer.mozilla.org/en-US/docs/Web/Security/Subresource_Integrity
+  assert_eq!(
+    sha384_integrity(b"alert('Hello, world.');"),
+    "sha384-H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO"
+  );
+  assert_eq!(sha384_integrity(b"console.log(1)"), sha384_integrity(b"console.log(1)"));
+  assert_ne!(sha384_integrity(b"console.log(1)"), sha384_integrity(b"console.log(2)"));
+}
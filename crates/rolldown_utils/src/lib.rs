@@ -9,6 +9,7 @@ pub mod ecmascript;
 pub mod futures;
 pub mod global_reference;
 pub mod indexmap;
+pub mod integrity;
 pub mod light_guess;
 pub mod mime;
 pub mod percent_encoding;
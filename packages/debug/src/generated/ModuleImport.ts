@@ -6,6 +6,7 @@ export type ModuleImport = {
    * - `import-statement`: `import { foo } from './lib.js';`
    * - `dynamic-import`: `import('./lib.js')`
    * - `require-call`: `require('./lib.js')`
+   * - `require-resolve`: `require.resolve('./lib.js')`
    * - `import-rule`: `@import 'bg-color.css'`
    * - `url-token`: `url('./icon.png')`
    * - `new-url`: `new URL('./worker.js', import.meta.url)`
@@ -15,6 +16,7 @@ export type ModuleImport = {
     | 'import-statement'
     | 'dynamic-import'
     | 'require-call'
+    | 'require-resolve'
     | 'import-rule'
     | 'url-token'
     | 'new-url'
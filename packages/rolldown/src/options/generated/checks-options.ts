@@ -79,4 +79,118 @@ export interface ChecksOptions {
    * @default true
    */
   preferBuiltinFeature?: boolean;
+
+  /**
+   * Whether to emit warning when Node.js globals like `process` or `Buffer` are referenced when `platform` is `browser`
+   * @default false
+   */
+  nodeGlobalInBrowser?: boolean;
+
+  /**
+   * Whether to emit warning when an ESM export is initialized with a CommonJS `require()` call
+   * @default false
+   */
+  requireInEsmExport?: boolean;
+
+  /**
+   * Whether to emit warning when a module declared as `sideEffects: false` in package.json contains top-level statements with side effects
+   * @default false
+   */
+  sideEffectsInPurePackage?: boolean;
+
+  /**
+   * Whether to emit warning when `module.exports` is conditionally assigned in an ES module
+   * @default true
+   */
+  conditionalModuleExportsInEsm?: boolean;
+
+  /**
+   * Whether to emit warning when ESM `import` or `export` syntax is used in a `.cjs` or `.cts` module
+   * @default false
+   */
+  esmSyntaxInCommonJs?: boolean;
+
+  /**
+   * Whether to emit warning when importing an export annotated with `@deprecated` JSDoc
+   * @default true
+   */
+  deprecatedExport?: boolean;
+
+  /**
+   * Whether to emit warning when an entry module of an `esm` or `cjs` build has no exports
+   * @default false
+   */
+  entryWithoutExports?: boolean;
+
+  /**
+   * Whether to emit warning when an exported function declaration is never referenced within its module
+   * @default false
+   */
+  unusedExportedFunction?: boolean;
+
+  /**
+   * Whether to emit warning when a dynamic import targets a module that is empty after tree shaking
+   * @default false
+   */
+  emptyDynamicImport?: boolean;
+
+  /**
+   * Whether to emit warning when an imported binding is used during module initialization before the circular import providing it has been executed
+   * @default false
+   */
+  circularImportAtInit?: boolean;
+
+  /**
+   * Whether to emit warning when the argument of `require()` can't be resolved at build time
+   * @default false
+   */
+  dynamicRequire?: boolean;
+
+  /**
+   * Whether to emit warning when a package declares `"sideEffects": false` while `treeshake` is disabled
+   * @default false
+   */
+  sideEffectsWithoutTreeshake?: boolean;
+
+  /**
+   * Whether to emit warning when the specifier of `import()` can't be resolved at build time
+   * @default false
+   */
+  dynamicImport?: boolean;
+
+  /**
+   * Whether to emit warning when trailing parameters of an exported function are never referenced
+   * @default false
+   */
+  unusedFunctionParameter?: boolean;
+
+  /**
+   * Whether to emit warning when the same module is both imported and required in one module
+   * @default false
+   */
+  mixedImportAndRequire?: boolean;
+
+  /**
+   * Whether to emit warning when the top-level code of a module unconditionally throws
+   * @default false
+   */
+  moduleAlwaysThrows?: boolean;
+
+  /**
+   * Whether to emit warning when modules re-export from each other in a loop
+   * @default false
+   */
+  circularReexport?: boolean;
+
+  /**
+   * Whether to emit warning when `module.exports` is reassigned after properties have been assigned to `exports`
+   * @default false
+   */
+  moduleExportsReassignment?: boolean;
+
+  /**
+   * Whether to emit warning when a binding visible to a direct `eval` has to be renamed because its name is already used in the chunk
+   * @default true
+   */
+  evalBindingRenamed?: boolean;
 }
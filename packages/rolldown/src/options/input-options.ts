@@ -111,6 +111,25 @@ export type OptimizationOptions = {
    * @default false
    */
   inlineConst?: boolean | { mode?: 'all' | 'smart'; pass?: number };
+  /**
+   * Drop the reverse mappings of TypeScript numeric enums when they are never used.
+   *
+   * TypeScript numeric enums are compiled with reverse mappings (`Enum[Enum["A"] = 0] = "A"`), so
+   * that `Enum[0]` evaluates to `'A'`. When an enum is only accessed by its member names, e.g.
+   * `Enum.A` or `Enum['A']`, the reverse mappings are dropped. Enums that are exported or passed
+   * around, e.g. `Object.keys(Enum)`, are always kept as they are.
+   *
+   * @default false
+   */
+  trimEnumReverseMappings?: boolean;
+  /**
+   * The longest static property chain on an import, e.g. `ns.a.b`, that is tracked as a single
+   * member access so unused exports can be tree shaken. The import is referenced as a whole for
+   * longer chains, which only show up in generated code.
+   *
+   * @default 64
+   */
+  maxMemberExprChainLength?: number;
 };
 
 export type AttachDebugOptions = 'none' | 'simple' | 'full';
@@ -191,6 +210,18 @@ export interface InputOptions {
     disableLiveBindings?: boolean;
     viteMode?: boolean;
     resolveNewUrlToAsset?: boolean;
+    /**
+     * A `new URL('./path', import.meta.url)` with a leading block comment containing this text is left to be resolved at runtime instead of being emitted as an asset.
+     *
+     * - Default: `'@url-ignore'`
+     */
+    newUrlIgnoreComment?: string;
+    /**
+     * Emit the file referenced by `require.resolve('./path')` as an asset and rewrite the path to the emitted file.
+     *
+     * - Default: `false`
+     */
+    resolveRequireResolveToAsset?: boolean;
     hmr?: HmrOptions;
     /**
      * Control which order should use when rendering modules in chunk
@@ -272,6 +303,23 @@ export interface InputOptions {
      */
     incrementalBuild?: boolean;
     transformHiresSourcemap?: boolean | 'boundary';
+    /**
+     * Report semantic errors of modules as warnings instead of failing the build.
+     *
+     * Semantic errors, e.g. a redeclared `let` binding or a duplicate export, are fatal by default.
+     * Enable this to bundle such code anyway, the output of the affected modules might be broken.
+     *
+     * @default false
+     */
+    semanticErrorsAsWarnings?: boolean;
+    /**
+     * Keep the parentheses of the source, e.g. `(a, b).c` or `(0, fn)()`, when tree shaking without minification.
+     *
+     * The dead code elimination run before bundling removes parentheses. With this option, it's replaced by a lighter pass that only removes `if` branches with a constant test, e.g. `if (false) { ... }`.
+     *
+     * @default false
+     */
+    preserveParens?: boolean;
   };
   /**
    * Replace global variables or [property accessors](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Property_accessors) with the provided values.
@@ -320,6 +368,19 @@ export interface InputOptions {
    * ```
    */
   define?: Record<string, string>;
+  /**
+   * Replace `import.meta.env.*` accesses with static values, like Vite does.
+   *
+   * `import.meta.env.KEY` is replaced with the value of `KEY` as a string literal, and any other
+   * reference to `import.meta.env`, e.g. `import.meta.env[key]`, is replaced with an object literal
+   * containing all values.
+   *
+   * @example
+   * ```js
+   * export default defineConfig({ importMetaEnv: { MODE: 'production' } })
+   * ```
+   */
+  importMetaEnv?: Record<string, string>;
   /**
    * Inject import statements on demand.
    *
@@ -379,6 +440,21 @@ export interface InputOptions {
   transform?: OxcTransformOption;
   watch?: WatcherOptions | false;
   dropLabels?: string[];
+  /**
+   * Remove `console.*(...)` calls whose return value isn't used, e.g. `console.log(x);`.
+   *
+   * Only calls on the global `console` are removed, calls through an alias like
+   * `const c = console; c.log(x)` are kept.
+   *
+   * @default false
+   */
+  dropConsole?: boolean;
+  /**
+   * Remove `debugger` statements.
+   *
+   * @default false
+   */
+  dropDebugger?: boolean;
   keepNames?: boolean;
   checks?: ChecksOptions;
   makeAbsoluteExternalsRelative?: MakeAbsoluteExternalsRelative;
@@ -403,6 +479,46 @@ export interface InputOptions {
    * and the tsconfig options will be merged with the top-level `transform` options, with the `transform` options taking precedence.
    */
   tsconfig?: string;
+  /**
+   * - Type: `'error' | 'warn' | 'wrap'`
+   * - Default: `'error'`
+   *
+   * How to handle a `require()` of an ES module that uses top-level await. Such a module can't be
+   * loaded synchronously, so Node.js throws `ERR_REQUIRE_ASYNC_MODULE` for it.
+   *
+   * - `'error'`: fail the build.
+   * - `'warn'`: emit a warning and keep the synchronous `require()`, which may observe exports
+   *   that are not initialized yet.
+   * - `'wrap'`: rewrite the `require()` to return a promise that resolves to the module's exports.
+   */
+  requireEsmInterop?: 'error' | 'warn' | 'wrap';
+  /**
+   * - Type: `string[]`
+   * - Default: `[]`
+   *
+   * Module requests starting with one of these prefixes, e.g. `'virtual:'` or `'\0'`, refer to
+   * virtual modules. They must be resolved by a plugin's `resolveId` hook: if no plugin resolves
+   * one, the build fails instead of treating it as an external dependency.
+   */
+  virtualPrefixes?: string[];
+  /**
+   * - Type: `string[]`
+   * - Default: `[]`
+   *
+   * Names of entries in `input` that only run side effects, e.g. a CLI. With
+   * `checks.entryWithoutExports` enabled, these entries aren't reported for having no exports.
+   */
+  sideEffectEntries?: string[];
+  /**
+   * - Type: `boolean`
+   * - Default: `false`
+   *
+   * Fail the build on TypeScript constructs that can't be transpiled one file at a time, the same
+   * way as [`isolatedModules`](https://www.typescriptlang.org/tsconfig/#isolatedModules) does:
+   * - `const enum` declarations, whose members would have to be inlined into other modules.
+   * - Re-exports of types without the `type` modifier, e.g. `export { SomeType } from './types'`.
+   */
+  isolatedModules?: boolean;
 }
 
 interface OverwriteInputOptionsForCli {
@@ -124,6 +124,25 @@ export interface OutputOptions {
   intro?: string | AddonFunction;
   outro?: string | AddonFunction;
   extend?: boolean;
+  /**
+   * Define the `name` of `iife` output on `globalThis` instead of `this`, or on `window` when
+   * `platform` is `'browser'`. `this` is `undefined` when the output is evaluated as an ES module
+   * or in strict mode, so namespaced names like `a.b.c` can't be created on it there.
+   *
+   * - Type: `boolean`
+   * - Default: `false`
+   */
+  globalThis?: boolean;
+  /**
+   * Insert a `;` before a module whose code starts with `(`, `[` or `` ` ``, e.g. an IIFE. Without
+   * it, such a module continues the statement before it when the chunk is concatenated with code
+   * that doesn't end with a semicolon, like a `banner` or another script. The wrapper of `iife` and
+   * `umd` chunks is guarded the same way.
+   *
+   * - Type: `boolean`
+   * - Default: `false`
+   */
+  defensiveSemicolons?: boolean;
   esModule?: boolean | 'if-default-prop';
   assetFileNames?: string | AssetFileNamesFunction;
   entryFileNames?: string | ChunkFileNamesFunction;
@@ -395,6 +414,37 @@ export interface OutputOptions {
    * - `inline`: preserve comments that contain `@license`, `@preserve` or starts with `//!` `/*!`
    */
   legalComments?: 'none' | 'inline';
+  /**
+   * Keep the comments matching this pattern in the output, in addition to legal comments.
+   *
+   * The pattern is tested against the whole comment, including the `//` or `/*` delimiters. Comments
+   * attached to code that gets removed are dropped along with it.
+   *
+   * @example
+   * ```js
+   * export default {
+   *   output: {
+   *     keepComments: /@custom/,
+   *   },
+   * }
+   * ```
+   */
+  keepComments?: RegExp;
+  /**
+   * Variables whose original names match this pattern keep their names when the output is
+   * minified, e.g. to find them in minified output without a source map.
+   *
+   * @example
+   * ```js
+   * export default {
+   *   output: {
+   *     minify: true,
+   *     keepVarNames: /^__DEBUG_/,
+   *   },
+   * }
+   * ```
+   */
+  keepVarNames?: RegExp;
   plugins?: RolldownOutputPluginOption;
   polyfillRequire?: boolean;
   hoistTransitiveImports?: false;
@@ -409,6 +459,58 @@ export interface OutputOptions {
    * Whether to minify internal exports.
    */
   minifyInternalExports?: boolean;
+  /**
+   * - Type: `boolean`
+   * - Default: `false`
+   *
+   * Whether to add `Symbol.toStringTag` to synthesized namespace objects, so that
+   * `Object.prototype.toString.call(ns)` returns `[object Module]`.
+   */
+  namespaceToStringTag?: boolean;
+  /**
+   * - Type: `boolean`
+   * - Default: `false`
+   *
+   * Whether to re-parse each generated chunk and fail the build if it isn't valid JavaScript. This
+   * adds a parse per chunk, so it's mostly useful to catch bugs in code generation or in plugins
+   * that modify the output in `renderChunk`.
+   */
+  validate?: boolean;
+  /**
+   * - Type: `string`
+   *
+   * Emit a JSON asset with this file name that lists the exports of each chunk, e.g. for SSR
+   * manifests. It maps the file name of each chunk to an object from the original export names to
+   * the names used in the output, which differ e.g. with `minifyInternalExports`.
+   *
+   * ```json
+   * { "shared-Bk2f3a.js": { "formatDate": "t" } }
+   * ```
+   */
+  exportsManifest?: string;
+  /**
+   * - Type: `boolean`
+   * - Default: `false`
+   *
+   * Compute a [subresource integrity](https://developer.mozilla.org/en-US/docs/Web/Security/Subresource_Integrity)
+   * hash of the final code of each chunk and expose it as `integrity` on the output chunk, e.g.
+   * `sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC`.
+   */
+  integrity?: boolean;
+  /**
+   * - Type: `boolean`
+   * - Default: `false`
+   *
+   * Allow top-level await with the `cjs` format. Chunks containing modules that use top-level
+   * await, directly or through their imports, are wrapped in an async IIFE and assign their
+   * exports once it settles. The promise of the IIFE is exposed as the non-enumerable `__tla`
+   * export, which the chunks requiring such a chunk await, so entries requiring it are wrapped as
+   * well. Code outside of the bundle should `await require('./chunk.js').__tla` before reading the
+   * exports. Errors thrown while evaluating the chunk are rethrown as uncaught exceptions.
+   *
+   * The `esm` format always supports top-level await, `iife` and `umd` never do.
+   */
+  topLevelAwait?: boolean;
 }
 
 interface OverwriteOutputOptionsForCli {
@@ -14,7 +14,23 @@ type ModuleSideEffectsOption =
 export type TreeshakingOptions = {
   moduleSideEffects?: ModuleSideEffectsOption;
   annotations?: boolean;
+  /**
+   * Functions whose calls are side effect free, so that unused calls are dropped, like
+   * `pure_funcs` of Terser. A plain name like `styled` also covers `styled.div()` and
+   * `styled()()`, a member path like `console.log` covers that member and the ones below it.
+   */
   manualPureFunctions?: readonly string[];
+  /**
+   * Global member paths whose reads are side effect free, e.g. `document` or `document.cookie`.
+   * An entry also covers the members below it.
+   */
+  pureGlobals?: readonly string[];
+  /**
+   * Extra comments that mark a call or `new` expression as pure, in addition to `#__PURE__` and
+   * `@__PURE__`, e.g. `#PURE#` for a `#PURE#` block comment in front of the call. Compared with the
+   * trimmed comment text.
+   */
+  pureAnnotations?: readonly string[];
   unknownGlobalSideEffects?: boolean;
   commonjs?: boolean;
   propertyReadSideEffects?: false | 'always';
@@ -66,6 +66,8 @@ export interface OutputChunk {
   map: SourceMap | null;
   sourcemapFileName: string | null;
   preliminaryFileName: string;
+  /** `sha384` subresource integrity hash of `code`, set when `output.integrity` is enabled. */
+  integrity: string | null;
 }
 
 export interface RolldownOutput {
@@ -89,6 +89,9 @@ export function bindingifyInputOptions(
     define: inputOptions.define
       ? Object.entries(inputOptions.define)
       : undefined,
+    importMetaEnv: inputOptions.importMetaEnv
+      ? Object.entries(inputOptions.importMetaEnv)
+      : undefined,
     inject: bindingifyInject(inputOptions.inject),
     experimental: bindingifyExperimental(inputOptions.experimental),
     profilerNames: inputOptions?.profilerNames,
@@ -96,6 +99,8 @@ export function bindingifyInputOptions(
     transform,
     watch: bindingifyWatch(inputOptions.watch),
     dropLabels: inputOptions.dropLabels,
+    dropConsole: inputOptions.dropConsole,
+    dropDebugger: inputOptions.dropDebugger,
     keepNames: inputOptions.keepNames,
     checks: inputOptions.checks,
     deferSyncScanData: () => {
@@ -121,6 +126,10 @@ export function bindingifyInputOptions(
     optimization: inputOptions.optimization,
     context: inputOptions.context,
     tsconfig: inputOptions.resolve?.tsconfigFilename ?? inputOptions.tsconfig,
+    requireEsmInterop: inputOptions.requireEsmInterop,
+    virtualPrefixes: inputOptions.virtualPrefixes,
+    sideEffectEntries: inputOptions.sideEffectEntries,
+    isolatedModules: inputOptions.isolatedModules,
   };
 }
 
@@ -187,6 +196,8 @@ function bindingifyExperimental(
     disableLiveBindings: experimental?.disableLiveBindings,
     viteMode: experimental?.viteMode,
     resolveNewUrlToAsset: experimental?.resolveNewUrlToAsset,
+    newUrlIgnoreComment: experimental?.newUrlIgnoreComment,
+    resolveRequireResolveToAsset: experimental?.resolveRequireResolveToAsset,
     hmr: bindingifyHmr(experimental?.hmr),
     attachDebugInfo: bindingifyAttachDebugInfo(
       experimental?.attachDebugInfo,
@@ -195,6 +206,8 @@ function bindingifyExperimental(
     chunkImportMap: experimental?.chunkImportMap,
     onDemandWrapping: experimental?.onDemandWrapping,
     incrementalBuild: experimental?.incrementalBuild,
+    semanticErrorsAsWarnings: experimental?.semanticErrorsAsWarnings,
+    preserveParens: experimental?.preserveParens,
   };
 }
 
@@ -397,6 +410,8 @@ function bindingifyTreeshakeOptions(
     moduleSideEffects: true,
     annotations: config.annotations,
     manualPureFunctions: config.manualPureFunctions,
+    pureGlobals: config.pureGlobals,
+    pureAnnotations: config.pureAnnotations,
     unknownGlobalSideEffects: config.unknownGlobalSideEffects,
     commonjs: config.commonjs,
   };
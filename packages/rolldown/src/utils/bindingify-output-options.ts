@@ -63,6 +63,8 @@ export function bindingifyOutputOptions(
     intro: bindingifyAddon(intro),
     outro: bindingifyAddon(outro),
     extend: outputOptions.extend,
+    globalThis: outputOptions.globalThis,
+    defensiveSemicolons: outputOptions.defensiveSemicolons,
     globals,
     generatedCode,
     esModule,
@@ -83,9 +85,16 @@ export function bindingifyOutputOptions(
     preserveModules,
     virtualDirname,
     legalComments,
+    keepComments: outputOptions.keepComments,
+    keepVarNames: outputOptions.keepVarNames,
     preserveModulesRoot,
     topLevelVar,
     minifyInternalExports: outputOptions.minifyInternalExports,
+    namespaceToStringTag: outputOptions.namespaceToStringTag,
+    validate: outputOptions.validate,
+    exportsManifest: outputOptions.exportsManifest,
+    integrity: outputOptions.integrity,
+    topLevelAwait: outputOptions.topLevelAwait,
   };
 }
 
@@ -72,6 +72,7 @@ function transformToRollupOutputChunk(
     },
     sourcemapFileName: bindingChunk.sourcemapFileName || null,
     preliminaryFileName: bindingChunk.preliminaryFileName,
+    integrity: bindingChunk.integrity || null,
   } as OutputChunk;
   const cache: Record<string | symbol, any> = {};
   return new Proxy(chunk, {
@@ -289,6 +289,120 @@ const ChecksOptionsSchema = v.strictObject({
       'Whether to emit warning when detecting prefer builtin feature',
     ),
   ),
+  nodeGlobalInBrowser: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when Node.js globals like `process` or `Buffer` are referenced when `platform` is `browser`',
+    ),
+  ),
+  requireInEsmExport: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when an ESM export is initialized with a CommonJS `require()` call',
+    ),
+  ),
+  sideEffectsInPurePackage: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when a module declared as `sideEffects: false` in package.json contains top-level statements with side effects',
+    ),
+  ),
+  conditionalModuleExportsInEsm: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when `module.exports` is conditionally assigned in an ES module',
+    ),
+  ),
+  esmSyntaxInCommonJs: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when ESM `import` or `export` syntax is used in a `.cjs` or `.cts` module',
+    ),
+  ),
+  deprecatedExport: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when importing an export annotated with `@deprecated` JSDoc',
+    ),
+  ),
+  entryWithoutExports: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when an entry module of an `esm` or `cjs` build has no exports',
+    ),
+  ),
+  unusedExportedFunction: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when an exported function declaration is never referenced within its module',
+    ),
+  ),
+  emptyDynamicImport: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when a dynamic import targets a module that is empty after tree shaking',
+    ),
+  ),
+  circularImportAtInit: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when an imported binding is used during module initialization before the circular import providing it has been executed',
+    ),
+  ),
+  dynamicRequire: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      "Whether to emit warning when the argument of `require()` can't be resolved at build time",
+    ),
+  ),
+  sideEffectsWithoutTreeshake: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when a package declares `"sideEffects": false` while `treeshake` is disabled',
+    ),
+  ),
+  dynamicImport: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      "Whether to emit warning when the specifier of `import()` can't be resolved at build time",
+    ),
+  ),
+  unusedFunctionParameter: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when trailing parameters of an exported function are never referenced',
+    ),
+  ),
+  mixedImportAndRequire: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when the same module is both imported and required in one module',
+    ),
+  ),
+  moduleAlwaysThrows: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when the top-level code of a module unconditionally throws',
+    ),
+  ),
+  circularReexport: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when modules re-export from each other in a loop',
+    ),
+  ),
+  moduleExportsReassignment: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when `module.exports` is reassigned after properties have been assigned to `exports`',
+    ),
+  ),
+  evalBindingRenamed: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warning when a binding visible to a direct `eval` has to be renamed because its name is already used in the chunk',
+    ),
+  ),
 });
 
 const CompressOptionsKeepNamesSchema = v.strictObject({
@@ -362,6 +476,8 @@ const TreeshakingOptionsSchema = v.union([
   v.looseObject({
     annotations: v.optional(v.boolean()),
     manualPureFunctions: v.optional(v.array(v.string())),
+    pureGlobals: v.optional(v.array(v.string())),
+    pureAnnotations: v.optional(v.array(v.string())),
     unknownGlobalSideEffects: v.optional(v.boolean()),
     commonjs: v.optional(v.boolean()),
     propertyReadSideEffects: v.optional(
@@ -389,6 +505,16 @@ const OptimizationOptionsSchema = v.strictObject({
     v.optional(v.boolean()),
     v.description('Use PIFE pattern for module wrappers'),
   ),
+  trimEnumReverseMappings: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Drop unused reverse mappings of TypeScript enums'),
+  ),
+  maxMemberExprChainLength: v.pipe(
+    v.optional(v.number()),
+    v.description(
+      'The longest property chain on an import that is tracked for tree shaking',
+    ),
+  ),
 });
 
 const OnLogSchema = v.pipe(
@@ -483,9 +609,13 @@ const InputOptionsSchema = v.strictObject({
       enableComposingJsPlugins: v.optional(v.boolean()),
       viteMode: v.optional(v.boolean()),
       resolveNewUrlToAsset: v.optional(v.boolean()),
+      newUrlIgnoreComment: v.optional(v.string()),
+      resolveRequireResolveToAsset: v.optional(v.boolean()),
       strictExecutionOrder: v.optional(v.boolean()),
       onDemandWrapping: v.optional(v.boolean()),
       incrementalBuild: v.optional(v.boolean()),
+      semanticErrorsAsWarnings: v.optional(v.boolean()),
+      preserveParens: v.optional(v.boolean()),
       hmr: v.optional(HmrSchema),
       attachDebugInfo: v.optional(v.union([
         v.literal('none'),
@@ -509,6 +639,10 @@ const InputOptionsSchema = v.strictObject({
     v.optional(v.record(v.string(), v.string())),
     v.description('Define global variables'),
   ),
+  importMetaEnv: v.pipe(
+    v.optional(v.record(v.string(), v.string())),
+    v.description('Replace `import.meta.env.*` with static values'),
+  ),
   inject: v.optional(
     v.record(
       v.string(),
@@ -531,6 +665,14 @@ const InputOptionsSchema = v.strictObject({
     v.optional(v.array(v.string())),
     v.description('Remove labeled statements with these label names'),
   ),
+  dropConsole: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Remove `console.*(...)` statements'),
+  ),
+  dropDebugger: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Remove `debugger` statements'),
+  ),
   checks: v.optional(ChecksOptionsSchema),
   keepNames: v.pipe(
     v.optional(v.boolean()),
@@ -559,6 +701,28 @@ const InputOptionsSchema = v.strictObject({
     v.optional(v.string()),
     v.description('Path to the tsconfig.json file.'),
   ),
+  requireEsmInterop: v.pipe(
+    v.optional(v.union([
+      v.literal('error'),
+      v.literal('warn'),
+      v.literal('wrap'),
+    ])),
+    v.description('How to handle `require()` of modules using top-level await'),
+  ),
+  virtualPrefixes: v.pipe(
+    v.optional(v.array(v.string())),
+    v.description('Module request prefixes that identify virtual modules'),
+  ),
+  sideEffectEntries: v.pipe(
+    v.optional(v.array(v.string())),
+    v.description('Names of entries that only run side effects'),
+  ),
+  isolatedModules: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      "Error on TypeScript constructs that can't be transpiled per file",
+    ),
+  ),
 });
 
 const InputCliOverrideSchema = v.strictObject({
@@ -801,6 +965,18 @@ const OutputOptionsSchema = v.strictObject({
       'Extend global variable defined by name in IIFE / UMD formats',
     ),
   ),
+  globalThis: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Define the name of IIFE output on `globalThis` (or `window` for browser) instead of `this`',
+    ),
+  ),
+  defensiveSemicolons: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Insert a semicolon before modules starting with `(`, `[` or a template literal',
+    ),
+  ),
   esModule: v.optional(v.union([v.boolean(), v.literal('if-default-prop')])),
   assetFileNames: v.optional(AssetFileNamesSchema),
   entryFileNames: v.optional(ChunkFileNamesSchema),
@@ -852,6 +1028,14 @@ const OutputOptionsSchema = v.strictObject({
     v.optional(v.union([v.literal('none'), v.literal('inline')])),
     v.description('Control comments in the output'),
   ),
+  keepComments: v.pipe(
+    v.optional(v.instance(RegExp)),
+    v.description('Keep comments matching this pattern in the output'),
+  ),
+  keepVarNames: v.pipe(
+    v.optional(v.instance(RegExp)),
+    v.description('Keep the names of variables matching this pattern when minifying'),
+  ),
   plugins: v.optional(v.custom<RolldownOutputPluginOption>(() => true)),
   polyfillRequire: v.pipe(
     v.optional(v.boolean()),
@@ -882,6 +1066,28 @@ const OutputOptionsSchema = v.strictObject({
     v.optional(v.boolean()),
     v.description('Rewrite top-level declarations to use `var`.'),
   ),
+  namespaceToStringTag: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Add `Symbol.toStringTag` to namespace objects'),
+  ),
+  validate: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Re-parse generated chunks to validate the output'),
+  ),
+  exportsManifest: v.pipe(
+    v.optional(v.string()),
+    v.description('File name of the emitted chunk exports manifest'),
+  ),
+  integrity: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Compute a sha384 integrity hash for each chunk'),
+  ),
+  topLevelAwait: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Allow top-level await in `cjs` output by wrapping chunks in an async IIFE',
+    ),
+  ),
 });
 
 const getAddonDescription = (
@@ -981,6 +1187,8 @@ const OutputCliOptionsSchema = v.omit(
     'sourcemapPathTransform',
     'plugins',
     'hoistTransitiveImports',
+    'keepComments',
+    'keepVarNames',
   ],
 );
 
@@ -0,0 +1,14 @@
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    external: /\.node$/,
+    output: {
+      format: 'cjs',
+    },
+  },
+  afterTest(output) {
+    expect(output.output[0].code).toMatch(/require\("[^"]*addon\.node"\)/)
+  },
+})
@@ -0,0 +1,20 @@
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+import { getOutputChunk } from 'rolldown-tests/utils'
+
+export default defineTest({
+  config: {
+    input: 'main.jsx',
+    jsx: {
+      mode: 'automatic',
+      jsxImportSource: 'react',
+    },
+    external: ['preact/jsx-runtime', 'react/jsx-runtime'],
+  },
+  afterTest: (output) => {
+    // The `@jsxImportSource` comment in the file wins over the option
+    const chunk = getOutputChunk(output)[0]
+    expect(chunk.code.includes('preact/jsx-runtime')).toBe(true)
+    expect(chunk.code.includes('react/jsx-runtime')).toBe(false)
+  },
+})
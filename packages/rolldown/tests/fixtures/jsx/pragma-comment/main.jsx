@@ -0,0 +1,2 @@
+/* @jsxImportSource preact */
+console.log(<div>test</div>)
@@ -0,0 +1,26 @@
+import type { RollupLog } from 'rolldown'
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+const warnings: RollupLog[] = []
+
+export default defineTest({
+  config: {
+    onwarn(warning) {
+      warnings.push(warning)
+    },
+  },
+  beforeTest: () => {
+    warnings.length = 0
+  },
+  afterTest: async () => {
+    // Both modules use `eval` and declare `value`, only the first one can keep the name
+    const renamed = warnings.filter((warning) => warning.code === 'EVAL_BINDING_RENAMED')
+    expect(renamed).toHaveLength(1)
+    expect(renamed[0].message).toMatch(/^`value` in '.*b\.js' is visible to a direct `eval`/)
+    const mod = await import('./dist/main.js' as string)
+    expect(mod.readA()).toBe('a')
+    // The binding of `b.js` is renamed, so its `eval` sees the one of `a.js`
+    expect(mod.readB()).toBe('a')
+  },
+})
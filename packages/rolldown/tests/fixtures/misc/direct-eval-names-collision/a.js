@@ -0,0 +1,5 @@
+const value = 'a'
+
+export function readA() {
+  return eval('value')
+}
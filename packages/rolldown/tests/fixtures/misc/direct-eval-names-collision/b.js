@@ -0,0 +1,5 @@
+const value = 'b'
+
+export function readB() {
+  return eval('value')
+}
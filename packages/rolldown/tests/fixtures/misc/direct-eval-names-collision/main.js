@@ -0,0 +1,2 @@
+export { readA } from './a.js'
+export { readB } from './b.js'
@@ -0,0 +1,21 @@
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+const warnings: string[] = []
+
+export default defineTest({
+  config: {
+    onwarn(warning) {
+      warnings.push(warning.code!)
+    },
+  },
+  afterTest: async () => {
+    expect(warnings).toStrictEqual(['EVAL'])
+    const mod = await import('./dist/main.js' as string)
+    expect(mod.value).toBe('main')
+    expect(mod.local).toBe('main')
+    expect(mod.readFromEval()).toStrictEqual(['module', 'local'])
+    // Scopes that can't be seen from the `eval` are still deconflicted
+    expect(mod.unrelated()).toBe('nested')
+  },
+})
@@ -0,0 +1,11 @@
+const value = 'module'
+
+export function readFromEval() {
+  const local = 'local'
+  return eval('[value, local]')
+}
+
+export function unrelated() {
+  const value = 'nested'
+  return value
+}
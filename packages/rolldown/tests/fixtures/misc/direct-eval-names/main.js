@@ -0,0 +1,4 @@
+export { readFromEval, unrelated } from './eval.js'
+
+export const value = 'main'
+export const local = 'main'
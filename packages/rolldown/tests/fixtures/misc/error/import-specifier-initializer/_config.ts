@@ -0,0 +1,18 @@
+import { defineTest } from 'rolldown-tests'
+import { stripVTControlCharacters } from 'util'
+import { expect } from 'vitest'
+
+// `import { x = 1 }` is not valid syntax, the parser rejects it before the scanner sees it.
+export default defineTest({
+  afterTest() {
+    expect.unreachable('import specifiers cannot have an initializer')
+  },
+  catchError(e: any) {
+    expect(e.errors).toEqual([
+      expect.objectContaining({
+        kind: 'PARSE_ERROR',
+      }),
+    ])
+    expect(stripVTControlCharacters(e.message)).toContain('main.js')
+  },
+})
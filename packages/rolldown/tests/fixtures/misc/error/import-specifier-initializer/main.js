@@ -0,0 +1,3 @@
+import { x = 1 } from './m'
+
+console.log(x)
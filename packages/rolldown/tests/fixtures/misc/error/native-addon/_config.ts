@@ -0,0 +1,11 @@
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {},
+  catchError(e: any) {
+    expect(e.errors[0]).toMatchObject({ code: 'RESOLVE_ERROR' })
+    expect(e.message).toContain("Native addons can't be bundled.")
+    expect(e.message).toContain('external')
+  },
+})
@@ -0,0 +1,2 @@
+const addon = require('./addon.node')
+console.log(addon)
@@ -0,0 +1,28 @@
+import type { OutputAsset, OutputChunk } from 'rolldown'
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      format: 'cjs',
+    },
+    experimental: {
+      resolveRequireResolveToAsset: true,
+    },
+  },
+  afterTest(output) {
+    const asset = output.output.find(
+      (item): item is OutputAsset =>
+        item.type === 'asset' && item.fileName.startsWith('assets/worker-'),
+    )
+    expect(asset).toBeDefined()
+    const main = output.output.find(
+      (item): item is OutputChunk =>
+        item.type === 'chunk' && item.fileName === 'main.js',
+    )
+    expect(main!.code).toContain(`require.resolve("./${asset!.fileName}")`)
+    // Kept as it is since the path depends on the runtime `paths` option
+    expect(main!.code).toContain(`require.resolve("./worker.js", { paths })`)
+  },
+})
@@ -0,0 +1,5 @@
+export const workerPath = require.resolve('./worker.js')
+
+export function resolveFrom(paths) {
+  return require.resolve('./worker.js', { paths })
+}
@@ -0,0 +1,25 @@
+import type { RollupLog } from 'rolldown'
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+const warnings: RollupLog[] = []
+
+export default defineTest({
+  config: {
+    onwarn(warning) {
+      warnings.push(warning)
+    },
+    checks: {
+      circularImportAtInit: true,
+    },
+  },
+  beforeTest: () => {
+    warnings.length = 0
+  },
+  afterTest: () => {
+    // `b.js` is executed before `a.js`, only its usage of `a` is a problem
+    expect(warnings).toHaveLength(1)
+    expect(warnings[0].code).toBe('CIRCULAR_IMPORT_AT_INIT')
+    expect(warnings[0].message).toMatch(/"default" imported from ".*a\.js" is used by ".*b\.js"/)
+  },
+})
@@ -0,0 +1,22 @@
+import type { RollupLog } from 'rolldown'
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+const warnings: RollupLog[] = []
+
+export default defineTest({
+  config: {
+    onwarn(warning) {
+      warnings.push(warning)
+    },
+    checks: {
+      circularImportAtInit: true,
+    },
+  },
+  beforeTest: () => {
+    warnings.length = 0
+  },
+  afterTest: () => {
+    expect(warnings).toHaveLength(0)
+  },
+})
@@ -0,0 +1,38 @@
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: ['main.js', 'other.js'],
+    output: {
+      minifyInternalExports: true,
+      exportsManifest: 'exports-manifest.json',
+    },
+  },
+  afterTest: (output) => {
+    const manifestAsset = output.output.find(
+      (item) => item.type === 'asset' && item.fileName === 'exports-manifest.json',
+    )
+    expect(manifestAsset).toBeDefined()
+    const manifest = JSON.parse(
+      manifestAsset!.type === 'asset' ? manifestAsset!.source.toString() : '',
+    )
+
+    expect(manifest['main.js']).toStrictEqual({ main: 'main' })
+    expect(manifest['other.js']).toStrictEqual({ other: 'other' })
+
+    const sharedChunk = output.output.find(
+      (item) => item.type === 'chunk' && !item.isEntry,
+    )!
+    const sharedExports = manifest[sharedChunk.fileName]
+    expect(Object.keys(sharedExports).sort()).toStrictEqual([
+      'formatDate',
+      'parseDate',
+    ])
+    // The shared chunk uses minified names, which the manifest maps back
+    expect(Object.values(sharedExports).sort()).toStrictEqual(
+      [...(sharedChunk.type === 'chunk' ? sharedChunk.exports : [])].sort(),
+    )
+    expect(sharedExports.formatDate).not.toBe('formatDate')
+  },
+})
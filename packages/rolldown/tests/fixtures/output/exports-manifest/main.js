@@ -0,0 +1,2 @@
+import { formatDate } from './shared.js'
+export const main = formatDate()
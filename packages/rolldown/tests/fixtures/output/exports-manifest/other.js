@@ -0,0 +1,2 @@
+import { formatDate, parseDate } from './shared.js'
+export const other = [formatDate(), parseDate()]
@@ -0,0 +1,2 @@
+export const formatDate = () => 'date'
+export const parseDate = () => 'parsed'
@@ -0,0 +1,15 @@
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      format: 'iife',
+    },
+  },
+  afterTest(output) {
+    const code = output.output[0].code
+    expect(code.match(/['"]use strict['"]/g)).toHaveLength(1)
+    expect(code.trimStart()).toMatch(/^['"]use strict['"];/)
+  },
+})
@@ -0,0 +1,4 @@
+'use strict'
+import a from './a.js'
+import b from './b.js'
+console.log(a, b)
@@ -0,0 +1,24 @@
+import { createRequire } from 'node:module'
+import path from 'node:path'
+import { pathToFileURL } from 'node:url'
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    platform: 'node',
+    output: {
+      format: 'cjs',
+      entryFileNames: '[name].cjs',
+    },
+  },
+  afterTest: (output) => {
+    expect(output.output[0].code).not.toContain('import.meta')
+
+    const file = path.join(import.meta.dirname, 'dist/main.cjs')
+    const exports = createRequire(import.meta.url)(file)
+    expect(exports.metaUrl).toBe(pathToFileURL(file).href)
+    expect(exports.destructuredUrl).toBe(exports.metaUrl)
+    expect(exports.destructuredFilename).toBe(file)
+  },
+})
@@ -0,0 +1,5 @@
+const { url, filename } = import.meta
+
+export const metaUrl = import.meta.url
+export const destructuredUrl = url
+export const destructuredFilename = filename
@@ -0,0 +1,24 @@
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      format: 'iife',
+    },
+  },
+  afterTest: (output) => {
+    const code = output.output[0].code
+    expect(code).not.toContain('import.meta')
+    expect(
+      code.split(
+        `var _documentCurrentScript = typeof document !== 'undefined' ? document.currentScript : null;`,
+      ),
+    ).toHaveLength(2)
+    expect(
+      code.split(
+        `_documentCurrentScript && _documentCurrentScript.src || new URL("main.js", document.baseURI).href`,
+      ),
+    ).toHaveLength(3)
+  },
+})
@@ -0,0 +1,3 @@
+const { url } = import.meta
+
+console.log(import.meta.url, url)
@@ -0,0 +1,25 @@
+import { createHash } from 'node:crypto'
+import type { OutputChunk } from 'rolldown'
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: ['main.js', 'other.js'],
+    output: {
+      integrity: true,
+    },
+  },
+  afterTest: (output) => {
+    const chunks = output.output.filter(
+      (item): item is OutputChunk => item.type === 'chunk',
+    )
+    expect(chunks).toHaveLength(2)
+    for (const chunk of chunks) {
+      const digest = createHash('sha384').update(chunk.code).digest('base64')
+      expect(chunk.integrity).toBe(`sha384-${digest}`)
+    }
+    // Chunks with different code get different hashes
+    expect(chunks[0].integrity).not.toBe(chunks[1].integrity)
+  },
+})
@@ -0,0 +1,17 @@
+import type { OutputChunk as RolldownOutputChunk } from 'rolldown'
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      minify: true,
+      keepComments: /@custom/,
+    },
+  },
+  afterTest: (output) => {
+    const chunk = output.output[0] as RolldownOutputChunk
+    expect(chunk.code).toContain('/* @custom */')
+    expect(chunk.code).not.toContain('/* other */')
+  },
+})
@@ -0,0 +1,5 @@
+/* @custom */
+export function foo() {
+  /* other */
+  return 1
+}
@@ -0,0 +1,17 @@
+import type { OutputChunk as RolldownOutputChunk } from 'rolldown'
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      minify: true,
+      keepVarNames: /^__DEBUG_/,
+    },
+  },
+  afterTest: (output) => {
+    const chunk = output.output[0] as RolldownOutputChunk
+    expect(chunk.code).toContain('__DEBUG_value')
+    expect(chunk.code).not.toContain('otherValue')
+  },
+})
@@ -0,0 +1,3 @@
+export function foo(__DEBUG_value, otherValue) {
+  console.log(__DEBUG_value, otherValue)
+}
@@ -0,0 +1,41 @@
+import path from 'node:path'
+import { expect } from 'vitest'
+import { defineTest } from 'rolldown-tests'
+
+const sourcemapPaths: string[] = []
+
+export default defineTest({
+  config: {
+    input: ['main.js'],
+    output: {
+      sourcemap: true,
+      sourcemapBaseUrl: 'https://example.com/assets',
+      sourcemapPathTransform(source, sourcemapPath) {
+        sourcemapPaths.push(sourcemapPath)
+        // Strip the absolute path of the fixture from each source
+        const absolute = path.resolve(path.dirname(sourcemapPath), source)
+        const relative = absolute.slice(import.meta.dirname.length + 1)
+        return `app:///${relative.replace(/\\/g, '/')}`
+      },
+    },
+  },
+  beforeTest: () => {
+    sourcemapPaths.length = 0
+  },
+  afterTest: function (output) {
+    expect(output.output[0].code).contains(
+      '//# sourceMappingURL=https://example.com/assets/main.js.map',
+    )
+    expect(sourcemapPaths).toHaveLength(2)
+    for (const sourcemapPath of sourcemapPaths) {
+      expect(path.basename(sourcemapPath)).toBe('main.js.map')
+    }
+
+    const asset = output.output[1]
+    expect(asset.type).toBe('asset')
+    if (asset.type === 'asset') {
+      const map = JSON.parse(asset.source.toString())
+      expect(map.sources.sort()).toStrictEqual(['app:///foo.js', 'app:///main.js'])
+    }
+  },
+})
@@ -0,0 +1,27 @@
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+declare global {
+  var __topLevelAwaitCjs: number | undefined
+}
+
+export default defineTest({
+  config: {
+    output: {
+      format: 'cjs',
+      entryFileNames: '[name].cjs',
+      topLevelAwait: true,
+    },
+  },
+  afterTest: async (output) => {
+    const chunk = output.output[0]
+    expect(chunk.code).toContain('(async () => {')
+    expect(chunk.code).toContain('await Promise.resolve(21)')
+
+    // @ts-ignore
+    await import('./dist/main.cjs')
+    // The sync importer only runs once the awaited module has been evaluated
+    await new Promise((resolve) => setTimeout(resolve))
+    expect(globalThis.__topLevelAwaitCjs).toBe(42)
+  },
+})
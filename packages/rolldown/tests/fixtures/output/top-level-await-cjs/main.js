@@ -0,0 +1,5 @@
+import { doubled } from './sync.js'
+
+globalThis.__topLevelAwaitCjs = doubled
+
+export { doubled }
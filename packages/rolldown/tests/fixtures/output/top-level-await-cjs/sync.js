@@ -0,0 +1,3 @@
+import { value } from './tla.js'
+
+export const doubled = value * 2
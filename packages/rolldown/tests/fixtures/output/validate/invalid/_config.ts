@@ -0,0 +1,28 @@
+import { defineTest } from 'rolldown-tests'
+import { stripVTControlCharacters } from 'util'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      validate: true,
+    },
+    plugins: [
+      {
+        name: 'corrupt-output',
+        renderChunk(code) {
+          // Simulate a codegen bug that leaves an unbalanced brace behind
+          return code + '\n{'
+        },
+      },
+    ],
+  },
+  catchError(e: any) {
+    expect(e.errors).toEqual([
+      expect.objectContaining({
+        kind: 'PARSE_ERROR',
+      }),
+    ])
+    expect(stripVTControlCharacters(e.message)).toContain('main.js')
+  },
+})
@@ -0,0 +1,15 @@
+import type { OutputChunk as RolldownOutputChunk } from 'rolldown'
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      validate: true,
+    },
+  },
+  afterTest: (output) => {
+    const chunk = output.output[0] as RolldownOutputChunk
+    expect(chunk.code).toContain('export { foo }')
+  },
+})
@@ -0,0 +1,50 @@
+import { expect } from 'vitest'
+import { defineTest } from 'rolldown-tests'
+import {
+  getLocation,
+  getOutputAsset,
+  getOutputChunk,
+} from 'rolldown-tests/utils'
+import { SourceMapConsumer, SourceMapGenerator } from 'source-map'
+
+const banner = '/* custom runtime */'
+
+export default defineTest({
+  config: {
+    plugins: [
+      {
+        name: 'test-plugin',
+        renderChunk(code, chunk) {
+          // Shift every character of the rendered chunk down by one line
+          const map = new SourceMapGenerator({ file: chunk.fileName })
+          code.split('\n').forEach((line, index) => {
+            for (let column = 0; column < line.length; column++) {
+              map.addMapping({
+                source: chunk.fileName,
+                original: { line: index + 1, column },
+                generated: { line: index + 2, column },
+              })
+            }
+          })
+          return { code: `${banner}\n${code}`, map: map.toString() }
+        },
+      },
+    ],
+    output: {
+      sourcemap: true,
+    },
+  },
+  afterTest: async (output) => {
+    const code = getOutputChunk(output)[0].code
+    expect(code.startsWith(`${banner}\n`)).toBe(true)
+
+    const map = getOutputAsset(output)[0].source as string
+    const smc = await new SourceMapConsumer(JSON.parse(map))
+    const originalLoc = smc.originalPositionFor(
+      getLocation(code, code.indexOf('console.log')),
+    )
+    expect(originalLoc.source).toMatch(/main\.js$/)
+    expect(originalLoc.line).toBe(2)
+    expect(originalLoc.column).toBe(0)
+  },
+})
@@ -0,0 +1,11 @@
+import { defineTest } from 'rolldown-tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  afterTest(output) {
+    const code = output.output[0].code
+    expect(code).toContain('globalThis.loadConfig()')
+    expect(code).toContain('globalThis.loadEntryConfig()')
+    expect(code).not.toContain('unused value')
+  },
+})
@@ -0,0 +1,2 @@
+export const config = globalThis.loadConfig()
+export const unused = 'unused value'
@@ -0,0 +1,3 @@
+import './lib.js'
+
+export const entryConfig = globalThis.loadEntryConfig()
@@ -5,7 +5,27 @@ use generator::generators::{
 fn main() -> anyhow::Result<()> {
   let ctx = Context { workspace_root: rolldown_workspace::root_dir() };
   let generators: Vec<Box<dyn Generator>> = vec![
-    Box::new(CheckOptionsGenerator { disabled_event: vec!["CircularDependency"] }),
+    Box::new(CheckOptionsGenerator {
+      disabled_event: vec![
+        "CircularDependency",
+        "NodeGlobalInBrowser",
+        "SideEffectsInPurePackage",
+        "EntryWithoutExports",
+        "UnusedExportedFunction",
+        "EmptyDynamicImport",
+        "CircularImportAtInit",
+        "DynamicRequire",
+        "SideEffectsWithoutTreeshake",
+        "DynamicImport",
+        "UnusedFunctionParameter",
+        "MixedImportAndRequire",
+        "ModuleAlwaysThrows",
+        "CircularReexport",
+        "ModuleExportsReassignment",
+        "RequireInEsmExport",
+        "EsmSyntaxInCommonJs",
+      ],
+    }),
     Box::new(HookUsageGenerator),
     Box::new(RuntimeHelperGenerator),
     Box::new(OxcRuntimeHelperGenerator),